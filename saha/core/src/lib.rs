@@ -7,6 +7,14 @@
 mod utils;
 pub mod stdlib;
 
+use saha_lib::errors::RuntimeError;
+
 pub fn register_core() {
     stdlib::register_stdlib();
+}
+
+/// Release core resources when the program ends. Returns errors for the
+/// resources which could not be released cleanly.
+pub fn shutdown_core() -> Vec<RuntimeError> {
+    return stdlib::shutdown_stdlib();
 }
\ No newline at end of file
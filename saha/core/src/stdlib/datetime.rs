@@ -0,0 +1,412 @@
+//! stdlib DateTime
+//!
+//! Defines the `std.time.DateTime` class, a point in time in UTC or in a fixed
+//! offset from UTC. Date times are parsed and formatted in ISO 8601, or
+//! formatted with strftime-style patterns.
+
+use std::{
+    any::Any,
+    convert::TryFrom,
+    sync::Arc
+};
+
+use chrono::{
+    DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, SecondsFormat, TimeDelta, TimeZone, Timelike, Utc,
+    format::{Item, StrftimeItems}
+};
+
+use saha_lib::prelude::*;
+use saha_lib::types::functions::positional_arg_name;
+
+use crate::{
+    stdlib::{
+        globals::result::SahaResult,
+        time::{DURATION_CLASS_NAME, duration_of, create_duration_value}
+    },
+    utils::{
+        get_new_instref, add_instance_to_symbol_table, create_core_param, create_core_param_with_default, read_core_instance,
+        is_self_argument
+    }
+};
+
+/// Fully qualified name of the `DateTime` class.
+pub const DATETIME_CLASS_NAME: &str = "std.time.DateTime";
+
+/// Create a new DateTime instance from date and time parts. The time parts
+/// and the offset from UTC in minutes default to zero.
+pub fn new_instance(
+    instref: InstRef,
+    args: &SahaFunctionArguments,
+    _type_params: &[Box<SahaType>],
+    _additional_data: &SahaFunctionArguments,
+    create_pos: Option<FilePosition>
+) -> Result<Box<dyn SahaObject>, RuntimeError> {
+    let mut params: SahaFunctionParamDefs = SahaFunctionParamDefs::new();
+
+    let param_list = vec![
+        create_core_param("year", SahaType::Int, 0),
+        create_core_param("month", SahaType::Int, 1),
+        create_core_param("day", SahaType::Int, 2),
+        create_core_param_with_default("hour", SahaType::Int, Value::int(0), 3),
+        create_core_param_with_default("minute", SahaType::Int, Value::int(0), 4),
+        create_core_param_with_default("second", SahaType::Int, Value::int(0), 5),
+        create_core_param_with_default("offsetMinutes", SahaType::Int, Value::int(0), 6)
+    ];
+
+    for (pname, param) in param_list {
+        params.insert(pname.to_string(), param);
+    }
+
+    let args = params.validate_args(args, &create_pos)?;
+    let part = |name: &str| args[name].int.unwrap();
+
+    let offset = get_offset(part("offsetMinutes"), &create_pos)?;
+
+    // parts out of range for chrono are invalid, instead of wrapping around
+    let datetime = match (
+        i32::try_from(part("year")),
+        u32::try_from(part("month")),
+        u32::try_from(part("day")),
+        u32::try_from(part("hour")),
+        u32::try_from(part("minute")),
+        u32::try_from(part("second"))
+    ) {
+        (Ok(year), Ok(month), Ok(day), Ok(hour), Ok(minute), Ok(second)) => {
+            offset.with_ymd_and_hms(year, month, day, hour, minute, second).single()
+        },
+        _ => None
+    };
+
+    return match datetime {
+        Some(datetime) => Ok(Box::new(SahaDateTime { instref: instref, datetime: datetime })),
+        None => {
+            let err = RuntimeError::new(
+                &format!(
+                    "Invalid date time {:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+                    part("year"), part("month"), part("day"), part("hour"), part("minute"), part("second")
+                ),
+                create_pos
+            );
+
+            Err(err)
+        }
+    };
+}
+
+/// Resolve the return type of a `DateTime` method. The type of `sub` depends
+/// on what is subtracted, so it is not resolved.
+pub fn method_type(_type_params: &[Box<SahaType>], method_name: &str) -> Option<SahaType> {
+    return match method_name {
+        "year" | "month" | "day" | "hour" | "minute" | "second" | "weekday" | "offsetMinutes" | "timestamp" | "compare" => Some(SahaType::Int),
+        "toUtc" | "withOffset" | "add" => Some(SahaType::Name(DATETIME_CLASS_NAME.to_string(), Vec::new())),
+        "format" | "toIso" | "display" => Some(SahaType::Str),
+        "equals" => Some(SahaType::Bool),
+        _ => None
+    };
+}
+
+/// Create a new `DateTime` instance, and get the instref value object for it.
+fn create_datetime_value(datetime: DateTime<FixedOffset>) -> Value {
+    let instref = get_new_instref();
+
+    return add_instance_to_symbol_table(instref, Box::new(SahaDateTime {
+        instref: instref,
+        datetime: datetime
+    }));
+}
+
+/// Get a fixed offset from UTC in minutes. Offsets must be less than a day.
+fn get_offset(offset_minutes: isize, pos: &Option<FilePosition>) -> Result<FixedOffset, RuntimeError> {
+    let offset = i32::try_from(offset_minutes).ok().and_then(|m| m.checked_mul(60)).and_then(FixedOffset::east_opt);
+
+    return match offset {
+        Some(offset) => Ok(offset),
+        None => Err(RuntimeError::new(&format!("Invalid UTC offset of {} minutes", offset_minutes), pos.clone()))
+    };
+}
+
+/// Get the current date and time in UTC.
+pub fn utc_now(_args: SahaFunctionArguments) -> SahaCallResult {
+    return Ok(create_datetime_value(Utc::now().fixed_offset()));
+}
+
+/// Get the date time for seconds since the Unix epoch, optionally in an
+/// offset from UTC.
+pub fn from_timestamp(args: SahaFunctionArguments) -> SahaCallResult {
+    let seconds = args["seconds"].int.unwrap();
+    let offset = get_offset(args["offsetMinutes"].int.unwrap(), &None)?;
+
+    return match DateTime::from_timestamp(seconds as i64, 0) {
+        Some(datetime) => Ok(create_datetime_value(datetime.with_timezone(&offset))),
+        None => Err(RuntimeError::new(&format!("Timestamp {} is out of range", seconds), None))
+    };
+}
+
+/// Parse an ISO 8601 date time, e.g. `2024-02-29T12:30:00+02:00`. Date times
+/// without an offset, and plain dates, are in UTC.
+pub fn parse_datetime(args: SahaFunctionArguments) -> SahaCallResult {
+    let text = args["text"].str.clone().unwrap();
+
+    let parsed = DateTime::parse_from_rfc3339(&text)
+        .or_else(|_| NaiveDateTime::parse_from_str(&text, "%Y-%m-%dT%H:%M:%S%.f").map(|dt| dt.and_utc().fixed_offset()))
+        .or_else(|_| NaiveDate::parse_from_str(&text, "%Y-%m-%d").map(|d| d.and_time(Default::default()).and_utc().fixed_offset()));
+
+    let outcome = match parsed {
+        Ok(datetime) => Ok(create_datetime_value(datetime)),
+        Err(_) => Err(Value::str(format!("Invalid ISO 8601 date time `{}`", text)))
+    };
+
+    let datetime_type = SahaType::Name(DATETIME_CLASS_NAME.to_string(), Vec::new());
+
+    return Ok(SahaResult::create_value(outcome, Box::new(datetime_type), Box::new(SahaType::Str)));
+}
+
+/// SahaDateTime is the core definition of the `DateTime` type in Saha.
+#[derive(Clone)]
+struct SahaDateTime {
+    instref: InstRef,
+    datetime: DateTime<FixedOffset>
+}
+
+impl SahaObject for SahaDateTime {
+    fn get_instance_ref(&self) -> InstRef {
+        return self.instref;
+    }
+
+    fn is_core_defined(&self) -> bool {
+        return true;
+    }
+
+    fn get_class_name(&self) -> String {
+        return "DateTime".to_string();
+    }
+
+    fn get_fully_qualified_class_name(&self) -> String {
+        return DATETIME_CLASS_NAME.to_string();
+    }
+
+    fn get_implements(&self) -> Vec<String> {
+        return ["Add", "Sub", "Equals", "Comparable", "Displayable"].iter().map(|b| b.to_string()).collect();
+    }
+
+    fn get_full_method_name(&mut self, _method_name: &str) -> String {
+        unimplemented!()
+    }
+
+    fn get_method_ref(&mut self, _method_name: &str) -> Result<Arc<Box<dyn SahaCallable>>, RuntimeError> {
+        unimplemented!()
+    }
+
+    fn get_type_params(&self) -> Vec<(char, Box<SahaType>)> {
+        return Vec::new();
+    }
+
+    fn get_named_type(&self) -> Box<SahaType> {
+        return Box::new(SahaType::Name(DATETIME_CLASS_NAME.to_string(), Vec::new()));
+    }
+
+    fn call_member(&mut self, access: AccessParams, args: SahaFunctionArguments) -> SahaCallResult {
+        if access.is_static_access {
+            return Err(RuntimeError::new(
+                &format!("No static method `{}` defined for `{}`", access.member_name, self.get_class_name()),
+                access.access_file_pos.clone()
+            ));
+        }
+
+        let datetime = self.datetime;
+        match access.member_name as &str {
+            "year" => self.int_part(&args, access, datetime.year() as isize),
+            "month" => self.int_part(&args, access, datetime.month() as isize),
+            "day" => self.int_part(&args, access, datetime.day() as isize),
+            "hour" => self.int_part(&args, access, datetime.hour() as isize),
+            "minute" => self.int_part(&args, access, datetime.minute() as isize),
+            "second" => self.int_part(&args, access, datetime.second() as isize),
+            "weekday" => self.int_part(&args, access, datetime.weekday().number_from_monday() as isize),
+            "offsetMinutes" => self.int_part(&args, access, (datetime.offset().local_minus_utc() / 60) as isize),
+            "timestamp" => self.int_part(&args, access, datetime.timestamp() as isize),
+            "toUtc" => self.to_utc(&args, access),
+            "withOffset" => self.with_offset(&args, access),
+            "format" => self.format(&args, access),
+            "toIso" | "display" => self.to_iso(&args, access),
+            "add" => self.add(&args, access),
+            "sub" => self.sub(&args, access),
+            "equals" => self.equals(&args, access),
+            "compare" => self.compare(&args, access),
+            _ => {
+                return Err(RuntimeError::new(
+                    &format!("No method `{}` defined for `{}`", access.member_name, self.get_class_name()),
+                    access.access_file_pos.clone()
+                ));
+            }
+        }
+    }
+
+    fn access_property(&self, _access: AccessParams) -> SahaCallResult {
+        unimplemented!()
+    }
+
+    fn mutate_property(&mut self, _access: AccessParams, _new_value: Value) -> SahaCallResult {
+        unimplemented!()
+    }
+
+    fn box_clone(&self) -> Box<dyn SahaObject> {
+        return Box::new(self.clone());
+    }
+
+    fn into_iter(&self) -> Box<dyn Iterator<Item = (Value, Value)>> {
+        unimplemented!()
+    }
+
+    fn set_data_from_iter(&mut self, _iterator: Box<dyn Iterator<Item = (Value, Value)>>) {
+        unimplemented!()
+    }
+
+    fn as_any(&self) -> Option<&dyn Any> {
+        return Some(self);
+    }
+}
+
+impl SahaDateTime {
+    /// Get the date time of a `DateTime` instance value.
+    fn datetime_of(&self, value: &Value) -> Option<DateTime<FixedOffset>> {
+        return read_core_instance(value, |d: &SahaDateTime| d.datetime);
+    }
+
+    /// Validate the `other` date time argument and get its date time.
+    fn other_datetime(&self, args: &SahaFunctionArguments, access: &AccessParams) -> Result<DateTime<FixedOffset>, RuntimeError> {
+        if is_self_argument(args, self.instref) {
+            return Ok(self.datetime);
+        }
+
+        let args = self.other_params(DATETIME_CLASS_NAME).validate_args(args, access.access_file_pos)?;
+
+        return Ok(self.datetime_of(&args["other"]).unwrap());
+    }
+
+    /// Get function parameter definitions for methods which take another
+    /// value of a class as `other`.
+    fn other_params(&self, class_name: &str) -> SahaFunctionParamDefs {
+        let mut params: SahaFunctionParamDefs = SahaFunctionParamDefs::new();
+        let (pname, param) = create_core_param("other", SahaType::Name(class_name.to_string(), Vec::new()), 0);
+
+        params.insert(pname.to_string(), param);
+
+        return params;
+    }
+
+    /// Get a date time value for a calculation result, which errors if the
+    /// result is out of range.
+    fn datetime_result(&self, datetime: Option<DateTime<FixedOffset>>, access: &AccessParams) -> SahaCallResult {
+        return match datetime {
+            Some(datetime) => Ok(create_datetime_value(datetime)),
+            None => Err(RuntimeError::new("Date time is out of range", access.access_file_pos.clone()))
+        };
+    }
+
+    /// Date time "methods" which return a part of the date time as an int.
+    fn int_part(&self, args: &SahaFunctionArguments, access: AccessParams, part: isize) -> SahaCallResult {
+        let params: SahaFunctionParamDefs = SahaFunctionParamDefs::new(); // no params
+
+        params.validate_args(args, access.access_file_pos)?;
+
+        return Ok(Value::int(part));
+    }
+
+    /// The DateTime::toUtc "method". Returns the same point in time in UTC.
+    pub fn to_utc(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let params: SahaFunctionParamDefs = SahaFunctionParamDefs::new(); // no params
+
+        params.validate_args(args, access.access_file_pos)?;
+
+        return Ok(create_datetime_value(self.datetime.to_utc().fixed_offset()));
+    }
+
+    /// The DateTime::withOffset "method". Returns the same point in time in an
+    /// offset from UTC in minutes.
+    pub fn with_offset(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let mut params: SahaFunctionParamDefs = SahaFunctionParamDefs::new();
+        let (pname, param) = create_core_param("offsetMinutes", SahaType::Int, 0);
+
+        params.insert(pname.to_string(), param);
+
+        let args = params.validate_args(args, access.access_file_pos)?;
+        let offset = get_offset(args["offsetMinutes"].int.unwrap(), access.access_file_pos)?;
+
+        return Ok(create_datetime_value(self.datetime.with_timezone(&offset)));
+    }
+
+    /// The DateTime::format "method". Formats the date time with a
+    /// strftime-style pattern, e.g. `%Y-%m-%d %H:%M`.
+    pub fn format(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let mut params: SahaFunctionParamDefs = SahaFunctionParamDefs::new();
+        let (pname, param) = create_core_param("pattern", SahaType::Str, 0);
+
+        params.insert(pname.to_string(), param);
+
+        let args = params.validate_args(args, access.access_file_pos)?;
+        let pattern = args["pattern"].str.clone().unwrap();
+
+        let items: Vec<Item> = StrftimeItems::new(&pattern).collect();
+
+        if items.contains(&Item::Error) {
+            let err = RuntimeError::new(&format!("Invalid date time format pattern `{}`", pattern), access.access_file_pos.clone());
+
+            return Err(err);
+        }
+
+        return Ok(Value::str(self.datetime.format_with_items(items.into_iter()).to_string()));
+    }
+
+    /// The DateTime::toIso "method", also used for displaying date times.
+    /// Formats the date time in ISO 8601, where UTC is marked with `Z`.
+    pub fn to_iso(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let params: SahaFunctionParamDefs = SahaFunctionParamDefs::new(); // no params
+
+        params.validate_args(args, access.access_file_pos)?;
+
+        return Ok(Value::str(self.datetime.to_rfc3339_opts(SecondsFormat::AutoSi, true)));
+    }
+
+    /// The DateTime::add "method", also used for the `+` operator. Adds a
+    /// duration to the date time.
+    pub fn add(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let args = self.other_params(DURATION_CLASS_NAME).validate_args(args, access.access_file_pos)?;
+        let duration = duration_of(&args["other"]).unwrap();
+
+        return self.datetime_result(self.datetime.checked_add_signed(duration), &access);
+    }
+
+    /// The DateTime::sub "method", also used for the `-` operator. Subtracting
+    /// a duration returns a date time, and subtracting another date time
+    /// returns the duration between the two.
+    pub fn sub(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let other = args.get("other").or_else(|| args.get(&positional_arg_name(0)));
+
+        if is_self_argument(args, self.instref) || other.and_then(|o| self.datetime_of(o)).is_some() {
+            let other_datetime = self.other_datetime(args, &access)?;
+            let difference: TimeDelta = self.datetime.signed_duration_since(other_datetime);
+
+            return Ok(create_duration_value(difference));
+        }
+
+        let args = self.other_params(DURATION_CLASS_NAME).validate_args(args, access.access_file_pos)?;
+        let duration = duration_of(&args["other"]).unwrap();
+
+        return self.datetime_result(self.datetime.checked_sub_signed(duration), &access);
+    }
+
+    /// The DateTime::equals "method", also used for the `==` operator. Date
+    /// times are equal when they are the same point in time.
+    pub fn equals(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let other = self.other_datetime(args, &access)?;
+
+        return Ok(Value::bool(self.datetime == other));
+    }
+
+    /// The DateTime::compare "method", also used for comparison operators.
+    pub fn compare(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let other = self.other_datetime(args, &access)?;
+
+        return Ok(Value::int(self.datetime.cmp(&other) as isize));
+    }
+}
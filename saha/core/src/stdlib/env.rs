@@ -0,0 +1,100 @@
+//! stdlib Environment
+//!
+//! The `std.env` module, giving access to script arguments, environment
+//! variables and the current working directory.
+
+use std::{
+    collections::HashMap,
+    env
+};
+
+use saha_lib::prelude::*;
+
+use crate::{
+    stdlib::{
+        fs::fs_result,
+        globals::{
+            option::SahaOption,
+            list::create_list_value,
+            dict::create_dict_value
+        }
+    },
+    utils::{create_core_function, create_core_param}
+};
+
+/// Register module stdlib parts.
+pub fn get_saha_functions() -> Vec<(String, CoreFunction)> {
+    let list_of_str = SahaType::Name("List".to_string(), vec![Box::new(SahaType::Str)]);
+    let dict_of_str = SahaType::Name("Dict".to_string(), vec![Box::new(SahaType::Str)]);
+    let option_of_str = SahaType::Name("Option".to_string(), vec![Box::new(SahaType::Str)]);
+    let result_of_str = SahaType::Name("Result".to_string(), vec![Box::new(SahaType::Str), Box::new(SahaType::Str)]);
+
+    return vec![
+        create_core_function("args", Vec::new(), Box::new(list_of_str), args),
+        create_core_function(
+            "get",
+            vec![create_core_param("name", SahaType::Str, 0)],
+            Box::new(option_of_str),
+            get
+        ),
+        create_core_function(
+            "set",
+            vec![
+                create_core_param("name", SahaType::Str, 0),
+                create_core_param("value", SahaType::Str, 1)
+            ],
+            Box::new(SahaType::Void),
+            set
+        ),
+        create_core_function("vars", Vec::new(), Box::new(dict_of_str), vars),
+        create_core_function("cwd", Vec::new(), Box::new(result_of_str), cwd),
+    ];
+}
+
+/// Get the arguments given to the script after `--` on the command line.
+fn args(_args: SahaFunctionArguments) -> SahaCallResult {
+    let script_args = saha_lib::SAHA_SYMBOL_TABLE.lock().unwrap().script_args.clone();
+
+    return Ok(create_list_value(script_args.into_iter().map(Value::str).collect(), Box::new(SahaType::Str)));
+}
+
+/// Get an environment variable value. Variables which are not set, or which
+/// are not valid unicode, are none.
+fn get(args: SahaFunctionArguments) -> SahaCallResult {
+    let name = args["name"].str.clone().unwrap();
+
+    return Ok(SahaOption::create_value(env::var(name).ok().map(Value::str), Box::new(SahaType::Str)));
+}
+
+/// Set an environment variable for the running program and the processes it
+/// starts.
+fn set(args: SahaFunctionArguments) -> SahaCallResult {
+    let name = args["name"].str.clone().unwrap();
+    let value = args["value"].str.clone().unwrap();
+
+    if name.is_empty() || name.contains('=') || name.contains('\0') || value.contains('\0') {
+        let err = RuntimeError::new(&format!("Invalid environment variable `{}`", name), None);
+
+        return Err(err);
+    }
+
+    env::set_var(name, value);
+
+    return Ok(Value::void());
+}
+
+/// Get all environment variables which are valid unicode.
+fn vars(_args: SahaFunctionArguments) -> SahaCallResult {
+    let data: HashMap<String, Value> = env::vars_os()
+        .filter_map(|(name, value)| Some((name.into_string().ok()?, Value::str(value.into_string().ok()?))))
+        .collect();
+
+    return Ok(create_dict_value(data, Box::new(SahaType::Str)));
+}
+
+/// Get the current working directory.
+fn cwd(_args: SahaFunctionArguments) -> SahaCallResult {
+    let outcome = env::current_dir().map(|dir| Value::str(dir.to_string_lossy().to_string()));
+
+    return fs_result(outcome, SahaType::Str, ".");
+}
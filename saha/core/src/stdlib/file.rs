@@ -0,0 +1,378 @@
+//! stdlib File
+//!
+//! Defines the `std.fs.File` class, a streaming handle to an open file. Files
+//! are opened with `std.fs.open()`, and are read and written through buffers
+//! so large files never need to fit into memory.
+//!
+//! The underlying handle is closed when the file is closed with `close()`, or
+//! when the program ends. Files open for writing are kept in a registry, so
+//! their buffered contents are written out even if they are never closed.
+
+use std::{
+    fs,
+    io::{self, BufReader, BufWriter, Seek, SeekFrom, Write},
+    sync::{Arc, Mutex, Weak}
+};
+
+use saha_lib::prelude::*;
+
+use crate::{
+    stdlib::{
+        fs::fs_result,
+        globals::{
+            option::SahaOption,
+            lines::{LineSource, create_lines_value, read_line}
+        }
+    },
+    utils::{get_new_instref, add_instance_to_symbol_table, create_core_param}
+};
+
+/// Fully qualified name of the `File` class.
+pub const FILE_CLASS_NAME: &str = "std.fs.File";
+
+/// Paths and handles of the files opened for writing.
+static WRITE_HANDLES: Mutex<Vec<(String, Weak<Mutex<FileHandle>>)>> = Mutex::new(Vec::new());
+
+/// Files cannot be created with `new`, they are opened with `std.fs.open()`.
+pub fn new_instance(
+    _instref: InstRef,
+    _args: &SahaFunctionArguments,
+    _type_params: &[Box<SahaType>],
+    _additional_data: &SahaFunctionArguments,
+    create_pos: Option<FilePosition>
+) -> Result<Box<dyn SahaObject>, RuntimeError> {
+    let err = RuntimeError::new("`File` cannot be created with `new`, use `std.fs.open` instead", create_pos);
+
+    return Err(err);
+}
+
+/// Resolve the return type of a `File` method.
+pub fn method_type(_type_params: &[Box<SahaType>], method_name: &str) -> Option<SahaType> {
+    let fs_result_type = |success_type| SahaType::Name("Result".to_string(), vec![Box::new(success_type), Box::new(SahaType::Str)]);
+
+    return match method_name {
+        "readLine" => Some(SahaType::Name("Option".to_string(), vec![Box::new(SahaType::Str)])),
+        "lines" => Some(SahaType::Name("Lines".to_string(), Vec::new())),
+        "write" | "flush" | "close" => Some(fs_result_type(SahaType::Bool)),
+        "seek" => Some(fs_result_type(SahaType::Int)),
+        _ => None
+    };
+}
+
+/// Open a file in a mode, and get the instref value object for the new `File`
+/// instance. The mode is `r` for reading, `w` for writing over any existing
+/// contents, or `a` for appending to the end of the file.
+pub fn open_file(path: &str, mode: &str) -> io::Result<Value> {
+    let handle = match mode {
+        "r" => FileHandle::Read(BufReader::new(fs::File::open(path)?)),
+        "w" => FileHandle::Write(BufWriter::new(fs::File::create(path)?)),
+        "a" => {
+            let file = fs::OpenOptions::new().create(true).append(true).open(path)?;
+
+            FileHandle::Write(BufWriter::new(file))
+        },
+        _ => unreachable!()
+    };
+
+    let is_write = matches!(handle, FileHandle::Write(..));
+    let handle = Arc::new(Mutex::new(handle));
+
+    if is_write {
+        let mut write_handles = WRITE_HANDLES.lock().unwrap();
+
+        write_handles.retain(|(_, h)| h.strong_count() > 0);
+        write_handles.push((path.to_string(), Arc::downgrade(&handle)));
+    }
+
+    let instref = get_new_instref();
+
+    let file_inst = Box::new(SahaFile {
+        instref: instref,
+        path: path.to_string(),
+        handle: handle
+    });
+
+    return Ok(add_instance_to_symbol_table(instref, file_inst));
+}
+
+/// Close the files which are still open for writing, writing out their
+/// buffered contents. Returns errors for the files which could not be written.
+pub fn close_open_files() -> Vec<RuntimeError> {
+    let write_handles = std::mem::take(&mut *WRITE_HANDLES.lock().unwrap());
+    let mut errors: Vec<RuntimeError> = Vec::new();
+
+    for (path, handle) in write_handles {
+        let handle = match handle.upgrade() {
+            Some(h) => h,
+            None => continue
+        };
+
+        let mut handle = handle.lock().unwrap();
+
+        if let FileHandle::Write(ref mut writer) = *handle {
+            if let Err(err) = writer.flush() {
+                errors.push(RuntimeError::new(&format!("Cannot write file `{}`: {}", path, err), None));
+            }
+        }
+
+        *handle = FileHandle::Closed;
+    }
+
+    return errors;
+}
+
+/// Is a string a valid mode for `open_file`.
+pub fn is_valid_mode(mode: &str) -> bool {
+    return ["r", "w", "a"].contains(&mode);
+}
+
+/// Buffered handle to an open file.
+enum FileHandle {
+    Read(BufReader<fs::File>),
+    Write(BufWriter<fs::File>),
+    Closed
+}
+
+impl LineSource for FileHandle {
+    fn next_line(&mut self) -> Option<String> {
+        let reader = match self {
+            FileHandle::Read(reader) => reader,
+            _ => return None
+        };
+
+        return read_line(reader).unwrap_or(None);
+    }
+}
+
+/// SahaFile is the core definition of the `File` type in Saha. Clones share
+/// the same handle.
+#[derive(Clone)]
+struct SahaFile {
+    instref: InstRef,
+    path: String,
+    handle: Arc<Mutex<FileHandle>>
+}
+
+impl SahaObject for SahaFile {
+    fn get_instance_ref(&self) -> InstRef {
+        return self.instref;
+    }
+
+    fn is_core_defined(&self) -> bool {
+        return true;
+    }
+
+    fn get_class_name(&self) -> String {
+        return "File".to_string();
+    }
+
+    fn get_fully_qualified_class_name(&self) -> String {
+        return FILE_CLASS_NAME.to_string();
+    }
+
+    fn get_implements(&self) -> Vec<String> {
+        return Vec::new();
+    }
+
+    fn get_full_method_name(&mut self, _method_name: &str) -> String {
+        unimplemented!()
+    }
+
+    fn get_method_ref(&mut self, _method_name: &str) -> Result<Arc<Box<dyn SahaCallable>>, RuntimeError> {
+        unimplemented!()
+    }
+
+    fn get_type_params(&self) -> Vec<(char, Box<SahaType>)> {
+        return Vec::new();
+    }
+
+    fn get_named_type(&self) -> Box<SahaType> {
+        return Box::new(SahaType::Name(FILE_CLASS_NAME.to_string(), Vec::new()));
+    }
+
+    fn call_member(&mut self, access: AccessParams, args: SahaFunctionArguments) -> SahaCallResult {
+        if access.is_static_access {
+            return Err(RuntimeError::new(
+                &format!("No static method `{}` defined for `{}`", access.member_name, self.get_class_name()),
+                access.access_file_pos.clone()
+            ));
+        }
+
+        match access.member_name as &str {
+            "readLine" => self.read_line(&args, access),
+            "lines" => self.lines(&args, access),
+            "write" => self.write(&args, access),
+            "flush" => self.flush(&args, access),
+            "seek" => self.seek(&args, access),
+            "close" => self.close(&args, access),
+            _ => {
+                return Err(RuntimeError::new(
+                    &format!("No method `{}` defined for `{}`", access.member_name, self.get_class_name()),
+                    access.access_file_pos.clone()
+                ));
+            }
+        }
+    }
+
+    fn access_property(&self, _access: AccessParams) -> SahaCallResult {
+        unimplemented!()
+    }
+
+    fn mutate_property(&mut self, _access: AccessParams, _new_value: Value) -> SahaCallResult {
+        unimplemented!()
+    }
+
+    fn box_clone(&self) -> Box<dyn SahaObject> {
+        return Box::new(self.clone());
+    }
+
+    fn into_iter(&self) -> Box<dyn Iterator<Item = (Value, Value)>> {
+        unimplemented!()
+    }
+
+    fn set_data_from_iter(&mut self, _iterator: Box<dyn Iterator<Item = (Value, Value)>>) {
+        unimplemented!()
+    }
+}
+
+impl SahaFile {
+    /// Get an error for using the file in a way its mode does not allow, or
+    /// after it has been closed.
+    fn mode_error(&self, handle: &FileHandle, wanted: &str, access: &AccessParams) -> RuntimeError {
+        let msg = match handle {
+            FileHandle::Closed => format!("Cannot use file `{}` after it has been closed", self.path),
+            _ => format!("File `{}` is not open for {}", self.path, wanted)
+        };
+
+        return RuntimeError::new(&msg, access.access_file_pos.clone());
+    }
+
+    /// The File::readLine "method". Returns `None` at the end of the file.
+    pub fn read_line(&mut self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let params: SahaFunctionParamDefs = SahaFunctionParamDefs::new(); // no params
+
+        params.validate_args(args, access.access_file_pos)?;
+
+        let mut handle = self.handle.lock().unwrap();
+
+        let reader = match *handle {
+            FileHandle::Read(ref mut reader) => reader,
+            _ => return Err(self.mode_error(&handle, "reading", &access))
+        };
+
+        let line = match read_line(reader) {
+            Ok(line) => line,
+            Err(err) => {
+                let err = RuntimeError::new(&format!("Cannot read file `{}`: {}", self.path, err), access.access_file_pos.clone());
+
+                return Err(err);
+            }
+        };
+
+        return Ok(SahaOption::create_value(line.map(Value::str), Box::new(SahaType::Str)));
+    }
+
+    /// The File::lines "method". Returns lines which can be looped over with
+    /// `for`, reading the file one line at a time.
+    pub fn lines(&mut self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let params: SahaFunctionParamDefs = SahaFunctionParamDefs::new(); // no params
+
+        params.validate_args(args, access.access_file_pos)?;
+
+        {
+            let handle = self.handle.lock().unwrap();
+
+            if let FileHandle::Write(..) | FileHandle::Closed = *handle {
+                return Err(self.mode_error(&handle, "reading", &access));
+            }
+        }
+
+        return Ok(create_lines_value(self.handle.clone()));
+    }
+
+    /// The File::write "method".
+    pub fn write(&mut self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let mut params: SahaFunctionParamDefs = SahaFunctionParamDefs::new();
+        let (pname, param) = create_core_param("text", SahaType::Str, 0);
+
+        params.insert(pname.to_string(), param);
+
+        let args = params.validate_args(args, access.access_file_pos)?;
+
+        let mut handle = self.handle.lock().unwrap();
+
+        let writer = match *handle {
+            FileHandle::Write(ref mut writer) => writer,
+            _ => return Err(self.mode_error(&handle, "writing", &access))
+        };
+
+        let outcome = writer.write_all(args["text"].str.clone().unwrap().as_bytes()).map(|_| Value::bool(true));
+
+        return fs_result(outcome, SahaType::Bool, &self.path);
+    }
+
+    /// The File::flush "method". Writes any buffered contents to the file.
+    pub fn flush(&mut self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let params: SahaFunctionParamDefs = SahaFunctionParamDefs::new(); // no params
+
+        params.validate_args(args, access.access_file_pos)?;
+
+        let mut handle = self.handle.lock().unwrap();
+
+        let writer = match *handle {
+            FileHandle::Write(ref mut writer) => writer,
+            _ => return Err(self.mode_error(&handle, "writing", &access))
+        };
+
+        let outcome = writer.flush().map(|_| Value::bool(true));
+
+        return fs_result(outcome, SahaType::Bool, &self.path);
+    }
+
+    /// The File::seek "method". Moves to a byte position counted from the
+    /// start of the file, and returns the new position.
+    pub fn seek(&mut self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let mut params: SahaFunctionParamDefs = SahaFunctionParamDefs::new();
+        let (pname, param) = create_core_param("position", SahaType::Int, 0);
+
+        params.insert(pname.to_string(), param);
+
+        let args = params.validate_args(args, access.access_file_pos)?;
+        let position = args["position"].int.unwrap();
+
+        if position < 0 {
+            let err = RuntimeError::new("Cannot seek to a negative file position", access.access_file_pos.clone());
+
+            return Err(err);
+        }
+
+        let mut handle = self.handle.lock().unwrap();
+
+        let outcome = match *handle {
+            FileHandle::Read(ref mut reader) => reader.seek(SeekFrom::Start(position as u64)),
+            FileHandle::Write(ref mut writer) => writer.seek(SeekFrom::Start(position as u64)),
+            FileHandle::Closed => return Err(self.mode_error(&handle, "seeking", &access))
+        };
+
+        return fs_result(outcome.map(|pos| Value::int(pos as isize)), SahaType::Int, &self.path);
+    }
+
+    /// The File::close "method". Buffered contents are written to the file
+    /// before the handle is closed. Closing a closed file does nothing.
+    pub fn close(&mut self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let params: SahaFunctionParamDefs = SahaFunctionParamDefs::new(); // no params
+
+        params.validate_args(args, access.access_file_pos)?;
+
+        let mut handle = self.handle.lock().unwrap();
+
+        let outcome = match *handle {
+            FileHandle::Write(ref mut writer) => writer.flush(),
+            _ => Ok(())
+        };
+
+        *handle = FileHandle::Closed;
+
+        return fs_result(outcome.map(|_| Value::bool(true)), SahaType::Bool, &self.path);
+    }
+}
@@ -0,0 +1,287 @@
+//! stdlib File system
+//!
+//! The `std.fs` module. File system operations never halt the program on
+//! failure, instead they return a `Result<T, str>` where the failure value
+//! describes what went wrong.
+
+use std::{
+    collections::HashMap,
+    fs,
+    io::{self, Write},
+    path::Path,
+    time::UNIX_EPOCH
+};
+
+use saha_lib::prelude::*;
+
+use crate::{
+    stdlib::{
+        file::{FILE_CLASS_NAME, open_file, is_valid_mode},
+        globals::{
+            result::SahaResult,
+            list::create_list_value,
+            dict::create_dict_value
+        }
+    },
+    utils::{create_core_function, create_core_param, create_core_param_with_default}
+};
+
+/// Register module stdlib parts.
+pub fn get_saha_functions() -> Vec<(String, CoreFunction)> {
+    let list_of_str = SahaType::Name("List".to_string(), vec![Box::new(SahaType::Str)]);
+    let dict_of_int = SahaType::Name("Dict".to_string(), vec![Box::new(SahaType::Int)]);
+
+    return vec![
+        create_core_function(
+            "readFile",
+            vec![create_core_param("path", SahaType::Str, 0)],
+            result_type(SahaType::Str),
+            read_file
+        ),
+        create_core_function(
+            "writeFile",
+            vec![
+                create_core_param("path", SahaType::Str, 0),
+                create_core_param("contents", SahaType::Str, 1)
+            ],
+            result_type(SahaType::Bool),
+            write_file
+        ),
+        create_core_function(
+            "appendFile",
+            vec![
+                create_core_param("path", SahaType::Str, 0),
+                create_core_param("contents", SahaType::Str, 1)
+            ],
+            result_type(SahaType::Bool),
+            append_file
+        ),
+        create_core_function(
+            "exists",
+            vec![create_core_param("path", SahaType::Str, 0)],
+            result_type(SahaType::Bool),
+            exists
+        ),
+        create_core_function(
+            "isDir",
+            vec![create_core_param("path", SahaType::Str, 0)],
+            result_type(SahaType::Bool),
+            is_dir
+        ),
+        create_core_function(
+            "listDir",
+            vec![create_core_param("path", SahaType::Str, 0)],
+            result_type(list_of_str),
+            list_dir
+        ),
+        create_core_function(
+            "mkdirAll",
+            vec![create_core_param("path", SahaType::Str, 0)],
+            result_type(SahaType::Bool),
+            mkdir_all
+        ),
+        create_core_function(
+            "remove",
+            vec![create_core_param("path", SahaType::Str, 0)],
+            result_type(SahaType::Bool),
+            remove
+        ),
+        create_core_function(
+            "rename",
+            vec![
+                create_core_param("from", SahaType::Str, 0),
+                create_core_param("to", SahaType::Str, 1)
+            ],
+            result_type(SahaType::Bool),
+            rename
+        ),
+        create_core_function(
+            "open",
+            vec![
+                create_core_param("path", SahaType::Str, 0),
+                create_core_param_with_default("mode", SahaType::Str, Value::str("r".to_string()), 1)
+            ],
+            result_type(SahaType::Name(FILE_CLASS_NAME.to_string(), Vec::new())),
+            open
+        ),
+        create_core_function(
+            "metadata",
+            vec![create_core_param("path", SahaType::Str, 0)],
+            result_type(dict_of_int),
+            metadata
+        ),
+    ];
+}
+
+/// Get the `Result<T, str>` type for a file system operation.
+fn result_type(success_type: SahaType) -> Box<SahaType> {
+    return Box::new(SahaType::Name(
+        "Result".to_string(),
+        vec![Box::new(success_type), Box::new(SahaType::Str)]
+    ));
+}
+
+/// Get a string argument value.
+fn str_arg(args: &SahaFunctionArguments, name: &str) -> String {
+    return args[name].str.clone().unwrap();
+}
+
+/// Turn the outcome of a file system operation into a `Result<T, str>` value.
+/// The failure value contains the path the operation was done on.
+pub fn fs_result(outcome: io::Result<Value>, success_type: SahaType, path: &str) -> SahaCallResult {
+    let outcome = outcome.map_err(|err| Value::str(format!("`{}`: {}", path, err)));
+
+    return Ok(SahaResult::create_value(outcome, Box::new(success_type), Box::new(SahaType::Str)));
+}
+
+/// Read the contents of a file into a string.
+fn read_file(args: SahaFunctionArguments) -> SahaCallResult {
+    let path = str_arg(&args, "path");
+
+    let outcome = fs::read_to_string(&path).map(Value::str);
+
+    return fs_result(outcome, SahaType::Str, &path);
+}
+
+/// Write a string to a file, replacing any existing contents. The file is
+/// created if it does not exist.
+fn write_file(args: SahaFunctionArguments) -> SahaCallResult {
+    let path = str_arg(&args, "path");
+
+    let outcome = fs::write(&path, str_arg(&args, "contents")).map(|_| Value::bool(true));
+
+    return fs_result(outcome, SahaType::Bool, &path);
+}
+
+/// Append a string to the end of a file. The file is created if it does not
+/// exist.
+fn append_file(args: SahaFunctionArguments) -> SahaCallResult {
+    let path = str_arg(&args, "path");
+
+    let outcome = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .and_then(|mut file| file.write_all(str_arg(&args, "contents").as_bytes()))
+        .map(|_| Value::bool(true));
+
+    return fs_result(outcome, SahaType::Bool, &path);
+}
+
+/// Check whether a file or directory exists.
+fn exists(args: SahaFunctionArguments) -> SahaCallResult {
+    let path = str_arg(&args, "path");
+
+    let outcome = Path::new(&path).try_exists().map(Value::bool);
+
+    return fs_result(outcome, SahaType::Bool, &path);
+}
+
+/// Check whether a path points to a directory. Missing paths are not
+/// directories.
+fn is_dir(args: SahaFunctionArguments) -> SahaCallResult {
+    let path = str_arg(&args, "path");
+
+    let outcome = match fs::metadata(&path) {
+        Ok(meta) => Ok(Value::bool(meta.is_dir())),
+        Err(ref err) if err.kind() == io::ErrorKind::NotFound => Ok(Value::bool(false)),
+        Err(err) => Err(err)
+    };
+
+    return fs_result(outcome, SahaType::Bool, &path);
+}
+
+/// List the entry names of a directory in alphabetical order.
+fn list_dir(args: SahaFunctionArguments) -> SahaCallResult {
+    let path = str_arg(&args, "path");
+    let list_of_str = SahaType::Name("List".to_string(), vec![Box::new(SahaType::Str)]);
+
+    let outcome = fs::read_dir(&path)
+        .and_then(|entries| entries.collect::<io::Result<Vec<fs::DirEntry>>>())
+        .map(|entries| {
+            let mut names: Vec<String> = entries.iter()
+                .map(|entry| entry.file_name().to_string_lossy().to_string())
+                .collect();
+
+            names.sort();
+
+            create_list_value(names.into_iter().map(Value::str).collect(), Box::new(SahaType::Str))
+        });
+
+    return fs_result(outcome, list_of_str, &path);
+}
+
+/// Create a directory and all of its missing parent directories.
+fn mkdir_all(args: SahaFunctionArguments) -> SahaCallResult {
+    let path = str_arg(&args, "path");
+
+    let outcome = fs::create_dir_all(&path).map(|_| Value::bool(true));
+
+    return fs_result(outcome, SahaType::Bool, &path);
+}
+
+/// Remove a file or an empty directory.
+fn remove(args: SahaFunctionArguments) -> SahaCallResult {
+    let path = str_arg(&args, "path");
+
+    let outcome = fs::symlink_metadata(&path)
+        .and_then(|meta| {
+            if meta.is_dir() {
+                return fs::remove_dir(&path);
+            }
+
+            return fs::remove_file(&path);
+        })
+        .map(|_| Value::bool(true));
+
+    return fs_result(outcome, SahaType::Bool, &path);
+}
+
+/// Rename or move a file or directory.
+fn rename(args: SahaFunctionArguments) -> SahaCallResult {
+    let from = str_arg(&args, "from");
+
+    let outcome = fs::rename(&from, str_arg(&args, "to")).map(|_| Value::bool(true));
+
+    return fs_result(outcome, SahaType::Bool, &from);
+}
+
+/// Open a file for streaming reads or writes. The mode is `r` for reading, `w`
+/// for writing over any existing contents, or `a` for appending.
+fn open(args: SahaFunctionArguments) -> SahaCallResult {
+    let path = str_arg(&args, "path");
+    let mode = str_arg(&args, "mode");
+
+    if !is_valid_mode(&mode) {
+        let err = RuntimeError::new(&format!("Invalid file mode `{}`, expected one of `r`, `w` or `a`", mode), None);
+
+        return Err(err);
+    }
+
+    let file_type = SahaType::Name(FILE_CLASS_NAME.to_string(), Vec::new());
+
+    return fs_result(open_file(&path, &mode), file_type, &path);
+}
+
+/// Get file metadata as a dict with the `size` in bytes and the `mtime`, the
+/// last modification time in seconds since the Unix epoch.
+fn metadata(args: SahaFunctionArguments) -> SahaCallResult {
+    let path = str_arg(&args, "path");
+    let dict_of_int = SahaType::Name("Dict".to_string(), vec![Box::new(SahaType::Int)]);
+
+    let outcome = fs::metadata(&path)
+        .and_then(|meta| {
+            let mtime = meta.modified()?
+                .duration_since(UNIX_EPOCH)
+                .map_err(io::Error::other)?;
+
+            let mut data: HashMap<String, Value> = HashMap::new();
+
+            data.insert("size".to_string(), Value::int(meta.len() as isize));
+            data.insert("mtime".to_string(), Value::int(mtime.as_secs() as isize));
+
+            return Ok(create_dict_value(data, Box::new(SahaType::Int)));
+        });
+
+    return fs_result(outcome, dict_of_int, &path);
+}
@@ -0,0 +1,33 @@
+//! stdlib Assertions
+//!
+//! Assertion helpers, mostly used in functions marked with `@test`.
+
+use saha_lib::prelude::*;
+
+use crate::utils::{create_core_function, create_core_param, create_core_param_with_default};
+
+/// Register module stdlib parts.
+pub fn get_saha_functions() -> Vec<(String, CoreFunction)> {
+    let fns: Vec<(String, CoreFunction)> = vec![create_core_function(
+        "assert",
+        vec![
+            create_core_param("condition", SahaType::Bool, 0),
+            create_core_param_with_default("message", SahaType::Str, Value::str("Assertion failed".to_string()), 1)
+        ],
+        Box::new(SahaType::Void),
+        assert
+    )];
+
+    return fns;
+}
+
+/// Fail with a runtime error if the condition does not hold.
+fn assert(args: SahaFunctionArguments) -> SahaCallResult {
+    if !args["condition"].bool.unwrap() {
+        let err = RuntimeError::new(&args["message"].str.clone().unwrap(), None);
+
+        return Err(err);
+    }
+
+    return Ok(Value::void());
+}
@@ -0,0 +1,60 @@
+//! stdlib Clone
+//!
+//! The `Cloneable` behavior for copying objects. Assigning an object only
+//! copies the reference to it, `clone()` creates a new instance instead. A
+//! deep clone, `clone(deep = true)`, clones the values inside the object too,
+//! except for objects which do not implement `Cloneable`, those are shared
+//! with the original. Objects referenced many times inside the cloned object
+//! are copied once, and references back to it get the copy. A derived `clone`
+//! copies the property values into the new instance without calling `init`.
+
+use saha_lib::prelude::*;
+use saha_lib::types::{
+    objects::{BehaviorDefinition, SELF_TYPE_NAME},
+    behaviors::{run_deep_clone, add_deep_clone}
+};
+
+use crate::utils::{create_core_behavior, create_core_param_with_default, add_instance_to_symbol_table};
+
+/// Register module stdlib parts.
+pub fn get_saha_behaviors() -> Vec<BehaviorDefinition> {
+    return vec![
+        create_core_behavior(
+            "Cloneable",
+            "clone",
+            vec![("deep", clone_params()["deep"].clone())],
+            Box::new(SahaType::Name(SELF_TYPE_NAME.to_string(), Vec::new()))
+        ),
+    ];
+}
+
+/// Parameters for `clone` methods.
+pub fn clone_params() -> SahaFunctionParamDefs {
+    let mut params: SahaFunctionParamDefs = SahaFunctionParamDefs::new();
+
+    let (pname, param) = create_core_param_with_default("deep", SahaType::Bool, Value::bool(false), 0);
+
+    params.insert(pname.to_string(), param);
+
+    return params;
+}
+
+/// Add a deep clone of a core object to the symbol table. The copy is added
+/// with the values of the original first, so that values referring back to
+/// the original get the copy while `clone_values` clones them.
+pub fn add_deep_clone_to_symbol_table<T: SahaObject + Clone + 'static>(
+    original: InstRef,
+    mut copy: T,
+    clone_values: impl FnOnce(&mut T) -> Result<(), RuntimeError>
+) -> SahaCallResult {
+    return run_deep_clone(|| {
+        let instref = copy.get_instance_ref();
+        let copy_value = add_instance_to_symbol_table(instref, Box::new(copy.clone()));
+
+        add_deep_clone(original, &copy_value);
+
+        clone_values(&mut copy)?;
+
+        return Ok(add_instance_to_symbol_table(instref, Box::new(copy)));
+    });
+}
@@ -3,13 +3,19 @@
 //! Defines the internal global `Dict<T>` type object.
 
 use std::{
+    any::Any,
     collections::HashMap,
     sync::Arc
 };
 
 use saha_lib::prelude::*;
 
-use crate::stdlib::globals::option::SahaOption;
+use saha_lib::types::behaviors::{values_equal, items_hash, clone_value, display_nested_value};
+
+use crate::{
+    stdlib::globals::{option::SahaOption, clone::{clone_params, add_deep_clone_to_symbol_table}, hashed::HashedEntries},
+    utils::{get_new_instref, add_instance_to_symbol_table, create_core_param, read_core_instance, is_self_argument}
+};
 
 /// Create a new Dict instance.
 pub fn new_instance(
@@ -19,8 +25,8 @@ pub fn new_instance(
     additional_data: &SahaFunctionArguments,
     create_pos: Option<FilePosition>
 ) -> Result<Box<dyn SahaObject>, RuntimeError> {
-    if type_params.len() != 1 {
-        let err = RuntimeError::new("`Dict` expects a single type parameter `T`", create_pos);
+    if type_params.is_empty() || type_params.len() > 2 {
+        let err = RuntimeError::new("`Dict` expects a type parameter `T` and an optional key type parameter `K`", create_pos);
 
         return Err(err);
     }
@@ -31,27 +37,72 @@ pub fn new_instance(
         return Err(err);
     }
 
-    let initial_data = if additional_data.is_empty() {
-        HashMap::new()
-    } else {
-        additional_data.clone()
-    };
-
     let dict_inst = Box::new(SahaDict {
         param_type: type_params[0].clone(),
-        data: initial_data.clone(),
+        key_type: type_params.get(1).cloned(),
+        data: str_keyed_entries(additional_data.clone()),
         instref: instref
     });
 
     return Ok(dict_inst);
 }
 
-/// SahaDict is the core definition of the `Dict<T>` type in Saha.
+/// Resolve the return type of a `Dict<T, K>` method.
+pub fn method_type(type_params: &[Box<SahaType>], method_name: &str) -> Option<SahaType> {
+    return match method_name {
+        "insert" | "remove" => Some(SahaType::Void),
+        "get" => Some(SahaType::Name("Option".to_string(), vec![type_params.first()?.clone()])),
+        "equals" => Some(SahaType::Bool),
+        "hash" => Some(SahaType::Int),
+        "display" => Some(SahaType::Str),
+        "clone" => Some(SahaType::Name("Dict".to_string(), type_params.to_vec())),
+        _ => None
+    };
+}
+
+/// Create a new Dict instance from a collection of keyed values, and get the
+/// instref value object for it.
+pub fn create_dict_value(data: HashMap<String, Value>, item_type: Box<SahaType>) -> Value {
+    return create_keyed_dict_value(data, item_type, None);
+}
+
+/// Create a new Dict instance from a collection of keyed values, with the key
+/// type parameter given explicitly as `str`, e.g. `Dict<int, str>`.
+pub fn create_keyed_dict_value(data: HashMap<String, Value>, item_type: Box<SahaType>, key_type: Option<Box<SahaType>>) -> Value {
+    let instref = get_new_instref();
+
+    let dict_inst = Box::new(SahaDict {
+        param_type: item_type,
+        key_type: key_type,
+        data: str_keyed_entries(data),
+        instref: instref
+    });
+
+    return add_instance_to_symbol_table(instref, dict_inst);
+}
+
+/// Convert `str` keyed values to dict entries.
+fn str_keyed_entries(data: HashMap<String, Value>) -> HashedEntries<Value> {
+    let mut entries = HashedEntries::default();
+
+    for (key, value) in data {
+        // string keys are hashed and compared without calling user code
+        entries.insert(Value::str(key), value, &None).ok();
+    }
+
+    return entries;
+}
+
+/// SahaDict is the core definition of the `Dict<T, K>` type in Saha. Keys are
+/// `str` unless a key type `K` is given, and items are stored in insertion
+/// order, bucketed by the `Hashable` hash of their key and compared using the
+/// `Equals` behavior.
 #[derive(Clone, Debug)]
 struct SahaDict {
     instref: InstRef,
     param_type: Box<SahaType>,
-    pub data: HashMap<String, Value>
+    key_type: Option<Box<SahaType>>,
+    pub data: HashedEntries<Value>
 }
 
 impl SahaObject for SahaDict {
@@ -72,7 +123,7 @@ impl SahaObject for SahaDict {
     }
 
     fn get_implements(&self) -> Vec<String> {
-        return vec![];
+        return vec!["Cloneable".to_string(), "Equals".to_string(), "Hashable".to_string(), "Displayable".to_string()];
     }
 
     fn get_full_method_name(&mut self, _method_name: &str) -> String {
@@ -84,14 +135,17 @@ impl SahaObject for SahaDict {
     }
 
     fn get_type_params(&self) -> Vec<(char, Box<SahaType>)> {
-        return vec![('T', self.param_type.clone())];
+        return vec![('T', self.param_type.clone()), ('K', Box::new(self.key_param_type()))];
     }
 
     fn get_named_type(&self) -> Box<SahaType> {
-        return Box::new(SahaType::Name(
-            "Dict".to_string(),
-            vec![self.param_type.clone()]
-        ));
+        let mut type_params = vec![self.param_type.clone()];
+
+        if let Some(key_type) = &self.key_type {
+            type_params.push(key_type.clone());
+        }
+
+        return Box::new(SahaType::Name("Dict".to_string(), type_params));
     }
 
     fn call_member(&mut self, access: AccessParams, args: SahaFunctionArguments) -> SahaCallResult {
@@ -106,6 +160,10 @@ impl SahaObject for SahaDict {
             "insert" => self.insert(&args, access),
             "remove" => self.remove(&args, access),
             "get" => self.get(&args, access),
+            "clone" => self.clone_self(&args, access),
+            "equals" => self.equals(&args, access),
+            "hash" => self.hash(&args, access),
+            "display" => self.display(&args, access),
             _ => {
                 return Err(RuntimeError::new(
                     &format!("No method `{}` defined for `{}`", access.member_name, self.get_class_name()),
@@ -128,102 +186,105 @@ impl SahaObject for SahaDict {
     }
 
     fn into_iter(&self) -> Box<Iterator<Item = (Value, Value)>> {
-        return Box::new(self.data.clone().into_iter().map(|(key, val)| (Value::str(key), val)));
+        return Box::new(self.data.items().into_iter());
     }
 
     fn set_data_from_iter(&mut self, iterator: Box<Iterator<Item = (Value, Value)>>) {
-        self.data = iterator.map(|(key, val)| (key.str.unwrap(), val)).collect();
+        let mut data = HashedEntries::default();
+
+        for (key, val) in iterator {
+            // the keys come from this dict, so they have been hashed before
+            data.insert(key, val, &None).ok();
+        }
+
+        self.data = data;
+    }
+
+    fn as_any(&self) -> Option<&dyn Any> {
+        return Some(self);
     }
 }
 
 impl SahaDict {
+    /// Type of the dict keys.
+    fn key_param_type(&self) -> SahaType {
+        return match &self.key_type {
+            Some(key_type) => *key_type.clone(),
+            None => SahaType::Str
+        };
+    }
+
     /// Parameters for the `insert` method.
     fn insert_params(&self) -> SahaFunctionParamDefs {
-        let mut params = HashMap::new();
+        let mut params = SahaFunctionParamDefs::new();
 
-        params.insert("key".to_string(), FunctionParameter {
-            name: "key".to_string(),
-            param_type: Box::new(SahaType::Str),
-            default: Value::void()
-        });
+        let (pname, param) = create_core_param("key", self.key_param_type(), 0);
 
-        params.insert("value".to_string(), FunctionParameter {
-            name: "value".to_string(),
-            param_type: self.param_type.clone(),
-            default: Value::void()
-        });
+        params.insert(pname.to_string(), param);
+
+        let (pname, param) = create_core_param("value", *self.param_type.clone(), 1);
+
+        params.insert(pname.to_string(), param);
 
         return params;
     }
 
     /// Insert a new value for a certain key.
     pub fn insert(&mut self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
-        self.insert_params().validate_args(&args, access.access_file_pos)?;
+        let args = self.insert_params().validate_args(args, access.access_file_pos)?;
 
-        let key_str = args.get("key").unwrap().clone().str.unwrap();
-
-        self.data.insert(key_str, args.get("value").unwrap().clone());
+        self.data.insert(args["key"].clone(), args["value"].clone(), access.access_file_pos)?;
 
         return Ok(Value::void());
     }
 
     /// Params for the `remove` method.
     fn remove_params(&self) -> SahaFunctionParamDefs {
-        let mut params = HashMap::new();
+        let mut params = SahaFunctionParamDefs::new();
 
-        params.insert("key".to_string(), FunctionParameter {
-            name: "key".to_string(),
-            param_type: Box::new(SahaType::Str),
-            default: Value::void()
-        });
+        let (pname, param) = create_core_param("key", self.key_param_type(), 0);
+
+        params.insert(pname.to_string(), param);
 
         return params;
     }
 
     /// Remove an item from the dict.
     pub fn remove(&mut self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
-        self.remove_params().validate_args(&args, access.access_file_pos)?;
+        let args = self.remove_params().validate_args(args, access.access_file_pos)?;
 
-        let key_str = args["key"].clone().str.unwrap();
-
-        self.data.remove(&key_str);
+        self.data.remove(&args["key"], access.access_file_pos)?;
 
         return Ok(Value::void());
     }
 
     /// Params for the `get` method.
     fn get_params(&self) -> SahaFunctionParamDefs {
-        let mut params = HashMap::new();
+        let mut params = SahaFunctionParamDefs::new();
 
-        params.insert("key".to_string(), FunctionParameter {
-            name: "key".to_string(),
-            param_type: Box::new(SahaType::Str),
-            default: Value::void()
-        });
+        let (pname, param) = create_core_param("key", self.key_param_type(), 0);
+
+        params.insert(pname.to_string(), param);
 
         return params;
     }
 
     /// Get an item from the dict. Returns SahaOption.
     pub fn get(&mut self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
-        self.get_params().validate_args(&args, access.access_file_pos)?;
-
-        let key_str = args["key"].clone().str.unwrap();
+        let args = self.get_params().validate_args(args, access.access_file_pos)?;
 
         let opt_obj: Box<dyn SahaObject>;
         let opt_instref = crate::utils::get_new_instref();
 
-        if !self.data.contains_key(&key_str) {
-            opt_obj = SahaOption::new_none(
+        if let Some(val) = self.data.get(&args["key"], access.access_file_pos)? {
+            opt_obj = SahaOption::new_some(
                 opt_instref,
+                val,
                 self.param_type.clone()
             );
         } else {
-            let val = self.data[&key_str].clone();
-
-            opt_obj = SahaOption::new_some(
+            opt_obj = SahaOption::new_none(
                 opt_instref,
-                val.clone(),
                 self.param_type.clone()
             );
         }
@@ -232,4 +293,99 @@ impl SahaDict {
 
         return Ok(Value::obj(opt_instref));
     }
-}
+
+    /// The Dict::clone "method". A deep clone clones the values as well.
+    pub fn clone_self(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let args = clone_params().validate_args(args, access.access_file_pos)?;
+
+        let mut new_dict = self.clone();
+
+        new_dict.instref = get_new_instref();
+
+        if !args["deep"].bool.unwrap() {
+            return Ok(add_instance_to_symbol_table(new_dict.instref, Box::new(new_dict)));
+        }
+
+        return add_deep_clone_to_symbol_table(self.instref, new_dict, |copy| {
+            let mut data = HashedEntries::default();
+
+            for (key, val) in copy.data.items() {
+                data.insert(key, clone_value(&val, true, access.access_file_pos)?, access.access_file_pos)?;
+            }
+
+            copy.data = data;
+
+            return Ok(());
+        });
+    }
+
+    /// Params for the `equals` method.
+    fn other_params(&self) -> SahaFunctionParamDefs {
+        let mut params = SahaFunctionParamDefs::new();
+
+        let (pname, param) = create_core_param("other", *self.get_named_type(), 0);
+
+        params.insert(pname.to_string(), param);
+
+        return params;
+    }
+
+    /// The Dict::equals "method", also used for the `==` operator. Dicts are
+    /// equal when they have equal values for equal keys, in any order.
+    pub fn equals(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        if is_self_argument(args, self.instref) {
+            return Ok(Value::bool(true));
+        }
+
+        let args = self.other_params().validate_args(args, access.access_file_pos)?;
+        let other_data = read_core_instance(&args["other"], |other: &SahaDict| other.data.clone()).unwrap();
+
+        if self.data.len() != other_data.len() {
+            return Ok(Value::bool(false));
+        }
+
+        for (key, value) in self.data.items() {
+            match other_data.get(&key, access.access_file_pos)? {
+                Some(other_value) if values_equal(&value, &other_value, access.access_file_pos)? => {},
+                _ => return Ok(Value::bool(false))
+            }
+        }
+
+        return Ok(Value::bool(true));
+    }
+
+    /// The Dict::hash "method". The entry hashes are combined regardless of
+    /// their order, as equal dicts can have their items in a different order.
+    pub fn hash(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let params: SahaFunctionParamDefs = SahaFunctionParamDefs::new(); // no params
+
+        params.validate_args(args, access.access_file_pos)?;
+
+        let mut hash: isize = 0;
+
+        for (key, value) in self.data.items() {
+            hash = hash.wrapping_add(items_hash(&[key, value], access.access_file_pos)?);
+        }
+
+        return Ok(Value::int(hash));
+    }
+
+    /// The Dict::display "method", e.g. `{"a": 1, "b": 2}`.
+    pub fn display(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let params: SahaFunctionParamDefs = SahaFunctionParamDefs::new(); // no params
+
+        params.validate_args(args, access.access_file_pos)?;
+
+        let mut items: Vec<String> = Vec::new();
+
+        for (key, value) in self.data.items() {
+            items.push(format!(
+                "{}: {}",
+                display_nested_value(&key, access.access_file_pos)?,
+                display_nested_value(&value, access.access_file_pos)?
+            ));
+        }
+
+        return Ok(Value::str(format!("{{{}}}", items.join(", "))));
+    }
+}
\ No newline at end of file
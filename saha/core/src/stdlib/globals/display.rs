@@ -0,0 +1,18 @@
+//! stdlib Display
+//!
+//! Behaviors for hashing and displaying objects. Classes can implement these
+//! by hand or derive them, e.g. `@derive(Hashable, Displayable)`. Hashable
+//! objects can be used as `Set` items, and displayable objects can be printed.
+
+use saha_lib::prelude::*;
+use saha_lib::types::objects::BehaviorDefinition;
+
+use crate::utils::create_core_behavior;
+
+/// Register module stdlib parts.
+pub fn get_saha_behaviors() -> Vec<BehaviorDefinition> {
+    return vec![
+        create_core_behavior("Hashable", "hash", Vec::new(), Box::new(SahaType::Int)),
+        create_core_behavior("Displayable", "display", Vec::new(), Box::new(SahaType::Str)),
+    ];
+}
@@ -0,0 +1,105 @@
+//! hashed.rs
+//!
+//! Insertion ordered item storage for `Dict` and `Set`, where items are looked
+//! up by the hash of their key and hash collisions are resolved by comparing
+//! the keys with the `Equals` behavior.
+
+use std::collections::HashMap;
+
+use saha_lib::prelude::*;
+use saha_lib::types::behaviors::{value_hash, values_equal};
+
+/// Hash bucketed entries with `Value` keys.
+#[derive(Clone, Debug)]
+pub struct HashedEntries<V> {
+    entries: Vec<(isize, Value, V)>,
+    buckets: HashMap<isize, Vec<usize>>
+}
+
+impl<V> Default for HashedEntries<V> {
+    fn default() -> Self {
+        return HashedEntries {
+            entries: Vec::new(),
+            buckets: HashMap::new()
+        };
+    }
+}
+
+impl<V: Clone> HashedEntries<V> {
+    /// Find the hash of a key, and the entry index of the key if it exists.
+    fn find(&self, key: &Value, pos: &Option<FilePosition>) -> Result<(isize, Option<usize>), RuntimeError> {
+        let hash = value_hash(key, pos)?;
+
+        if let Some(bucket) = self.buckets.get(&hash) {
+            for idx in bucket {
+                if values_equal(&self.entries[*idx].1, key, pos)? {
+                    return Ok((hash, Some(*idx)));
+                }
+            }
+        }
+
+        return Ok((hash, None));
+    }
+
+    /// Get the value for a key.
+    pub fn get(&self, key: &Value, pos: &Option<FilePosition>) -> Result<Option<V>, RuntimeError> {
+        let (_, idx) = self.find(key, pos)?;
+
+        return Ok(idx.map(|idx| self.entries[idx].2.clone()));
+    }
+
+    /// Check if a key exists.
+    pub fn contains_key(&self, key: &Value, pos: &Option<FilePosition>) -> Result<bool, RuntimeError> {
+        let (_, idx) = self.find(key, pos)?;
+
+        return Ok(idx.is_some());
+    }
+
+    /// Insert a value for a key. An existing value for an equal key is
+    /// replaced, in which case `false` is returned.
+    pub fn insert(&mut self, key: Value, value: V, pos: &Option<FilePosition>) -> Result<bool, RuntimeError> {
+        let (hash, idx) = self.find(&key, pos)?;
+
+        if let Some(idx) = idx {
+            self.entries[idx].2 = value;
+
+            return Ok(false);
+        }
+
+        self.buckets.entry(hash).or_default().push(self.entries.len());
+        self.entries.push((hash, key, value));
+
+        return Ok(true);
+    }
+
+    /// Remove a key, returning the value it had.
+    pub fn remove(&mut self, key: &Value, pos: &Option<FilePosition>) -> Result<Option<V>, RuntimeError> {
+        let (_, idx) = self.find(key, pos)?;
+
+        let idx = match idx {
+            Some(idx) => idx,
+            None => return Ok(None)
+        };
+
+        let (_, _, value) = self.entries.remove(idx);
+
+        // entry indices after the removed one have shifted
+        self.buckets.clear();
+
+        for (idx, (hash, _, _)) in self.entries.iter().enumerate() {
+            self.buckets.entry(*hash).or_default().push(idx);
+        }
+
+        return Ok(Some(value));
+    }
+
+    /// Number of entries.
+    pub fn len(&self) -> usize {
+        return self.entries.len();
+    }
+
+    /// Get the keys and values in insertion order.
+    pub fn items(&self) -> Vec<(Value, V)> {
+        return self.entries.iter().map(|(_, key, value)| (key.clone(), value.clone())).collect();
+    }
+}
@@ -0,0 +1,194 @@
+//! stdlib Input
+//!
+//! Reading program input from the standard input. When the program source is
+//! itself read from the standard input, there is no input left to read.
+
+use std::{
+    io::{self, Read},
+    sync::{Arc, Mutex}
+};
+
+use saha_lib::prelude::*;
+
+use crate::{
+    stdlib::globals::{
+        option::SahaOption,
+        lines::{self, LineSource, create_lines_value}
+    },
+    utils::{create_core_function, get_new_instref, add_instance_to_symbol_table}
+};
+
+/// Class name of the standard input handle.
+pub const STDIN_CLASS_NAME: &str = "std.io.Stdin";
+
+/// Register module stdlib parts.
+pub fn get_saha_functions() -> Vec<(String, CoreFunction)> {
+    let option_of_str = SahaType::Name("Option".to_string(), vec![Box::new(SahaType::Str)]);
+    let stdin_type = SahaType::Name(STDIN_CLASS_NAME.to_string(), Vec::new());
+
+    return vec![
+        create_core_function("readLine", Vec::new(), Box::new(option_of_str), read_line),
+        create_core_function("readAll", Vec::new(), Box::new(SahaType::Str), read_all),
+        create_core_function("stdin", Vec::new(), Box::new(stdin_type), stdin),
+    ];
+}
+
+/// The standard input handle cannot be created with `new`, it is received from
+/// `stdin()`.
+pub fn new_instance(
+    _instref: InstRef,
+    _args: &SahaFunctionArguments,
+    _type_params: &[Box<SahaType>],
+    _additional_data: &SahaFunctionArguments,
+    create_pos: Option<FilePosition>
+) -> Result<Box<dyn SahaObject>, RuntimeError> {
+    let err = RuntimeError::new("`Stdin` cannot be created with `new`, use `stdin` instead", create_pos);
+
+    return Err(err);
+}
+
+/// Resolve the return type of a `Stdin` method.
+pub fn method_type(_type_params: &[Box<SahaType>], method_name: &str) -> Option<SahaType> {
+    return match method_name {
+        "lines" => Some(SahaType::Name("Lines".to_string(), Vec::new())),
+        _ => None
+    };
+}
+
+/// Read a single line from the standard input without its line ending.
+/// Returns `None` when there is no more input.
+fn read_stdin_line() -> io::Result<Option<String>> {
+    return lines::read_line(&mut io::stdin().lock());
+}
+
+/// Read the next line of input, or none when there is no more input.
+fn read_line(_args: SahaFunctionArguments) -> SahaCallResult {
+    let line = match read_stdin_line() {
+        Ok(line) => line,
+        Err(err) => return Err(RuntimeError::new(&format!("Cannot read from STDIN: {}", err), None))
+    };
+
+    return Ok(SahaOption::create_value(line.map(Value::str), Box::new(SahaType::Str)));
+}
+
+/// Read all of the remaining input.
+fn read_all(_args: SahaFunctionArguments) -> SahaCallResult {
+    let mut input = String::new();
+
+    if let Err(err) = io::stdin().lock().read_to_string(&mut input) {
+        return Err(RuntimeError::new(&format!("Cannot read from STDIN: {}", err), None));
+    }
+
+    return Ok(Value::str(input));
+}
+
+/// Get a handle to the standard input.
+fn stdin(_args: SahaFunctionArguments) -> SahaCallResult {
+    let instref = get_new_instref();
+
+    return Ok(add_instance_to_symbol_table(instref, Box::new(SahaStdin { instref: instref })));
+}
+
+/// Line source for looping over the standard input lines.
+struct StdinLines;
+
+impl LineSource for StdinLines {
+    fn next_line(&mut self) -> Option<String> {
+        return read_stdin_line().unwrap_or(None);
+    }
+}
+
+/// SahaStdin is a handle to the standard input of the program.
+#[derive(Clone)]
+struct SahaStdin {
+    instref: InstRef
+}
+
+impl SahaObject for SahaStdin {
+    fn get_instance_ref(&self) -> InstRef {
+        return self.instref;
+    }
+
+    fn is_core_defined(&self) -> bool {
+        return true;
+    }
+
+    fn get_class_name(&self) -> String {
+        return STDIN_CLASS_NAME.to_string();
+    }
+
+    fn get_fully_qualified_class_name(&self) -> String {
+        return self.get_class_name();
+    }
+
+    fn get_implements(&self) -> Vec<String> {
+        return Vec::new();
+    }
+
+    fn get_full_method_name(&mut self, _method_name: &str) -> String {
+        unimplemented!()
+    }
+
+    fn get_method_ref(&mut self, _method_name: &str) -> Result<Arc<Box<dyn SahaCallable>>, RuntimeError> {
+        unimplemented!()
+    }
+
+    fn get_type_params(&self) -> Vec<(char, Box<SahaType>)> {
+        return Vec::new();
+    }
+
+    fn get_named_type(&self) -> Box<SahaType> {
+        return Box::new(SahaType::Name(STDIN_CLASS_NAME.to_string(), Vec::new()));
+    }
+
+    fn call_member(&mut self, access: AccessParams, args: SahaFunctionArguments) -> SahaCallResult {
+        if access.is_static_access {
+            return Err(RuntimeError::new(
+                &format!("No static method `{}` defined for `{}`", access.member_name, self.get_class_name()),
+                access.access_file_pos.clone()
+            ));
+        }
+
+        match access.member_name as &str {
+            "lines" => self.lines(&args, access),
+            _ => {
+                return Err(RuntimeError::new(
+                    &format!("No method `{}` defined for `{}`", access.member_name, self.get_class_name()),
+                    access.access_file_pos.clone()
+                ));
+            }
+        }
+    }
+
+    fn access_property(&self, _access: AccessParams) -> SahaCallResult {
+        unimplemented!()
+    }
+
+    fn mutate_property(&mut self, _access: AccessParams, _new_value: Value) -> SahaCallResult {
+        unimplemented!()
+    }
+
+    fn box_clone(&self) -> Box<dyn SahaObject> {
+        return Box::new(self.clone());
+    }
+
+    fn into_iter(&self) -> Box<dyn Iterator<Item = (Value, Value)>> {
+        unimplemented!()
+    }
+
+    fn set_data_from_iter(&mut self, _iterator: Box<dyn Iterator<Item = (Value, Value)>>) {
+        unimplemented!()
+    }
+}
+
+impl SahaStdin {
+    /// The Stdin::lines "method". Returns lines which can be looped over with
+    /// `for`, reading the input one line at a time.
+    pub fn lines(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let params: SahaFunctionParamDefs = SahaFunctionParamDefs::new(); // no params
+
+        params.validate_args(args, access.access_file_pos)?;
+
+        return Ok(create_lines_value(Arc::new(Mutex::new(StdinLines))));
+    }
+}
@@ -0,0 +1,145 @@
+//! lines.rs
+//!
+//! Defines the internal `Lines` object, which lazily reads lines from a source
+//! such as a file when looped over with `for`. Lines are never read into
+//! memory all at once.
+
+use std::{
+    io::{self, BufRead},
+    sync::{Arc, Mutex}
+};
+
+use saha_lib::prelude::*;
+use saha_lib::types::objects::ITERABLE_IMPLEMENTS_NAME;
+
+use crate::utils::{get_new_instref, add_instance_to_symbol_table};
+
+/// Something lines can be read from one at a time.
+pub trait LineSource: Send {
+    /// Read the next line without its line ending, or `None` when there are no
+    /// more lines to read.
+    fn next_line(&mut self) -> Option<String>;
+}
+
+/// Read a single line from a reader without its line ending. Returns `None` at
+/// the end of the input. Invalid UTF-8 is replaced with `U+FFFD` instead of
+/// failing the read, so it does not end a loop over the lines early.
+pub fn read_line<R: BufRead>(reader: &mut R) -> io::Result<Option<String>> {
+    let mut bytes: Vec<u8> = Vec::new();
+
+    if reader.read_until(b'\n', &mut bytes)? == 0 {
+        return Ok(None);
+    }
+
+    let mut line = String::from_utf8_lossy(&bytes).into_owned();
+
+    trim_line_ending(&mut line);
+
+    return Ok(Some(line));
+}
+
+/// Remove the line ending from the end of a read line, if it has one.
+fn trim_line_ending(line: &mut String) {
+    if line.ends_with('\n') {
+        line.pop();
+
+        if line.ends_with('\r') {
+            line.pop();
+        }
+    }
+}
+
+/// Create a new Lines instance reading from a line source, and get the instref
+/// value object for it.
+pub fn create_lines_value(source: Arc<Mutex<dyn LineSource>>) -> Value {
+    let instref = get_new_instref();
+
+    let lines_inst = Box::new(SahaLines {
+        instref: instref,
+        source: source
+    });
+
+    return add_instance_to_symbol_table(instref, lines_inst);
+}
+
+/// SahaLines is a lazy iterable over the lines of a line source. Each loop
+/// continues reading from where the source currently is.
+#[derive(Clone)]
+struct SahaLines {
+    instref: InstRef,
+    source: Arc<Mutex<dyn LineSource>>
+}
+
+impl SahaObject for SahaLines {
+    fn get_instance_ref(&self) -> InstRef {
+        return self.instref;
+    }
+
+    fn is_core_defined(&self) -> bool {
+        return true;
+    }
+
+    fn get_class_name(&self) -> String {
+        return "Lines".to_string();
+    }
+
+    fn get_fully_qualified_class_name(&self) -> String {
+        return self.get_class_name();
+    }
+
+    fn get_implements(&self) -> Vec<String> {
+        return vec![ITERABLE_IMPLEMENTS_NAME.to_string()];
+    }
+
+    fn get_full_method_name(&mut self, _method_name: &str) -> String {
+        unimplemented!()
+    }
+
+    fn get_method_ref(&mut self, _method_name: &str) -> Result<Arc<Box<dyn SahaCallable>>, RuntimeError> {
+        unimplemented!()
+    }
+
+    fn get_type_params(&self) -> Vec<(char, Box<SahaType>)> {
+        return Vec::new();
+    }
+
+    fn get_named_type(&self) -> Box<SahaType> {
+        return Box::new(SahaType::Name("Lines".to_string(), Vec::new()));
+    }
+
+    fn call_member(&mut self, access: AccessParams, _args: SahaFunctionArguments) -> SahaCallResult {
+        return Err(RuntimeError::new(
+            &format!("No method `{}` defined for `{}`", access.member_name, self.get_class_name()),
+            access.access_file_pos.clone()
+        ));
+    }
+
+    fn access_property(&self, _access: AccessParams) -> SahaCallResult {
+        unimplemented!()
+    }
+
+    fn mutate_property(&mut self, _access: AccessParams, _new_value: Value) -> SahaCallResult {
+        unimplemented!()
+    }
+
+    fn box_clone(&self) -> Box<dyn SahaObject> {
+        return Box::new(self.clone());
+    }
+
+    fn into_iter(&self) -> Box<dyn Iterator<Item = (Value, Value)>> {
+        let source = self.source.clone();
+        let mut idx = 0;
+
+        return Box::new(std::iter::from_fn(move || {
+            let line = source.lock().unwrap().next_line()?;
+
+            idx += 1;
+
+            return Some((Value::int(idx - 1), Value::str(line)));
+        }));
+    }
+
+    fn set_data_from_iter(&mut self, _iterator: Box<dyn Iterator<Item = (Value, Value)>>) {
+        unimplemented!()
+    }
+}
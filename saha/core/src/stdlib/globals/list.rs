@@ -3,11 +3,17 @@
 //! Defines the internal global `List<T>` type object.
 
 use std::{
-    collections::HashMap,
+    any::Any,
     sync::Arc
 };
 
 use saha_lib::prelude::*;
+use saha_lib::types::behaviors::{values_equal, items_equal, items_hash, clone_value, display_nested_value};
+
+use crate::{
+    stdlib::globals::clone::{clone_params, add_deep_clone_to_symbol_table},
+    utils::{get_new_instref, add_instance_to_symbol_table, create_core_param, read_core_instance, is_self_argument}
+};
 
 /// Create a new List instance.
 pub fn new_instance(
@@ -32,7 +38,14 @@ pub fn new_instance(
     let initial_data = if additional_data.is_empty() {
         vec![]
     } else {
-        additional_data.iter().map(|(_, i)| i.clone()).collect()
+        // initial items are keyed `item_N`, sort them back to their original order
+        let mut items: Vec<(usize, Value)> = additional_data.iter().map(|(k, i)| {
+            (k.trim_start_matches("item_").parse::<usize>().unwrap_or(0), i.clone())
+        }).collect();
+
+        items.sort_by_key(|(idx, _)| *idx);
+
+        items.into_iter().map(|(_, i)| i).collect()
     };
 
     let list_inst = Box::new(SahaList {
@@ -45,6 +58,34 @@ pub fn new_instance(
     return Ok(list_inst);
 }
 
+/// Resolve the return type of a `List<T>` method.
+pub fn method_type(type_params: &[Box<SahaType>], method_name: &str) -> Option<SahaType> {
+    return match method_name {
+        "push" => Some(SahaType::Void),
+        "count" => Some(SahaType::Int),
+        "contains" | "equals" => Some(SahaType::Bool),
+        "hash" => Some(SahaType::Int),
+        "display" => Some(SahaType::Str),
+        "clone" => Some(SahaType::Name("List".to_string(), type_params.to_vec())),
+        _ => None
+    };
+}
+
+/// Create a new List instance from a collection of values, and get the
+/// instref value object for it.
+pub fn create_list_value(items: Vec<Value>, item_type: Box<SahaType>) -> Value {
+    let instref = get_new_instref();
+
+    let list_inst = Box::new(SahaList {
+        param_type: item_type,
+        data: items,
+        instref: instref,
+        cursor_position: 0
+    });
+
+    return add_instance_to_symbol_table(instref, list_inst);
+}
+
 /// SahaList is the core definition of the `List<T>` type in Saha.
 #[derive(Clone, Debug)]
 struct SahaList {
@@ -72,7 +113,7 @@ impl SahaObject for SahaList {
     }
 
     fn get_implements(&self) -> Vec<String> {
-        return vec![];
+        return vec!["Cloneable".to_string(), "Equals".to_string(), "Hashable".to_string(), "Displayable".to_string()];
     }
 
     fn get_full_method_name(&mut self, _method_name: &str) -> String {
@@ -105,6 +146,11 @@ impl SahaObject for SahaList {
         match access.member_name as &str {
             "push" => self.push(&args, access),
             "count" => self.count(&args, access),
+            "contains" => self.contains(&args, access),
+            "clone" => self.clone_self(&args, access),
+            "equals" => self.equals(&args, access),
+            "hash" => self.hash(&args, access),
+            "display" => self.display(&args, access),
             _ => {
                 return Err(RuntimeError::new(
                     &format!("No method `{}` defined for `{}`", access.member_name, self.get_class_name()),
@@ -133,25 +179,28 @@ impl SahaObject for SahaList {
     fn set_data_from_iter(&mut self, iterator: Box<Iterator<Item = (Value, Value)>>) {
         self.data = iterator.map(|(_, val)| val).collect();
     }
+
+    fn as_any(&self) -> Option<&dyn Any> {
+        return Some(self);
+    }
 }
 
 impl SahaList {
-    /// Get function parameter definition for the List::push method.
+    /// Get function parameter definition for the List::push and
+    /// List::contains methods.
     fn push_params(&self) -> SahaFunctionParamDefs {
-        let mut params: SahaFunctionParamDefs = HashMap::new();
+        let mut params: SahaFunctionParamDefs = SahaFunctionParamDefs::new();
 
-        params.insert("value".to_string(), FunctionParameter {
-            name: "value".to_string(),
-            param_type: self.param_type.clone(),
-            default: Value::void()
-        });
+        let (pname, param) = create_core_param("value", *self.param_type.clone(), 0);
+
+        params.insert(pname.to_string(), param);
 
         return params;
     }
 
     /// The List::push "method".
     pub fn push(&mut self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
-        self.push_params().validate_args(&args, access.access_file_pos)?;
+        let args = self.push_params().validate_args(args, access.access_file_pos)?;
 
         let pushed_val = args.get("value").unwrap();
 
@@ -162,7 +211,7 @@ impl SahaList {
 
     /// The List::count "method".
     pub fn count(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
-        let params: SahaFunctionParamDefs = HashMap::new(); // no params
+        let params: SahaFunctionParamDefs = SahaFunctionParamDefs::new(); // no params
 
         params.validate_args(&args, access.access_file_pos)?;
 
@@ -170,4 +219,88 @@ impl SahaList {
 
         return Ok(Value::int(count as isize));
     }
+
+    /// The List::contains "method". Items are compared using the `Equals`
+    /// behavior.
+    pub fn contains(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let args = self.push_params().validate_args(args, access.access_file_pos)?;
+
+        let value = args.get("value").unwrap();
+
+        for item in &self.data {
+            if values_equal(item, value, access.access_file_pos)? {
+                return Ok(Value::bool(true));
+            }
+        }
+
+        return Ok(Value::bool(false));
+    }
+
+    /// The List::clone "method". A deep clone clones the items as well.
+    pub fn clone_self(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let args = clone_params().validate_args(args, access.access_file_pos)?;
+
+        let mut new_list = self.clone();
+
+        new_list.instref = get_new_instref();
+
+        if !args["deep"].bool.unwrap() {
+            return Ok(add_instance_to_symbol_table(new_list.instref, Box::new(new_list)));
+        }
+
+        return add_deep_clone_to_symbol_table(self.instref, new_list, |copy| {
+            copy.data = copy.data.iter()
+                .map(|item| clone_value(item, true, access.access_file_pos))
+                .collect::<Result<Vec<Value>, RuntimeError>>()?;
+
+            return Ok(());
+        });
+    }
+
+    /// Get function parameter definitions for methods which take another list
+    /// as `other`.
+    fn other_params(&self) -> SahaFunctionParamDefs {
+        let mut params: SahaFunctionParamDefs = SahaFunctionParamDefs::new();
+
+        let (pname, param) = create_core_param("other", *self.get_named_type(), 0);
+
+        params.insert(pname.to_string(), param);
+
+        return params;
+    }
+
+    /// The List::equals "method", also used for the `==` operator. Lists are
+    /// equal when their items are equal in the same order.
+    pub fn equals(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        if is_self_argument(args, self.instref) {
+            return Ok(Value::bool(true));
+        }
+
+        let args = self.other_params().validate_args(args, access.access_file_pos)?;
+        let other_items = read_core_instance(&args["other"], |other: &SahaList| other.data.clone()).unwrap();
+
+        return Ok(Value::bool(items_equal(&self.data, &other_items, access.access_file_pos)?));
+    }
+
+    /// The List::hash "method", combines the hashes of the items.
+    pub fn hash(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let params: SahaFunctionParamDefs = SahaFunctionParamDefs::new(); // no params
+
+        params.validate_args(args, access.access_file_pos)?;
+
+        return Ok(Value::int(items_hash(&self.data, access.access_file_pos)?));
+    }
+
+    /// The List::display "method", e.g. `[1, 2, 3]`.
+    pub fn display(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let params: SahaFunctionParamDefs = SahaFunctionParamDefs::new(); // no params
+
+        params.validate_args(args, access.access_file_pos)?;
+
+        let items = self.data.iter()
+            .map(|item| display_nested_value(item, access.access_file_pos))
+            .collect::<Result<Vec<String>, RuntimeError>>()?;
+
+        return Ok(Value::str(format!("[{}]", items.join(", "))));
+    }
 }
\ No newline at end of file
@@ -3,7 +3,15 @@
 //! These are the "global" namespace stdlib members.
 
 pub mod print;
+pub mod input;
 pub mod list;
 pub mod dict;
+pub mod set;
+pub mod hashed;
 pub mod result;
-pub mod option;
\ No newline at end of file
+pub mod option;
+pub mod operators;
+pub mod display;
+pub mod clone;
+pub mod assert;
+pub mod lines;
@@ -0,0 +1,34 @@
+//! stdlib Operators
+//!
+//! Behaviors which allow userland classes to overload operators. When the left
+//! hand side operand of a binary operation is an object implementing one of
+//! these, the operation is dispatched to the behavior method.
+
+use saha_lib::prelude::*;
+use saha_lib::types::objects::{BehaviorDefinition, SELF_TYPE_NAME};
+
+use crate::utils::{create_core_behavior, create_core_param};
+
+/// Register module stdlib parts.
+pub fn get_saha_behaviors() -> Vec<BehaviorDefinition> {
+    let self_type = || Box::new(SahaType::Name(SELF_TYPE_NAME.to_string(), Vec::new()));
+
+    return vec![
+        create_operator_behavior("Add", "add", self_type()),
+        create_operator_behavior("Sub", "sub", self_type()),
+        create_operator_behavior("Mul", "mul", self_type()),
+        create_operator_behavior("Equals", "equals", Box::new(SahaType::Bool)),
+        create_operator_behavior("Comparable", "compare", Box::new(SahaType::Int)),
+    ];
+}
+
+/// Create an operator behavior with a single method, which receives the right
+/// hand side operand as an `other` parameter of the implementing class type.
+fn create_operator_behavior(name: &str, method_name: &str, return_type: Box<SahaType>) -> BehaviorDefinition {
+    return create_core_behavior(
+        name,
+        method_name,
+        vec![create_core_param("other", SahaType::Name(SELF_TYPE_NAME.to_string(), Vec::new()), 0)],
+        return_type
+    );
+}
@@ -3,9 +3,18 @@
 //! Anything related to the `Option<T>` type used for error and result
 //! management in Saha. Very similar to the `Option` type in Rust.
 
-use std::sync::Arc;
+use std::{
+    any::Any,
+    sync::Arc
+};
 
 use saha_lib::prelude::*;
+use saha_lib::types::behaviors::{values_equal, items_hash, clone_value, display_nested_value};
+
+use crate::{
+    stdlib::globals::clone::{clone_params, add_deep_clone_to_symbol_table},
+    utils::{get_new_instref, add_instance_to_symbol_table, create_core_param, read_core_instance, is_self_argument}
+};
 
 /// Create a new Option instance.
 pub fn new_instance(
@@ -15,7 +24,7 @@ pub fn new_instance(
     additional_data: &SahaFunctionArguments,
     create_pos: Option<FilePosition>
 ) -> Result<Box<dyn SahaObject>, RuntimeError> {
-    if type_params.len() != 2 {
+    if type_params.len() != 1 {
         let err = RuntimeError::new("`Option` expects a type parameter `T`", create_pos);
 
         return Err(err);
@@ -32,12 +41,16 @@ pub fn new_instance(
     let initial_value: Value;
     let is_initially_some: bool;
 
-    if additional_data.is_empty() {
-        initial_value = Value::void();
-        is_initially_some = false;
-    } else {
-        unimplemented!()
-    }
+    match additional_data.get("value") {
+        Some(value) => {
+            initial_value = value.clone();
+            is_initially_some = true;
+        },
+        None => {
+            initial_value = Value::void();
+            is_initially_some = false;
+        }
+    };
 
     let option_inst = Box::new(SahaOption {
         option_type: type_params[0].clone(),
@@ -49,6 +62,18 @@ pub fn new_instance(
     return Ok(option_inst);
 }
 
+/// Resolve the return type of an `Option<T>` method.
+pub fn method_type(type_params: &[Box<SahaType>], method_name: &str) -> Option<SahaType> {
+    return match method_name {
+        "isSome" | "isNone" | "equals" => Some(SahaType::Bool),
+        "unwrap" => Some(*type_params.first()?.clone()),
+        "hash" => Some(SahaType::Int),
+        "display" => Some(SahaType::Str),
+        "clone" => Some(SahaType::Name("Option".to_string(), type_params.to_vec())),
+        _ => None
+    };
+}
+
 /// SahaOption is the option type generic class for optional outcomes in Saha.
 #[derive(Clone)]
 pub struct SahaOption {
@@ -76,7 +101,7 @@ impl SahaObject for SahaOption {
     }
 
     fn get_implements(&self) -> Vec<String> {
-        return vec![];
+        return vec!["Equals".to_string(), "Hashable".to_string(), "Displayable".to_string(), "Cloneable".to_string()];
     }
 
     fn get_full_method_name(&mut self, _method_name: &str) -> String {
@@ -100,7 +125,7 @@ impl SahaObject for SahaOption {
         ));
     }
 
-    fn call_member(&mut self, access: AccessParams, _args: SahaFunctionArguments) -> SahaCallResult {
+    fn call_member(&mut self, access: AccessParams, args: SahaFunctionArguments) -> SahaCallResult {
         if access.is_static_access {
             match access.member_name as &str {
                 _ => Err(RuntimeError::new(
@@ -125,6 +150,10 @@ impl SahaObject for SahaOption {
 
                     Ok(self.option_value.clone())
                 },
+                "equals" => self.equals(&args, access),
+                "hash" => self.hash(&args, access),
+                "display" => self.display(&args, access),
+                "clone" => self.clone_self(&args, access),
                 _ => {
                     Err(RuntimeError::new(
                         &format!("No method `{}` defined for `{}`", access.member_name, self.get_class_name()),
@@ -154,9 +183,26 @@ impl SahaObject for SahaOption {
     fn set_data_from_iter(&mut self, _iterator: Box<Iterator<Item = (Value, Value)>>) {
         unimplemented!()
     }
+
+    fn as_any(&self) -> Option<&dyn Any> {
+        return Some(self);
+    }
 }
 
 impl SahaOption {
+    /// Create a new Option instance from a Rust option, and get the instref
+    /// value object for it.
+    pub fn create_value(value: Option<Value>, option_type: Box<SahaType>) -> Value {
+        let instref = get_new_instref();
+
+        let option_inst = match value {
+            Some(value) => SahaOption::new_some(instref, value, option_type),
+            None => SahaOption::new_none(instref, option_type)
+        };
+
+        return add_instance_to_symbol_table(instref, option_inst);
+    }
+
     /// Create a new some typed SahaOption.
     pub fn new_some(instref: InstRef, value: Value, option_type: Box<SahaType>) -> Box<dyn SahaObject> {
         return Box::new(SahaOption {
@@ -186,4 +232,79 @@ impl SahaOption {
     pub fn is_none(&self) -> SahaCallResult {
         return Ok(Value::bool(!self.is_some));
     }
+
+    /// Get function parameter definitions for methods which take another
+    /// option as `other`.
+    fn other_params(&self) -> SahaFunctionParamDefs {
+        let mut params: SahaFunctionParamDefs = SahaFunctionParamDefs::new();
+
+        let (pname, param) = create_core_param("other", *self.get_named_type(), 0);
+
+        params.insert(pname.to_string(), param);
+
+        return params;
+    }
+
+    /// The Option::equals "method", also used for the `==` operator. Options
+    /// are equal when both are none, or both contain equal values.
+    pub fn equals(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        if is_self_argument(args, self.instref) {
+            return Ok(Value::bool(true));
+        }
+
+        let args = self.other_params().validate_args(args, access.access_file_pos)?;
+        let (other_is_some, other_value) = read_core_instance(&args["other"], |other: &SahaOption| {
+            (other.is_some, other.option_value.clone())
+        }).unwrap();
+
+        let is_equal = match (self.is_some, other_is_some) {
+            (true, true) => values_equal(&self.option_value, &other_value, access.access_file_pos)?,
+            (false, false) => true,
+            _ => false
+        };
+
+        return Ok(Value::bool(is_equal));
+    }
+
+    /// The Option::hash "method".
+    pub fn hash(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let params: SahaFunctionParamDefs = SahaFunctionParamDefs::new(); // no params
+
+        params.validate_args(args, access.access_file_pos)?;
+
+        let hash = items_hash(&[Value::bool(self.is_some), self.option_value.clone()], access.access_file_pos)?;
+
+        return Ok(Value::int(hash));
+    }
+
+    /// The Option::display "method", e.g. `Some(1)` or `None`.
+    pub fn display(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let params: SahaFunctionParamDefs = SahaFunctionParamDefs::new(); // no params
+
+        params.validate_args(args, access.access_file_pos)?;
+
+        if !self.is_some {
+            return Ok(Value::str("None".to_string()));
+        }
+
+        return Ok(Value::str(format!("Some({})", display_nested_value(&self.option_value, access.access_file_pos)?)));
+    }
+    /// The Option::clone "method". A deep clone clones the value as well.
+    pub fn clone_self(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let args = clone_params().validate_args(args, access.access_file_pos)?;
+
+        let mut new_option = self.clone();
+
+        new_option.instref = get_new_instref();
+
+        if !args["deep"].bool.unwrap() {
+            return Ok(add_instance_to_symbol_table(new_option.instref, Box::new(new_option)));
+        }
+
+        return add_deep_clone_to_symbol_table(self.instref, new_option, |copy| {
+            copy.option_value = clone_value(&copy.option_value, true, access.access_file_pos)?;
+
+            return Ok(());
+        });
+    }
 }
\ No newline at end of file
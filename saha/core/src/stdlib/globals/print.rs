@@ -1,10 +1,12 @@
 //! stdlib Printing
 //!
-//! Generic output helpers.
+//! Generic output helpers. Anything `Displayable` can be printed, which
+//! includes primitive values.
 
 use saha_lib::prelude::*;
+use saha_lib::types::behaviors::display_value;
 
-use crate::utils::create_core_function;
+use crate::utils::{create_core_function, create_core_param};
 
 /// Register module stdlib parts.
 pub fn get_saha_functions() -> Vec<(String, CoreFunction)> {
@@ -12,52 +14,28 @@ pub fn get_saha_functions() -> Vec<(String, CoreFunction)> {
 
     fns.push(create_core_function(
         "print",
-        vec![
-            ("text", FunctionParameter {
-                name: "text".to_string(),
-                param_type: Box::new(SahaType::Str),
-                default: Value::void()
-            })
-        ],
+        vec![create_core_param("text", SahaType::Name("Displayable".to_string(), Vec::new()), 0)],
         Box::new(SahaType::Void),
         print
     ));
 
     fns.push(create_core_function(
         "print_line",
-        vec![
-            ("text", FunctionParameter {
-                name: "text".to_string(),
-                param_type: Box::new(SahaType::Str),
-                default: Value::void()
-            })
-        ],
+        vec![create_core_param("text", SahaType::Name("Displayable".to_string(), Vec::new()), 0)],
         Box::new(SahaType::Void),
         print_line
     ));
 
     fns.push(create_core_function(
         "print_err",
-        vec![
-            ("text", FunctionParameter {
-                name: "text".to_string(),
-                param_type: Box::new(SahaType::Str),
-                default: Value::void()
-            })
-        ],
+        vec![create_core_param("text", SahaType::Name("Displayable".to_string(), Vec::new()), 0)],
         Box::new(SahaType::Void),
         print_err
     ));
 
     fns.push(create_core_function(
         "print_err_line",
-        vec![
-            ("text", FunctionParameter {
-                name: "text".to_string(),
-                param_type: Box::new(SahaType::Str),
-                default: Value::void()
-            })
-        ],
+        vec![create_core_param("text", SahaType::Name("Displayable".to_string(), Vec::new()), 0)],
         Box::new(SahaType::Void),
         print_err_line
     ));
@@ -66,7 +44,7 @@ pub fn get_saha_functions() -> Vec<(String, CoreFunction)> {
 }
 
 fn print(args: SahaFunctionArguments) -> SahaCallResult {
-    let to_print = args["text"].clone().str.unwrap();
+    let to_print = display_value(&args["text"], &None)?;
 
     print!("{}", to_print);
 
@@ -74,7 +52,7 @@ fn print(args: SahaFunctionArguments) -> SahaCallResult {
 }
 
 fn print_line(args: SahaFunctionArguments) -> SahaCallResult {
-    let to_print = args["text"].clone().str.unwrap();
+    let to_print = display_value(&args["text"], &None)?;
 
     println!("{}", to_print);
 
@@ -82,7 +60,7 @@ fn print_line(args: SahaFunctionArguments) -> SahaCallResult {
 }
 
 fn print_err(args: SahaFunctionArguments) -> SahaCallResult {
-    let to_print = args["text"].clone().str.unwrap();
+    let to_print = display_value(&args["text"], &None)?;
 
     eprint!("{}", to_print);
 
@@ -90,7 +68,7 @@ fn print_err(args: SahaFunctionArguments) -> SahaCallResult {
 }
 
 fn print_err_line(args: SahaFunctionArguments) -> SahaCallResult {
-    let to_print = args["text"].clone().str.unwrap();
+    let to_print = display_value(&args["text"], &None)?;
 
     eprintln!("{}", to_print);
 
@@ -4,11 +4,17 @@
 //! management in Saha. Very similar to the `Result` type in Rust.
 
 use std::{
-    collections::HashMap,
+    any::Any,
     sync::Arc
 };
 
 use saha_lib::prelude::*;
+use saha_lib::types::behaviors::{values_equal, items_hash, clone_value, display_nested_value};
+
+use crate::{
+    stdlib::globals::clone::{clone_params, add_deep_clone_to_symbol_table},
+    utils::{get_new_instref, add_instance_to_symbol_table, create_core_param, read_core_instance, is_self_argument}
+};
 
 /// Create a new Result instance.
 pub fn new_instance(
@@ -35,11 +41,15 @@ pub fn new_instance(
     let initial_value: Value;
     let is_initially_success: bool;
 
-    if additional_data.is_empty() {
-        initial_value = Value::void();
+    if let Some(value) = additional_data.get("success") {
+        initial_value = value.clone();
+        is_initially_success = true;
+    } else if let Some(value) = additional_data.get("failure") {
+        initial_value = value.clone();
         is_initially_success = false;
     } else {
-        unimplemented!()
+        initial_value = Value::void();
+        is_initially_success = false;
     }
 
     let result_inst = Box::new(SahaResult {
@@ -53,6 +63,19 @@ pub fn new_instance(
     return Ok(result_inst);
 }
 
+/// Resolve the return type of a `Result<T, E>` method.
+pub fn method_type(type_params: &[Box<SahaType>], method_name: &str) -> Option<SahaType> {
+    return match method_name {
+        "succeed" | "fail" => Some(SahaType::Void),
+        "isSuccess" | "isFailed" | "equals" => Some(SahaType::Bool),
+        "unwrap" => Some(*type_params.first()?.clone()),
+        "hash" => Some(SahaType::Int),
+        "display" => Some(SahaType::Str),
+        "clone" => Some(SahaType::Name("Result".to_string(), type_params.to_vec())),
+        _ => None
+    };
+}
+
 /// SahaResult is the result type generic class for result outcomes in Saha.
 #[derive(Clone)]
 pub struct SahaResult {
@@ -81,7 +104,7 @@ impl SahaObject for SahaResult {
     }
 
     fn get_implements(&self) -> Vec<String> {
-        return vec![];
+        return vec!["Equals".to_string(), "Hashable".to_string(), "Displayable".to_string(), "Cloneable".to_string()];
     }
 
     fn get_full_method_name(&mut self, _method_name: &str) -> String {
@@ -121,6 +144,10 @@ impl SahaObject for SahaResult {
                 "isSuccess" => Ok(Value::bool(self.is_success)),
                 "isFailed" => Ok(Value::bool(!self.is_success)),
                 "unwrap" => Ok(self.result_value.clone()),
+                "equals" => self.equals(&args, access),
+                "hash" => self.hash(&args, access),
+                "display" => self.display(&args, access),
+                "clone" => self.clone_self(&args, access),
                 _ => {
                     Err(RuntimeError::new(
                         &format!("No method `{}` defined for `{}`", access.member_name, self.get_class_name()),
@@ -150,6 +177,10 @@ impl SahaObject for SahaResult {
     fn set_data_from_iter(&mut self, _iterator: Box<Iterator<Item = (Value, Value)>>) {
         unimplemented!()
     }
+
+    fn as_any(&self) -> Option<&dyn Any> {
+        return Some(self);
+    }
 }
 
 impl SahaResult {
@@ -173,22 +204,33 @@ impl SahaResult {
         });
     }
 
+    /// Create a new Result instance from a Rust result, and get the instref
+    /// value object for it.
+    pub fn create_value(outcome: Result<Value, Value>, success_type: Box<SahaType>, fail_type: Box<SahaType>) -> Value {
+        let instref = get_new_instref();
+
+        let result_inst = match outcome {
+            Ok(value) => SahaResult::new_success(instref, value, success_type, fail_type),
+            Err(value) => SahaResult::new_failure(instref, value, success_type, fail_type)
+        };
+
+        return add_instance_to_symbol_table(instref, result_inst);
+    }
+
     /// This defines the parameters the succeed function requires.
     fn succeed_params(&self) -> SahaFunctionParamDefs {
-        let mut params = HashMap::new();
+        let mut params = SahaFunctionParamDefs::new();
 
-        params.insert("value".to_string(), FunctionParameter {
-            name: "value".to_string(),
-            param_type: self.success_type.clone(),
-            default: Value::void()
-        });
+        let (pname, param) = create_core_param("value", *self.success_type.clone(), 0);
+
+        params.insert(pname.to_string(), param);
 
         return params;
     }
 
     /// This makes the Result succeed with a given value.
     pub fn succeed(&mut self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
-        self.succeed_params().validate_args(&args, access.access_file_pos)?;
+        let args = self.succeed_params().validate_args(args, access.access_file_pos)?;
 
         self.is_success = true;
         self.result_value = args["value"].clone();
@@ -198,24 +240,91 @@ impl SahaResult {
 
     /// This defines the parameters the fail function requires.
     fn fail_params(&self) -> SahaFunctionParamDefs {
-        let mut params = HashMap::new();
+        let mut params = SahaFunctionParamDefs::new();
 
-        params.insert("value".to_string(), FunctionParameter {
-            name: "value".to_string(),
-            param_type: self.success_type.clone(),
-            default: Value::void()
-        });
+        let (pname, param) = create_core_param("value", *self.fail_type.clone(), 0);
+
+        params.insert(pname.to_string(), param);
 
         return params;
     }
 
     /// This makes the Result fail with a given value.
     pub fn fail(&mut self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
-        self.fail_params().validate_args(&args, access.access_file_pos)?;
+        let args = self.fail_params().validate_args(args, access.access_file_pos)?;
 
         self.is_success = false;
         self.result_value = args["value"].clone();
 
         return Ok(Value::void());
     }
+
+    /// This defines the parameters the equals function requires.
+    fn other_params(&self) -> SahaFunctionParamDefs {
+        let mut params = SahaFunctionParamDefs::new();
+
+        let (pname, param) = create_core_param("other", *self.get_named_type(), 0);
+
+        params.insert(pname.to_string(), param);
+
+        return params;
+    }
+
+    /// The Result::equals "method", also used for the `==` operator. Results
+    /// are equal when both succeeded or both failed with equal values.
+    pub fn equals(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        if is_self_argument(args, self.instref) {
+            return Ok(Value::bool(true));
+        }
+
+        let args = self.other_params().validate_args(args, access.access_file_pos)?;
+        let (other_is_success, other_value) = read_core_instance(&args["other"], |other: &SahaResult| {
+            (other.is_success, other.result_value.clone())
+        }).unwrap();
+
+        let is_equal = self.is_success == other_is_success
+            && values_equal(&self.result_value, &other_value, access.access_file_pos)?;
+
+        return Ok(Value::bool(is_equal));
+    }
+
+    /// The Result::hash "method".
+    pub fn hash(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let params: SahaFunctionParamDefs = SahaFunctionParamDefs::new(); // no params
+
+        params.validate_args(args, access.access_file_pos)?;
+
+        let hash = items_hash(&[Value::bool(self.is_success), self.result_value.clone()], access.access_file_pos)?;
+
+        return Ok(Value::int(hash));
+    }
+
+    /// The Result::display "method", e.g. `Success(1)` or `Failure("error")`.
+    pub fn display(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let params: SahaFunctionParamDefs = SahaFunctionParamDefs::new(); // no params
+
+        params.validate_args(args, access.access_file_pos)?;
+
+        let outcome = if self.is_success { "Success" } else { "Failure" };
+
+        return Ok(Value::str(format!("{}({})", outcome, display_nested_value(&self.result_value, access.access_file_pos)?)));
+    }
+    /// The Result::clone "method". A deep clone clones the value as well.
+    pub fn clone_self(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let args = clone_params().validate_args(args, access.access_file_pos)?;
+
+        let mut new_result = self.clone();
+
+        new_result.instref = get_new_instref();
+
+        if !args["deep"].bool.unwrap() {
+            return Ok(add_instance_to_symbol_table(new_result.instref, Box::new(new_result)));
+        }
+
+        return add_deep_clone_to_symbol_table(self.instref, new_result, |copy| {
+            copy.result_value = clone_value(&copy.result_value, true, access.access_file_pos)?;
+
+            return Ok(());
+        });
+    }
 }
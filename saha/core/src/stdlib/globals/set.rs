@@ -0,0 +1,305 @@
+//! set.rs
+//!
+//! Defines the internal global `Set<T>` type object.
+
+use std::{
+    any::Any,
+    sync::Arc
+};
+
+use saha_lib::prelude::*;
+use saha_lib::types::behaviors::{value_hash, clone_value, display_nested_value};
+
+use crate::{
+    stdlib::globals::{clone::{clone_params, add_deep_clone_to_symbol_table}, hashed::HashedEntries},
+    utils::{get_new_instref, add_instance_to_symbol_table, create_core_param, read_core_instance, is_self_argument}
+};
+
+/// Create a new Set instance.
+pub fn new_instance(
+    instref: InstRef,
+    args: &SahaFunctionArguments,
+    type_params: &[Box<SahaType>],
+    _additional_data: &SahaFunctionArguments,
+    create_pos: Option<FilePosition>
+) -> Result<Box<dyn SahaObject>, RuntimeError> {
+    if type_params.len() != 1 {
+        let err = RuntimeError::new("`Set` expects a single type parameter `T`", create_pos);
+
+        return Err(err);
+    }
+
+    if !args.is_empty() {
+        let err = RuntimeError::new("`Set` expects no arguments", create_pos);
+
+        return Err(err);
+    }
+
+    let set_inst = Box::new(SahaSet {
+        param_type: type_params[0].clone(),
+        data: HashedEntries::default(),
+        instref: instref
+    });
+
+    return Ok(set_inst);
+}
+
+/// Resolve the return type of a `Set<T>` method.
+pub fn method_type(type_params: &[Box<SahaType>], method_name: &str) -> Option<SahaType> {
+    return match method_name {
+        "insert" | "contains" | "remove" | "equals" => Some(SahaType::Bool),
+        "count" | "hash" => Some(SahaType::Int),
+        "display" => Some(SahaType::Str),
+        "clone" => Some(SahaType::Name("Set".to_string(), type_params.to_vec())),
+        _ => None
+    };
+}
+
+/// SahaSet is the core definition of the `Set<T>` type in Saha. Items are
+/// stored in insertion order, bucketed by their `Hashable` hash and compared
+/// using the `Equals` behavior.
+#[derive(Clone, Debug)]
+struct SahaSet {
+    instref: InstRef,
+    param_type: Box<SahaType>,
+    pub data: HashedEntries<()>
+}
+
+impl SahaObject for SahaSet {
+    fn get_instance_ref(&self) -> InstRef {
+        return self.instref;
+    }
+
+    fn is_core_defined(&self) -> bool {
+        return true;
+    }
+
+    fn get_class_name(&self) -> String {
+        return "Set".to_string();
+    }
+
+    fn get_fully_qualified_class_name(&self) -> String {
+        return self.get_class_name();
+    }
+
+    fn get_implements(&self) -> Vec<String> {
+        return vec!["Cloneable".to_string(), "Equals".to_string(), "Hashable".to_string(), "Displayable".to_string()];
+    }
+
+    fn get_full_method_name(&mut self, _method_name: &str) -> String {
+        unimplemented!()
+    }
+
+    fn get_method_ref(&mut self, _method_name: &str) -> Result<Arc<Box<dyn SahaCallable>>, RuntimeError> {
+        unimplemented!()
+    }
+
+    fn get_type_params(&self) -> Vec<(char, Box<SahaType>)> {
+        return vec![('T', self.param_type.clone())];
+    }
+
+    fn get_named_type(&self) -> Box<SahaType> {
+        return Box::new(SahaType::Name(
+            "Set".to_string(),
+            vec![self.param_type.clone()]
+        ));
+    }
+
+    fn call_member(&mut self, access: AccessParams, args: SahaFunctionArguments) -> SahaCallResult {
+        if access.is_static_access {
+            return Err(RuntimeError::new(
+                &format!("No static method `{}` defined for `{}`", access.member_name, self.get_class_name()),
+                access.access_file_pos.clone()
+            ));
+        }
+
+        match access.member_name as &str {
+            "insert" => self.insert(&args, access),
+            "contains" => self.contains(&args, access),
+            "remove" => self.remove(&args, access),
+            "count" => self.count(&args, access),
+            "clone" => self.clone_self(&args, access),
+            "equals" => self.equals(&args, access),
+            "hash" => self.hash(&args, access),
+            "display" => self.display(&args, access),
+            _ => {
+                return Err(RuntimeError::new(
+                    &format!("No method `{}` defined for `{}`", access.member_name, self.get_class_name()),
+                    access.access_file_pos.clone()
+                ));
+            }
+        }
+    }
+
+    fn access_property(&self, _access: AccessParams) -> SahaCallResult {
+        unimplemented!()
+    }
+
+    fn mutate_property(&mut self, _access: AccessParams, _new_value: Value) -> SahaCallResult {
+        unimplemented!()
+    }
+
+    fn box_clone(&self) -> Box<dyn SahaObject> {
+        return Box::new(self.clone());
+    }
+
+    fn into_iter(&self) -> Box<dyn Iterator<Item = (Value, Value)>> {
+        return Box::new(self.data.items().into_iter().enumerate().map(|(idx, (val, _))| (Value::int(idx as isize), val)));
+    }
+
+    fn set_data_from_iter(&mut self, iterator: Box<dyn Iterator<Item = (Value, Value)>>) {
+        let mut data = HashedEntries::default();
+
+        for (_, val) in iterator {
+            // the items come from this set, so they have been hashed before
+            data.insert(val, (), &None).ok();
+        }
+
+        self.data = data;
+    }
+
+    fn as_any(&self) -> Option<&dyn Any> {
+        return Some(self);
+    }
+}
+
+impl SahaSet {
+    /// Get function parameter definitions for methods which take a single item.
+    fn value_params(&self) -> SahaFunctionParamDefs {
+        let mut params: SahaFunctionParamDefs = SahaFunctionParamDefs::new();
+
+        let (pname, param) = create_core_param("value", *self.param_type.clone(), 0);
+
+        params.insert(pname.to_string(), param);
+
+        return params;
+    }
+
+    /// The Set::insert "method". Returns `false` if an equal item already
+    /// exists in the set.
+    pub fn insert(&mut self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let args = self.value_params().validate_args(args, access.access_file_pos)?;
+
+        let inserted = self.data.insert(args["value"].clone(), (), access.access_file_pos)?;
+
+        return Ok(Value::bool(inserted));
+    }
+
+    /// The Set::contains "method".
+    pub fn contains(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let args = self.value_params().validate_args(args, access.access_file_pos)?;
+
+        let exists = self.data.contains_key(&args["value"], access.access_file_pos)?;
+
+        return Ok(Value::bool(exists));
+    }
+
+    /// The Set::remove "method". Returns `false` if the item was not in the
+    /// set.
+    pub fn remove(&mut self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let args = self.value_params().validate_args(args, access.access_file_pos)?;
+
+        let removed = self.data.remove(&args["value"], access.access_file_pos)?;
+
+        return Ok(Value::bool(removed.is_some()));
+    }
+
+    /// The Set::count "method".
+    pub fn count(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let params: SahaFunctionParamDefs = SahaFunctionParamDefs::new(); // no params
+
+        params.validate_args(args, access.access_file_pos)?;
+
+        return Ok(Value::int(self.data.len() as isize));
+    }
+
+    /// The Set::clone "method". A deep clone clones the items as well, which
+    /// are hashed again as their identities change.
+    pub fn clone_self(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let args = clone_params().validate_args(args, access.access_file_pos)?;
+
+        let mut new_set = self.clone();
+
+        new_set.instref = get_new_instref();
+
+        if !args["deep"].bool.unwrap() {
+            return Ok(add_instance_to_symbol_table(new_set.instref, Box::new(new_set)));
+        }
+
+        return add_deep_clone_to_symbol_table(self.instref, new_set, |copy| {
+            let mut data = HashedEntries::default();
+
+            for (item, _) in copy.data.items() {
+                data.insert(clone_value(&item, true, access.access_file_pos)?, (), access.access_file_pos)?;
+            }
+
+            copy.data = data;
+
+            return Ok(());
+        });
+    }
+
+    /// Get function parameter definitions for methods which take another set
+    /// as `other`.
+    fn other_params(&self) -> SahaFunctionParamDefs {
+        let mut params: SahaFunctionParamDefs = SahaFunctionParamDefs::new();
+
+        let (pname, param) = create_core_param("other", *self.get_named_type(), 0);
+
+        params.insert(pname.to_string(), param);
+
+        return params;
+    }
+
+    /// The Set::equals "method", also used for the `==` operator. Sets are
+    /// equal when they contain equal items, in any order.
+    pub fn equals(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        if is_self_argument(args, self.instref) {
+            return Ok(Value::bool(true));
+        }
+
+        let args = self.other_params().validate_args(args, access.access_file_pos)?;
+        let other_data = read_core_instance(&args["other"], |other: &SahaSet| other.data.clone()).unwrap();
+
+        if self.data.len() != other_data.len() {
+            return Ok(Value::bool(false));
+        }
+
+        for (item, _) in self.data.items() {
+            if !other_data.contains_key(&item, access.access_file_pos)? {
+                return Ok(Value::bool(false));
+            }
+        }
+
+        return Ok(Value::bool(true));
+    }
+
+    /// The Set::hash "method". The item hashes are combined regardless of
+    /// their order, as equal sets can have their items in a different order.
+    pub fn hash(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let params: SahaFunctionParamDefs = SahaFunctionParamDefs::new(); // no params
+
+        params.validate_args(args, access.access_file_pos)?;
+
+        let mut hash: isize = 0;
+
+        for (item, _) in self.data.items() {
+            hash = hash.wrapping_add(value_hash(&item, access.access_file_pos)?);
+        }
+
+        return Ok(Value::int(hash));
+    }
+
+    /// The Set::display "method", e.g. `{1, 2, 3}`.
+    pub fn display(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let params: SahaFunctionParamDefs = SahaFunctionParamDefs::new(); // no params
+
+        params.validate_args(args, access.access_file_pos)?;
+
+        let items = self.data.items().iter()
+            .map(|(item, _)| display_nested_value(item, access.access_file_pos))
+            .collect::<Result<Vec<String>, RuntimeError>>()?;
+
+        return Ok(Value::str(format!("{{{}}}", items.join(", "))));
+    }
+}
\ No newline at end of file
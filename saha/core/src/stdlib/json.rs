@@ -0,0 +1,706 @@
+//! stdlib JSON
+//!
+//! The `std.json` module. Parsed JSON is held in `std.json.JsonValue`
+//! instances, which are read with typed helpers such as `asInt()`. Values can
+//! also be decoded straight into Saha types, including user classes whose
+//! properties match the JSON object keys, with `std.json.Decoder<T>`.
+
+use std::{
+    any::Any,
+    collections::HashMap,
+    convert::TryFrom,
+    sync::Arc
+};
+
+use noisy_float::prelude::*;
+
+use serde_json::{Map, Number, Value as Json};
+
+use saha_lib::prelude::*;
+use saha_lib::types::{
+    behaviors::{call_object_method, display_value, get_property_values},
+    objects::{ClassDefinition, INIT_METHOD_NAME, newup_instance}
+};
+
+use crate::{
+    stdlib::globals::{
+        option::SahaOption,
+        result::SahaResult,
+        list::create_list_value,
+        dict::{create_dict_value, create_keyed_dict_value}
+    },
+    utils::{
+        create_core_function, create_core_param, create_core_param_with_default, get_new_instref, add_instance_to_symbol_table,
+        read_core_instance, is_self_argument
+    }
+};
+
+/// Fully qualified name of the `JsonValue` class.
+pub const JSON_VALUE_CLASS_NAME: &str = "std.json.JsonValue";
+
+/// Fully qualified name of the `Decoder<T>` class.
+pub const DECODER_CLASS_NAME: &str = "std.json.Decoder";
+
+/// Register module stdlib parts.
+pub fn get_saha_functions() -> Vec<(String, CoreFunction)> {
+    let json_value_type = SahaType::Name(JSON_VALUE_CLASS_NAME.to_string(), Vec::new());
+    let result_of_json_value = SahaType::Name("Result".to_string(), vec![Box::new(json_value_type), Box::new(SahaType::Str)]);
+
+    return vec![
+        create_core_function(
+            "parse",
+            vec![create_core_param("text", SahaType::Str, 0)],
+            Box::new(result_of_json_value),
+            parse
+        ),
+        create_core_function(
+            "stringify",
+            vec![
+                create_core_param("value", SahaType::Any, 0),
+                create_core_param_with_default("pretty", SahaType::Bool, Value::bool(false), 1)
+            ],
+            Box::new(SahaType::Str),
+            stringify
+        ),
+    ];
+}
+
+/// `JsonValue` instances are created by parsing JSON, not with `new`.
+pub fn new_json_value_instance(
+    _instref: InstRef,
+    _args: &SahaFunctionArguments,
+    _type_params: &[Box<SahaType>],
+    _additional_data: &SahaFunctionArguments,
+    create_pos: Option<FilePosition>
+) -> Result<Box<dyn SahaObject>, RuntimeError> {
+    let err = RuntimeError::new("`JsonValue` cannot be created with `new`, use `std.json.parse` instead", create_pos);
+
+    return Err(err);
+}
+
+/// Resolve the return type of a `JsonValue` method.
+pub fn json_value_method_type(_type_params: &[Box<SahaType>], method_name: &str) -> Option<SahaType> {
+    let json_value_type = || Box::new(SahaType::Name(JSON_VALUE_CLASS_NAME.to_string(), Vec::new()));
+    let option_type = |inner_type| SahaType::Name("Option".to_string(), vec![Box::new(inner_type)]);
+
+    return match method_name {
+        "kind" | "display" => Some(SahaType::Str),
+        "isNull" | "equals" => Some(SahaType::Bool),
+        "asBool" => Some(option_type(SahaType::Bool)),
+        "asInt" => Some(option_type(SahaType::Int)),
+        "asFloat" => Some(option_type(SahaType::Float)),
+        "asStr" => Some(option_type(SahaType::Str)),
+        "asList" => Some(option_type(SahaType::Name("List".to_string(), vec![json_value_type()]))),
+        "asDict" => Some(option_type(SahaType::Name("Dict".to_string(), vec![json_value_type()]))),
+        _ => None
+    };
+}
+
+/// Create a new Decoder instance for decoding JSON into the type `T`.
+pub fn new_decoder_instance(
+    instref: InstRef,
+    args: &SahaFunctionArguments,
+    type_params: &[Box<SahaType>],
+    _additional_data: &SahaFunctionArguments,
+    create_pos: Option<FilePosition>
+) -> Result<Box<dyn SahaObject>, RuntimeError> {
+    if type_params.len() != 1 {
+        let err = RuntimeError::new("`Decoder` expects a single type parameter `T`", create_pos);
+
+        return Err(err);
+    }
+
+    if !args.is_empty() {
+        let err = RuntimeError::new("`Decoder` expects no arguments", create_pos);
+
+        return Err(err);
+    }
+
+    return Ok(Box::new(SahaDecoder {
+        instref: instref,
+        target_type: type_params[0].clone()
+    }));
+}
+
+/// Resolve the return type of a `Decoder<T>` method.
+pub fn decoder_method_type(type_params: &[Box<SahaType>], method_name: &str) -> Option<SahaType> {
+    return match method_name {
+        "decode" | "fromValue" => Some(SahaType::Name("Result".to_string(), vec![type_params.first()?.clone(), Box::new(SahaType::Str)])),
+        _ => None
+    };
+}
+
+/// Create a new `JsonValue` instance, and get the instref value object for it.
+fn create_json_value(json: Json) -> Value {
+    let instref = get_new_instref();
+
+    return add_instance_to_symbol_table(instref, Box::new(SahaJsonValue {
+        instref: instref,
+        json: json
+    }));
+}
+
+/// Get the JSON held by a `JsonValue` instance value.
+fn json_of(value: &Value) -> Option<Json> {
+    return read_core_instance(value, |j: &SahaJsonValue| j.json.clone());
+}
+
+/// Get the kind of a JSON value, as returned by `JsonValue::kind`.
+fn json_kind(json: &Json) -> &'static str {
+    return match json {
+        Json::Null => "null",
+        Json::Bool(..) => "bool",
+        Json::Number(n) if n.is_i64() => "int",
+        Json::Number(..) => "float",
+        Json::String(..) => "str",
+        Json::Array(..) => "list",
+        Json::Object(..) => "dict"
+    };
+}
+
+/// Parse JSON text.
+fn parse(args: SahaFunctionArguments) -> SahaCallResult {
+    let text = args["text"].str.clone().unwrap();
+
+    let outcome = match serde_json::from_str::<Json>(&text) {
+        Ok(json) => Ok(create_json_value(json)),
+        Err(err) => Err(Value::str(format!("Invalid JSON: {}", err)))
+    };
+
+    let json_value_type = SahaType::Name(JSON_VALUE_CLASS_NAME.to_string(), Vec::new());
+
+    return Ok(SahaResult::create_value(outcome, Box::new(json_value_type), Box::new(SahaType::Str)));
+}
+
+/// Convert a value to JSON text. Lists, sets, dicts, options, JSON values and
+/// user class instances are converted along with their contents.
+fn stringify(args: SahaFunctionArguments) -> SahaCallResult {
+    let json = to_json(&args["value"], &mut Vec::new())?;
+
+    let text = if args["pretty"].bool.unwrap() {
+        serde_json::to_string_pretty(&json)
+    } else {
+        serde_json::to_string(&json)
+    };
+
+    return Ok(Value::str(text.unwrap()));
+}
+
+/// Convert a Saha value to JSON. The objects being converted are tracked, so
+/// objects which contain themselves are errors instead of endless recursion.
+fn to_json(value: &Value, converting: &mut Vec<InstRef>) -> Result<Json, RuntimeError> {
+    let json = match *value.kind {
+        SahaType::Str => Json::String(value.str.clone().unwrap()),
+        SahaType::Int => Json::Number(Number::from(value.int.unwrap() as i64)),
+        SahaType::Float => Json::Number(Number::from_f64(value.float.unwrap().raw()).unwrap()),
+        SahaType::Bool => Json::Bool(value.bool.unwrap()),
+        SahaType::Tuple(..) => Json::Array(value.tuple.clone().unwrap().iter().map(|v| to_json(v, converting)).collect::<Result<_, _>>()?),
+        SahaType::Obj => object_to_json(value, converting)?,
+        _ => return Err(RuntimeError::new("Cannot convert `void` to JSON", None))
+    };
+
+    return Ok(json);
+}
+
+/// Convert a Saha object to JSON.
+fn object_to_json(value: &Value, converting: &mut Vec<InstRef>) -> Result<Json, RuntimeError> {
+    let instref = value.obj.unwrap();
+
+    if converting.contains(&instref) {
+        return Err(RuntimeError::new("Cannot convert an object which contains itself to JSON", None));
+    }
+
+    converting.push(instref);
+
+    let json = object_contents_to_json(value, converting);
+
+    converting.pop();
+
+    return json;
+}
+
+/// Convert the contents of a Saha object to JSON.
+fn object_contents_to_json(value: &Value, converting: &mut Vec<InstRef>) -> Result<Json, RuntimeError> {
+    let inst = saha_lib::SAHA_SYMBOL_TABLE.lock().unwrap().instances.get(&value.obj.unwrap()).cloned();
+
+    let inst = match inst {
+        Some(inst) => inst,
+        None => return Err(RuntimeError::new("Cannot access undefined instance", None))
+    };
+
+    let class_name = inst.lock().unwrap().get_fully_qualified_class_name();
+
+    let json = match class_name.as_str() {
+        "List" | "Set" => {
+            let items: Vec<Value> = inst.lock().unwrap().into_iter().map(|(_, v)| v).collect();
+
+            Json::Array(items.iter().map(|v| to_json(v, converting)).collect::<Result<_, _>>()?)
+        },
+        "Dict" => {
+            let items: Vec<(Value, Value)> = inst.lock().unwrap().into_iter().collect();
+            let mut map = Map::new();
+
+            for (key, item) in items {
+                let key = match key.str {
+                    Some(key) => key,
+                    None => {
+                        return Err(RuntimeError::new(
+                            &format!("Cannot convert a `Dict` with `{}` keys to JSON, keys must be strings", key.kind.to_readable_string()),
+                            None
+                        ));
+                    }
+                };
+
+                map.insert(key, to_json(&item, converting)?);
+            }
+
+            Json::Object(map)
+        },
+        "Option" => {
+            let is_some = call_object_method(value, "isSome", HashMap::new(), &None)?;
+
+            if is_some.bool.unwrap() {
+                to_json(&call_object_method(value, "unwrap", HashMap::new(), &None)?, converting)?
+            } else {
+                Json::Null
+            }
+        },
+        JSON_VALUE_CLASS_NAME => json_of(value).unwrap(),
+        _ if saha_lib::SAHA_SYMBOL_TABLE.lock().unwrap().classes.contains_key(&class_name) => {
+            let mut map = Map::new();
+
+            for (name, prop_value) in get_property_values(value)? {
+                map.insert(name, to_json(&prop_value, converting)?);
+            }
+
+            Json::Object(map)
+        },
+        _ => return Err(RuntimeError::new(&format!("Cannot convert `{}` to JSON", class_name), None))
+    };
+
+    return Ok(json);
+}
+
+/// Why decoding JSON failed.
+enum DecodeError {
+    /// The JSON does not fit the type, the decoding result is a failure with
+    /// this message.
+    Failure(String),
+    /// Creating a class instance raised an error, e.g. in its `init`. It is
+    /// raised from the decoding as is.
+    Runtime(RuntimeError)
+}
+
+/// Decode JSON into a value of a Saha type. The path points to the decoded
+/// JSON value in error messages, e.g. `$.points[1].x`. Class instances are
+/// created at the decode call position.
+fn from_json(json: &Json, ty: &SahaType, path: &str, pos: &FilePosition) -> Result<Value, DecodeError> {
+    let mismatch = || DecodeError::Failure(format!("`{}`: expected `{}`, found `{}`", path, ty.to_readable_string(), json_kind(json)));
+
+    let value = match (ty, json) {
+        (SahaType::Str, Json::String(s)) => Value::str(s.clone()),
+        (SahaType::Bool, Json::Bool(b)) => Value::bool(*b),
+        (SahaType::Int, Json::Number(n)) => {
+            match n.as_i64().and_then(|i| isize::try_from(i).ok()) {
+                Some(i) => Value::int(i),
+                None => return Err(mismatch())
+            }
+        },
+        (SahaType::Float, Json::Number(n)) => Value::float(r64(n.as_f64().unwrap())),
+        (SahaType::Tuple(types), Json::Array(items)) if types.len() == items.len() => {
+            let mut values: Vec<Value> = Vec::new();
+
+            for (idx, (item, item_type)) in items.iter().zip(types).enumerate() {
+                values.push(from_json(item, item_type, &format!("{}[{}]", path, idx), pos)?);
+            }
+
+            Value::tuple(values, types.clone())
+        },
+        (SahaType::Name(n, _), _) if n == JSON_VALUE_CLASS_NAME => create_json_value(json.clone()),
+        (SahaType::Name(n, tps), Json::Null) if n == "Option" && tps.len() == 1 => {
+            SahaOption::create_value(None, tps[0].clone())
+        },
+        (SahaType::Name(n, tps), _) if n == "Option" && tps.len() == 1 => {
+            SahaOption::create_value(Some(from_json(json, &tps[0], path, pos)?), tps[0].clone())
+        },
+        (SahaType::Name(n, tps), Json::Array(items)) if n == "List" && tps.len() == 1 => {
+            let mut values: Vec<Value> = Vec::new();
+
+            for (idx, item) in items.iter().enumerate() {
+                values.push(from_json(item, &tps[0], &format!("{}[{}]", path, idx), pos)?);
+            }
+
+            create_list_value(values, tps[0].clone())
+        },
+        (SahaType::Name(n, tps), Json::Object(map)) if n == "Dict" && is_str_keyed_dict(tps) => {
+            let mut values: HashMap<String, Value> = HashMap::new();
+
+            for (key, item) in map {
+                values.insert(key.clone(), from_json(item, &tps[0], &format!("{}.{}", path, key), pos)?);
+            }
+
+            create_keyed_dict_value(values, tps[0].clone(), tps.get(1).cloned())
+        },
+        (SahaType::Name(n, tps), Json::Object(map)) => {
+            let classdef = saha_lib::SAHA_SYMBOL_TABLE.lock().unwrap().classes.get(n).cloned();
+
+            match classdef {
+                Some(classdef) => object_from_json(map, &classdef, tps, path, pos)?,
+                None => return Err(DecodeError::Failure(format!("`{}`: `{}` cannot be decoded from JSON", path, ty.to_readable_string())))
+            }
+        },
+        _ => return Err(mismatch())
+    };
+
+    return Ok(value);
+}
+
+/// Decode a JSON object into a user class instance. Each property is decoded
+/// from the object key with the same name, using the property type. Missing
+/// keys use property defaults, or none for optional properties. The instance
+/// is created like a newup outside of the class, so its `init` is called.
+fn object_from_json(map: &Map<String, Json>, classdef: &ClassDefinition, tps: &[Box<SahaType>], path: &str, pos: &FilePosition) -> Result<Value, DecodeError> {
+    let type_params: HashMap<char, Box<SahaType>> = classdef.type_params.iter()
+        .zip(tps)
+        .map(|((c, _), t)| (*c, t.clone()))
+        .collect();
+
+    let mut args: SahaFunctionArguments = HashMap::new();
+
+    let mut properties: Vec<_> = classdef.properties.iter().filter(|(_, p)| !p.is_static).collect();
+
+    properties.sort_by_key(|(name, _)| *name);
+
+    for (pname, prop) in properties {
+        let prop_type = prop.prop_type.with_type_params(&type_params);
+
+        match map.get(pname) {
+            Some(item) => {
+                args.insert(pname.clone(), from_json(item, &prop_type, &format!("{}.{}", path, pname), pos)?);
+            },
+            None if *prop.default.kind != SahaType::Void => continue,
+            None => match prop_type {
+                SahaType::Name(ref n, ref inner) if n == "Option" && inner.len() == 1 => {
+                    args.insert(pname.clone(), SahaOption::create_value(None, inner[0].clone()));
+                },
+                _ => return Err(DecodeError::Failure(format!("`{}`: missing property `{}` of `{}`", path, pname, classdef.fqname)))
+            }
+        };
+    }
+
+    let created = newup_instance(&classdef.fqname, args, tps, pos).map_err(DecodeError::Runtime)?;
+
+    // like with newups, an `init` which returns a `Result` results in a
+    // `Result` containing the instance or the `init` failure value
+    let init_returns_result = saha_lib::SAHA_SYMBOL_TABLE.lock().unwrap().methods
+        .get(&format!("{}#{}", classdef.fqname, INIT_METHOD_NAME))
+        .is_some_and(|init| matches!(*init.get_return_type(), SahaType::Name(ref n, ref tps) if n == "Result" && tps.len() == 2));
+
+    if !init_returns_result {
+        return Ok(created);
+    }
+
+    let is_success = call_object_method(&created, "isSuccess", HashMap::new(), &None).map_err(DecodeError::Runtime)?;
+    let outcome = call_object_method(&created, "unwrap", HashMap::new(), &None).map_err(DecodeError::Runtime)?;
+
+    if is_success.bool.unwrap() {
+        return Ok(outcome);
+    }
+
+    let failure = display_value(&outcome, &None).map_err(DecodeError::Runtime)?;
+
+    return Err(DecodeError::Failure(format!("`{}`: `{}` failed to initialize: {}", path, classdef.fqname, failure)));
+}
+
+/// Check if `Dict` type parameters have `str` keys, which are the only keys
+/// JSON objects can have.
+fn is_str_keyed_dict(tps: &[Box<SahaType>]) -> bool {
+    return tps.len() == 1 || (tps.len() == 2 && *tps[1] == SahaType::Str);
+}
+
+/// SahaJsonValue is the core definition of the `JsonValue` type in Saha. It
+/// holds any JSON value, which is read with the typed `as*` helpers.
+#[derive(Clone)]
+struct SahaJsonValue {
+    instref: InstRef,
+    json: Json
+}
+
+impl SahaObject for SahaJsonValue {
+    fn get_instance_ref(&self) -> InstRef {
+        return self.instref;
+    }
+
+    fn is_core_defined(&self) -> bool {
+        return true;
+    }
+
+    fn get_class_name(&self) -> String {
+        return "JsonValue".to_string();
+    }
+
+    fn get_fully_qualified_class_name(&self) -> String {
+        return JSON_VALUE_CLASS_NAME.to_string();
+    }
+
+    fn get_implements(&self) -> Vec<String> {
+        return vec!["Equals".to_string(), "Displayable".to_string()];
+    }
+
+    fn get_full_method_name(&mut self, _method_name: &str) -> String {
+        unimplemented!()
+    }
+
+    fn get_method_ref(&mut self, _method_name: &str) -> Result<Arc<Box<dyn SahaCallable>>, RuntimeError> {
+        unimplemented!()
+    }
+
+    fn get_type_params(&self) -> Vec<(char, Box<SahaType>)> {
+        return Vec::new();
+    }
+
+    fn get_named_type(&self) -> Box<SahaType> {
+        return Box::new(SahaType::Name(JSON_VALUE_CLASS_NAME.to_string(), Vec::new()));
+    }
+
+    fn call_member(&mut self, access: AccessParams, args: SahaFunctionArguments) -> SahaCallResult {
+        if access.is_static_access {
+            return Err(RuntimeError::new(
+                &format!("No static method `{}` defined for `{}`", access.member_name, self.get_class_name()),
+                access.access_file_pos.clone()
+            ));
+        }
+
+        let json = self.json.clone();
+        let json_value_type = || Box::new(SahaType::Name(JSON_VALUE_CLASS_NAME.to_string(), Vec::new()));
+
+        match access.member_name as &str {
+            "kind" => self.get(&args, access, || Value::str(json_kind(&json).to_string())),
+            "isNull" => self.get(&args, access, || Value::bool(json.is_null())),
+            "asBool" => self.get(&args, access, || {
+                SahaOption::create_value(json.as_bool().map(Value::bool), Box::new(SahaType::Bool))
+            }),
+            "asInt" => self.get(&args, access, || {
+                let int = json.as_i64().and_then(|i| isize::try_from(i).ok());
+
+                SahaOption::create_value(int.map(Value::int), Box::new(SahaType::Int))
+            }),
+            "asFloat" => self.get(&args, access, || {
+                SahaOption::create_value(json.as_f64().map(|f| Value::float(r64(f))), Box::new(SahaType::Float))
+            }),
+            "asStr" => self.get(&args, access, || {
+                SahaOption::create_value(json.as_str().map(|s| Value::str(s.to_string())), Box::new(SahaType::Str))
+            }),
+            "asList" => self.get(&args, access, || {
+                let list = json.as_array().map(|items| {
+                    create_list_value(items.iter().cloned().map(create_json_value).collect(), json_value_type())
+                });
+
+                SahaOption::create_value(list, Box::new(SahaType::Name("List".to_string(), vec![json_value_type()])))
+            }),
+            "asDict" => self.get(&args, access, || {
+                let dict = json.as_object().map(|map| {
+                    let items = map.iter().map(|(k, v)| (k.clone(), create_json_value(v.clone()))).collect();
+
+                    create_dict_value(items, json_value_type())
+                });
+
+                SahaOption::create_value(dict, Box::new(SahaType::Name("Dict".to_string(), vec![json_value_type()])))
+            }),
+            "display" => self.get(&args, access, || Value::str(json.to_string())),
+            "equals" => self.equals(&args, access),
+            _ => {
+                return Err(RuntimeError::new(
+                    &format!("No method `{}` defined for `{}`", access.member_name, self.get_class_name()),
+                    access.access_file_pos.clone()
+                ));
+            }
+        }
+    }
+
+    fn access_property(&self, _access: AccessParams) -> SahaCallResult {
+        unimplemented!()
+    }
+
+    fn mutate_property(&mut self, _access: AccessParams, _new_value: Value) -> SahaCallResult {
+        unimplemented!()
+    }
+
+    fn box_clone(&self) -> Box<dyn SahaObject> {
+        return Box::new(self.clone());
+    }
+
+    fn into_iter(&self) -> Box<dyn Iterator<Item = (Value, Value)>> {
+        unimplemented!()
+    }
+
+    fn set_data_from_iter(&mut self, _iterator: Box<dyn Iterator<Item = (Value, Value)>>) {
+        unimplemented!()
+    }
+
+    fn as_any(&self) -> Option<&dyn Any> {
+        return Some(self);
+    }
+}
+
+impl SahaJsonValue {
+    /// JsonValue "methods" which take no parameters, such as `kind` and the
+    /// typed `as*` helpers which return none for other kinds of JSON.
+    fn get(&self, args: &SahaFunctionArguments, access: AccessParams, value: impl FnOnce() -> Value) -> SahaCallResult {
+        let params: SahaFunctionParamDefs = SahaFunctionParamDefs::new(); // no params
+
+        params.validate_args(args, access.access_file_pos)?;
+
+        return Ok(value());
+    }
+
+    /// The JsonValue::equals "method", also used for the `==` operator.
+    pub fn equals(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        // comparing to itself would lock this instance again
+        if is_self_argument(args, self.instref) {
+            return Ok(Value::bool(true));
+        }
+
+        let mut params: SahaFunctionParamDefs = SahaFunctionParamDefs::new();
+        let (pname, param) = create_core_param("other", SahaType::Name(JSON_VALUE_CLASS_NAME.to_string(), Vec::new()), 0);
+
+        params.insert(pname.to_string(), param);
+
+        let args = params.validate_args(args, access.access_file_pos)?;
+
+        return Ok(Value::bool(json_of(&args["other"]) == Some(self.json.clone())));
+    }
+}
+
+/// SahaDecoder is the core definition of the `Decoder<T>` type in Saha, which
+/// decodes JSON into values of the type `T`.
+#[derive(Clone)]
+struct SahaDecoder {
+    instref: InstRef,
+    target_type: Box<SahaType>
+}
+
+impl SahaObject for SahaDecoder {
+    fn get_instance_ref(&self) -> InstRef {
+        return self.instref;
+    }
+
+    fn is_core_defined(&self) -> bool {
+        return true;
+    }
+
+    fn get_class_name(&self) -> String {
+        return "Decoder".to_string();
+    }
+
+    fn get_fully_qualified_class_name(&self) -> String {
+        return DECODER_CLASS_NAME.to_string();
+    }
+
+    fn get_implements(&self) -> Vec<String> {
+        return Vec::new();
+    }
+
+    fn get_full_method_name(&mut self, _method_name: &str) -> String {
+        unimplemented!()
+    }
+
+    fn get_method_ref(&mut self, _method_name: &str) -> Result<Arc<Box<dyn SahaCallable>>, RuntimeError> {
+        unimplemented!()
+    }
+
+    fn get_type_params(&self) -> Vec<(char, Box<SahaType>)> {
+        return vec![('T', self.target_type.clone())];
+    }
+
+    fn get_named_type(&self) -> Box<SahaType> {
+        return Box::new(SahaType::Name(DECODER_CLASS_NAME.to_string(), vec![self.target_type.clone()]));
+    }
+
+    fn call_member(&mut self, access: AccessParams, args: SahaFunctionArguments) -> SahaCallResult {
+        if access.is_static_access {
+            return Err(RuntimeError::new(
+                &format!("No static method `{}` defined for `{}`", access.member_name, self.get_class_name()),
+                access.access_file_pos.clone()
+            ));
+        }
+
+        match access.member_name as &str {
+            "decode" => self.decode(&args, access),
+            "fromValue" => self.decode_value(&args, access),
+            _ => {
+                return Err(RuntimeError::new(
+                    &format!("No method `{}` defined for `{}`", access.member_name, self.get_class_name()),
+                    access.access_file_pos.clone()
+                ));
+            }
+        }
+    }
+
+    fn access_property(&self, _access: AccessParams) -> SahaCallResult {
+        unimplemented!()
+    }
+
+    fn mutate_property(&mut self, _access: AccessParams, _new_value: Value) -> SahaCallResult {
+        unimplemented!()
+    }
+
+    fn box_clone(&self) -> Box<dyn SahaObject> {
+        return Box::new(self.clone());
+    }
+
+    fn into_iter(&self) -> Box<dyn Iterator<Item = (Value, Value)>> {
+        unimplemented!()
+    }
+
+    fn set_data_from_iter(&mut self, _iterator: Box<dyn Iterator<Item = (Value, Value)>>) {
+        unimplemented!()
+    }
+}
+
+impl SahaDecoder {
+    /// Decode JSON into a `Result<T, str>`. Errors raised while creating
+    /// class instances are not decoding failures, and are raised as is.
+    fn decode_json(&self, json: &Json, access: &AccessParams) -> SahaCallResult {
+        let pos = access.access_file_pos.clone().unwrap_or_else(FilePosition::unknown);
+
+        let outcome = match from_json(json, &self.target_type, "$", &pos) {
+            Ok(value) => Ok(value),
+            Err(DecodeError::Failure(message)) => Err(Value::str(message)),
+            Err(DecodeError::Runtime(err)) => return Err(err)
+        };
+
+        return Ok(SahaResult::create_value(outcome, self.target_type.clone(), Box::new(SahaType::Str)));
+    }
+
+    /// The Decoder::decode "method". Parses JSON text and decodes it.
+    pub fn decode(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let mut params: SahaFunctionParamDefs = SahaFunctionParamDefs::new();
+        let (pname, param) = create_core_param("text", SahaType::Str, 0);
+
+        params.insert(pname.to_string(), param);
+
+        let args = params.validate_args(args, access.access_file_pos)?;
+
+        return match serde_json::from_str::<Json>(&args["text"].str.clone().unwrap()) {
+            Ok(json) => self.decode_json(&json, &access),
+            Err(err) => {
+                let outcome = Err(Value::str(format!("Invalid JSON: {}", err)));
+
+                Ok(SahaResult::create_value(outcome, self.target_type.clone(), Box::new(SahaType::Str)))
+            }
+        };
+    }
+
+    /// The Decoder::fromValue "method". Decodes an already parsed `JsonValue`.
+    pub fn decode_value(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let mut params: SahaFunctionParamDefs = SahaFunctionParamDefs::new();
+        let (pname, param) = create_core_param("value", SahaType::Name(JSON_VALUE_CLASS_NAME.to_string(), Vec::new()), 0);
+
+        params.insert(pname.to_string(), param);
+
+        let args = params.validate_args(args, access.access_file_pos)?;
+
+        return self.decode_json(&json_of(&args["value"]).unwrap(), &access);
+    }
+}
@@ -0,0 +1,293 @@
+//! stdlib Math
+//!
+//! The `std.math` module. Like Saha floats in general, the functions never
+//! produce NaN or infinite values, instead calculations which would result in
+//! them are runtime errors.
+
+use std::f64::consts;
+
+use noisy_float::prelude::*;
+
+use saha_lib::prelude::*;
+
+use crate::utils::{create_core_function, create_core_param, create_core_param_with_default};
+
+/// Register module stdlib parts.
+pub fn get_saha_functions() -> Vec<(String, CoreFunction)> {
+    let float = |name| create_core_param(name, SahaType::Float, 0);
+    let float_pair = |first, second| vec![
+        create_core_param(first, SahaType::Float, 0),
+        create_core_param(second, SahaType::Float, 1)
+    ];
+    let int_pair = vec![
+        create_core_param("a", SahaType::Int, 0),
+        create_core_param("b", SahaType::Int, 1)
+    ];
+    let float_type = || Box::new(SahaType::Float);
+    let int_type = || Box::new(SahaType::Int);
+
+    return vec![
+        create_core_function("abs", vec![float("value")], float_type(), abs),
+        create_core_function("min", float_pair("a", "b"), float_type(), min),
+        create_core_function("max", float_pair("a", "b"), float_type(), max),
+        create_core_function(
+            "clamp",
+            vec![
+                create_core_param("value", SahaType::Float, 0),
+                create_core_param("min", SahaType::Float, 1),
+                create_core_param("max", SahaType::Float, 2)
+            ],
+            float_type(),
+            clamp
+        ),
+        create_core_function("absInt", vec![create_core_param("value", SahaType::Int, 0)], int_type(), abs_int),
+        create_core_function("minInt", int_pair.clone(), int_type(), min_int),
+        create_core_function("maxInt", int_pair.clone(), int_type(), max_int),
+        create_core_function(
+            "clampInt",
+            vec![
+                create_core_param("value", SahaType::Int, 0),
+                create_core_param("min", SahaType::Int, 1),
+                create_core_param("max", SahaType::Int, 2)
+            ],
+            int_type(),
+            clamp_int
+        ),
+        create_core_function("pow", float_pair("base", "exponent"), float_type(), pow),
+        create_core_function("sqrt", vec![float("value")], float_type(), sqrt),
+        create_core_function("floor", vec![float("value")], float_type(), floor),
+        create_core_function("ceil", vec![float("value")], float_type(), ceil),
+        create_core_function("round", vec![float("value")], float_type(), round),
+        create_core_function("trunc", vec![float("value")], float_type(), trunc),
+        create_core_function("sin", vec![float("value")], float_type(), sin),
+        create_core_function("cos", vec![float("value")], float_type(), cos),
+        create_core_function("tan", vec![float("value")], float_type(), tan),
+        create_core_function("atan2", float_pair("y", "x"), float_type(), atan2),
+        create_core_function(
+            "log",
+            vec![
+                create_core_param("value", SahaType::Float, 0),
+                create_core_param_with_default("base", SahaType::Float, Value::float(r64(10.0)), 1)
+            ],
+            float_type(),
+            log
+        ),
+        create_core_function("ln", vec![float("value")], float_type(), ln),
+        create_core_function("exp", vec![float("value")], float_type(), exp),
+        create_core_function("isNan", vec![float("value")], Box::new(SahaType::Bool), is_nan),
+        create_core_function("isInfinite", vec![float("value")], Box::new(SahaType::Bool), is_infinite),
+        create_core_function("gcd", int_pair.clone(), Box::new(SahaType::Int), gcd),
+        create_core_function(
+            "divmod",
+            int_pair,
+            Box::new(SahaType::Tuple(vec![Box::new(SahaType::Int), Box::new(SahaType::Int)])),
+            divmod
+        ),
+    ];
+}
+
+/// Register module stdlib constants.
+pub fn get_saha_constants() -> Vec<(String, Value)> {
+    return vec![
+        ("PI".to_string(), Value::float(r64(consts::PI))),
+        ("E".to_string(), Value::float(r64(consts::E))),
+    ];
+}
+
+/// Get a float argument value.
+fn float_arg(args: &SahaFunctionArguments, name: &str) -> f64 {
+    return args[name].float.unwrap().raw();
+}
+
+/// Get an int argument value.
+fn int_arg(args: &SahaFunctionArguments, name: &str) -> isize {
+    return args[name].int.unwrap();
+}
+
+/// Get a float value for a calculation result, which errors if the result is
+/// not a finite number.
+fn finite_value(fn_name: &str, result: f64) -> SahaCallResult {
+    return match R64::try_new(result) {
+        Some(value) => Ok(Value::float(value)),
+        None => Err(RuntimeError::new(&format!("Result of `{}` is not a finite number", fn_name), None))
+    };
+}
+
+/// Apply a single argument float function to the `value` argument.
+fn apply(args: &SahaFunctionArguments, fn_name: &str, func: fn(f64) -> f64) -> SahaCallResult {
+    return finite_value(fn_name, func(float_arg(args, "value")));
+}
+
+fn abs(args: SahaFunctionArguments) -> SahaCallResult {
+    return apply(&args, "abs", f64::abs);
+}
+
+fn min(args: SahaFunctionArguments) -> SahaCallResult {
+    return finite_value("min", float_arg(&args, "a").min(float_arg(&args, "b")));
+}
+
+fn max(args: SahaFunctionArguments) -> SahaCallResult {
+    return finite_value("max", float_arg(&args, "a").max(float_arg(&args, "b")));
+}
+
+/// Restrict a value to a range. Errors if the range minimum is larger than the
+/// maximum.
+fn clamp(args: SahaFunctionArguments) -> SahaCallResult {
+    let (min, max) = (float_arg(&args, "min"), float_arg(&args, "max"));
+
+    if min > max {
+        return Err(clamp_range_error(min, max));
+    }
+
+    return finite_value("clamp", float_arg(&args, "value").clamp(min, max));
+}
+
+/// Absolute value of an int. Errors for the smallest int, which has no
+/// positive counterpart.
+fn abs_int(args: SahaFunctionArguments) -> SahaCallResult {
+    return match int_arg(&args, "value").checked_abs() {
+        Some(value) => Ok(Value::int(value)),
+        None => Err(RuntimeError::new("Result of `absInt` is too large for an `int`", None))
+    };
+}
+
+fn min_int(args: SahaFunctionArguments) -> SahaCallResult {
+    return Ok(Value::int(int_arg(&args, "a").min(int_arg(&args, "b"))));
+}
+
+fn max_int(args: SahaFunctionArguments) -> SahaCallResult {
+    return Ok(Value::int(int_arg(&args, "a").max(int_arg(&args, "b"))));
+}
+
+/// Restrict an int to a range. Errors if the range minimum is larger than the
+/// maximum.
+fn clamp_int(args: SahaFunctionArguments) -> SahaCallResult {
+    let (min, max) = (int_arg(&args, "min"), int_arg(&args, "max"));
+
+    if min > max {
+        return Err(clamp_range_error(min, max));
+    }
+
+    return Ok(Value::int(int_arg(&args, "value").clamp(min, max)));
+}
+
+/// Error for clamping to a range where the minimum is larger than the maximum.
+fn clamp_range_error<T: std::fmt::Display>(min: T, max: T) -> RuntimeError {
+    return RuntimeError::new(&format!("Cannot clamp to a range where `min` {} is larger than `max` {}", min, max), None);
+}
+
+fn pow(args: SahaFunctionArguments) -> SahaCallResult {
+    return finite_value("pow", float_arg(&args, "base").powf(float_arg(&args, "exponent")));
+}
+
+fn sqrt(args: SahaFunctionArguments) -> SahaCallResult {
+    return apply(&args, "sqrt", f64::sqrt);
+}
+
+fn floor(args: SahaFunctionArguments) -> SahaCallResult {
+    return apply(&args, "floor", f64::floor);
+}
+
+fn ceil(args: SahaFunctionArguments) -> SahaCallResult {
+    return apply(&args, "ceil", f64::ceil);
+}
+
+/// Round half-way values away from zero.
+fn round(args: SahaFunctionArguments) -> SahaCallResult {
+    return apply(&args, "round", f64::round);
+}
+
+fn trunc(args: SahaFunctionArguments) -> SahaCallResult {
+    return apply(&args, "trunc", f64::trunc);
+}
+
+fn sin(args: SahaFunctionArguments) -> SahaCallResult {
+    return apply(&args, "sin", f64::sin);
+}
+
+fn cos(args: SahaFunctionArguments) -> SahaCallResult {
+    return apply(&args, "cos", f64::cos);
+}
+
+fn tan(args: SahaFunctionArguments) -> SahaCallResult {
+    return apply(&args, "tan", f64::tan);
+}
+
+fn atan2(args: SahaFunctionArguments) -> SahaCallResult {
+    return finite_value("atan2", float_arg(&args, "y").atan2(float_arg(&args, "x")));
+}
+
+/// Logarithm with a base, which defaults to 10. Bases 10 and 2 are calculated
+/// directly for exact results with powers of the base.
+fn log(args: SahaFunctionArguments) -> SahaCallResult {
+    let (value, base) = (float_arg(&args, "value"), float_arg(&args, "base"));
+
+    let result = match base {
+        10.0 => value.log10(),
+        2.0 => value.log2(),
+        _ => value.log(base)
+    };
+
+    return finite_value("log", result);
+}
+
+/// Natural logarithm.
+fn ln(args: SahaFunctionArguments) -> SahaCallResult {
+    return apply(&args, "ln", f64::ln);
+}
+
+fn exp(args: SahaFunctionArguments) -> SahaCallResult {
+    return apply(&args, "exp", f64::exp);
+}
+
+/// Saha floats can never be NaN, so this is always false.
+fn is_nan(args: SahaFunctionArguments) -> SahaCallResult {
+    return Ok(Value::bool(float_arg(&args, "value").is_nan()));
+}
+
+/// Saha floats can never be infinite, so this is always false.
+fn is_infinite(args: SahaFunctionArguments) -> SahaCallResult {
+    return Ok(Value::bool(float_arg(&args, "value").is_infinite()));
+}
+
+/// Greatest common divisor, which is never negative.
+fn gcd(args: SahaFunctionArguments) -> SahaCallResult {
+    let (mut a, mut b) = (int_arg(&args, "a").unsigned_abs(), int_arg(&args, "b").unsigned_abs());
+
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    if a > isize::MAX as usize {
+        return Err(RuntimeError::new("Result of `gcd` is too large for an `int`", None));
+    }
+
+    return Ok(Value::int(a as isize));
+}
+
+/// Floored division and remainder, where the remainder has the sign of the
+/// divisor.
+fn divmod(args: SahaFunctionArguments) -> SahaCallResult {
+    let (a, b) = (int_arg(&args, "a"), int_arg(&args, "b"));
+
+    if b == 0 {
+        return Err(RuntimeError::new("Division by zero", None));
+    }
+
+    let mut quotient = match a.checked_div(b) {
+        Some(q) => q,
+        None => return Err(RuntimeError::new("Result of `divmod` is too large for an `int`", None))
+    };
+
+    let mut remainder = a % b;
+
+    if remainder != 0 && (remainder < 0) != (b < 0) {
+        quotient -= 1;
+        remainder += b;
+    }
+
+    return Ok(Value::tuple(
+        vec![Value::int(quotient), Value::int(remainder)],
+        vec![Box::new(SahaType::Int), Box::new(SahaType::Int)]
+    ));
+}
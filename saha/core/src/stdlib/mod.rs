@@ -1,55 +1,214 @@
 //! Saha standard library
 //!
 //! The Saha stdlib contains generic language features that are usable in all
-//! Saha code bases.
+//! Saha code bases. The stdlib is organised into named modules, such as
+//! `std.io` and `std.collections`, whose members can be imported with
+//! `use std.io.print_line;`.
+//!
+//! Members are registered under their fully qualified names, and need to be
+//! imported before use. Only the members listed in the prelude are registered
+//! under their plain names instead, and are available without importing them.
 
 mod globals;
+mod fs;
+mod file;
+mod env;
+mod process;
+mod math;
+mod random;
+mod time;
+mod datetime;
+mod json;
+mod regex;
+mod strings;
 
-use saha_lib::types::functions::CoreFunction;
+use saha_lib::errors::RuntimeError;
+use saha_lib::types::{
+    Value,
+    functions::CoreFunction,
+    objects::{CoreConstructorFn, CoreMethodTypeFn, BehaviorDefinition}
+};
 
-pub fn register_stdlib() {
-    let mut funcs: Vec<(String, CoreFunction)> = Vec::new();
+/// Standard library members which are available without importing them. The
+/// runtime refers to the core classes and behaviors in here by their plain
+/// names.
+const PRELUDE: &[&str] = &[
+    "std.io.print",
+    "std.io.print_line",
+    "std.io.print_err",
+    "std.io.print_err_line",
+    "std.collections.List",
+    "std.collections.Dict",
+    "std.collections.Set",
+    "std.core.assert",
+    "std.core.Option",
+    "std.core.Result",
+    "std.core.Add",
+    "std.core.Sub",
+    "std.core.Mul",
+    "std.core.Equals",
+    "std.core.Comparable",
+    "std.core.Hashable",
+    "std.core.Displayable",
+    "std.core.Cloneable",
+];
 
-    funcs.append(&mut globals::print::get_saha_functions());
+/// A named standard library module and its members.
+struct StdModule {
+    /// Fully qualified module name, e.g. `std.io`.
+    name: &'static str,
+    functions: Vec<(String, CoreFunction)>,
+    constants: Vec<(String, Value)>,
+    classes: Vec<(String, CoreConstructorFn, CoreMethodTypeFn)>,
+    behaviors: Vec<BehaviorDefinition>
+}
 
-    {
-        let mut st = saha_lib::SAHA_SYMBOL_TABLE.lock().unwrap();
+impl StdModule {
+    /// Create a new empty module.
+    fn new(name: &'static str) -> StdModule {
+        return StdModule {
+            name: name,
+            functions: Vec::new(),
+            constants: Vec::new(),
+            classes: Vec::new(),
+            behaviors: Vec::new()
+        };
+    }
 
-        for (fnname, fnitem) in funcs {
-            st.functions.insert(fnname, Box::new(fnitem));
-        }
+    /// Get the fully qualified name of a module member.
+    fn qualified_name(&self, member: &str) -> String {
+        return format!("{}.{}", self.name, member);
     }
 
-    register_list_class();
-    register_dict_class();
-    register_result_class();
-    register_option_class();
-}
+    /// Get the name under which a module member is registered into the symbol
+    /// table.
+    fn registered_name(&self, member: &str) -> String {
+        let qualified_name = self.qualified_name(member);
 
-/// Register the stdlib List type.
-fn register_list_class() {
-    let mut st = saha_lib::SAHA_SYMBOL_TABLE.lock().unwrap();
+        if PRELUDE.contains(&qualified_name.as_str()) {
+            return member.to_string();
+        }
 
-    st.core_classes.insert("List".to_string(), globals::list::new_instance);
+        return qualified_name;
+    }
 }
 
-/// Register the stdlib Dict type.
-fn register_dict_class() {
-    let mut st = saha_lib::SAHA_SYMBOL_TABLE.lock().unwrap();
+/// Get all standard library modules with their members.
+fn get_std_modules() -> Vec<StdModule> {
+    let mut io = StdModule::new("std.io");
 
-    st.core_classes.insert("Dict".to_string(), globals::dict::new_instance);
-}
+    io.functions.append(&mut globals::print::get_saha_functions());
+    io.functions.append(&mut globals::input::get_saha_functions());
+    io.classes.push(("Stdin".to_string(), globals::input::new_instance, globals::input::method_type));
 
-/// Register the stdlib Result type.
-fn register_result_class() {
-    let mut st = saha_lib::SAHA_SYMBOL_TABLE.lock().unwrap();
+    let mut collections = StdModule::new("std.collections");
+
+    collections.classes.push(("List".to_string(), globals::list::new_instance, globals::list::method_type));
+    collections.classes.push(("Dict".to_string(), globals::dict::new_instance, globals::dict::method_type));
+    collections.classes.push(("Set".to_string(), globals::set::new_instance, globals::set::method_type));
+
+    let mut core = StdModule::new("std.core");
+
+    core.functions.append(&mut globals::assert::get_saha_functions());
+    core.classes.push(("Result".to_string(), globals::result::new_instance, globals::result::method_type));
+    core.classes.push(("Option".to_string(), globals::option::new_instance, globals::option::method_type));
+    core.behaviors.append(&mut globals::operators::get_saha_behaviors());
+    core.behaviors.append(&mut globals::display::get_saha_behaviors());
+    core.behaviors.append(&mut globals::clone::get_saha_behaviors());
+
+    let mut filesystem = StdModule::new("std.fs");
+
+    filesystem.functions.append(&mut fs::get_saha_functions());
+    filesystem.classes.push(("File".to_string(), file::new_instance, file::method_type));
+
+    let mut environment = StdModule::new("std.env");
+
+    environment.functions.append(&mut env::get_saha_functions());
+
+    let mut process_module = StdModule::new("std.process");
+
+    process_module.functions.append(&mut process::get_saha_functions());
+
+    let mut math_module = StdModule::new("std.math");
+
+    math_module.functions.append(&mut math::get_saha_functions());
+    math_module.constants.append(&mut math::get_saha_constants());
+
+    let mut random_module = StdModule::new("std.random");
+
+    random_module.functions.append(&mut random::get_saha_functions());
+    random_module.classes.push(("Rng".to_string(), random::new_instance, random::method_type));
+
+    let mut time_module = StdModule::new("std.time");
 
-    st.core_classes.insert("Result".to_string(), globals::result::new_instance);
+    time_module.functions.append(&mut time::get_saha_functions());
+    time_module.classes.push(("Duration".to_string(), time::new_instance, time::method_type));
+    time_module.classes.push(("DateTime".to_string(), datetime::new_instance, datetime::method_type));
+
+    let mut json_module = StdModule::new("std.json");
+
+    json_module.functions.append(&mut json::get_saha_functions());
+    json_module.classes.push(("JsonValue".to_string(), json::new_json_value_instance, json::json_value_method_type));
+    json_module.classes.push(("Decoder".to_string(), json::new_decoder_instance, json::decoder_method_type));
+
+    let mut regex_module = StdModule::new("std.regex");
+
+    regex_module.functions.append(&mut regex::get_saha_functions());
+    regex_module.classes.push(("Regex".to_string(), regex::new_regex_instance, regex::regex_method_type));
+    regex_module.classes.push(("Match".to_string(), regex::new_match_instance, regex::match_method_type));
+
+    let mut str_module = StdModule::new("std.str");
+
+    str_module.functions.append(&mut strings::get_saha_functions());
+
+    return vec![
+        io, collections, core, str_module, filesystem, environment, process_module, math_module, random_module, time_module, json_module, regex_module
+    ];
 }
 
-/// Register the stdlib Result type.
-fn register_option_class() {
+/// Release stdlib resources when the program ends, e.g. files which were left
+/// open for writing.
+pub fn shutdown_stdlib() -> Vec<RuntimeError> {
+    return file::close_open_files();
+}
+
+pub fn register_stdlib() {
+    let modules = get_std_modules();
+
     let mut st = saha_lib::SAHA_SYMBOL_TABLE.lock().unwrap();
 
-    st.core_classes.insert("Option".to_string(), globals::option::new_instance);
-}
\ No newline at end of file
+    for mut module in modules {
+        for (fnname, mut fnitem) in std::mem::take(&mut module.functions) {
+            let name = module.registered_name(&fnname);
+
+            fnitem.name = name.clone();
+
+            st.std_members.insert(module.qualified_name(&fnname), name.clone());
+            st.functions.insert(name, Box::new(fnitem));
+        }
+
+        for (constname, value) in std::mem::take(&mut module.constants) {
+            let name = module.registered_name(&constname);
+
+            st.std_members.insert(module.qualified_name(&constname), name.clone());
+            st.constants.insert(name, value);
+        }
+
+        for (classname, constructor, method_type) in module.classes.iter() {
+            let name = module.registered_name(classname);
+
+            st.std_members.insert(module.qualified_name(classname), name.clone());
+            st.core_classes.insert(name.clone(), *constructor);
+            st.core_method_types.insert(name, *method_type);
+        }
+
+        for mut behavior in std::mem::take(&mut module.behaviors) {
+            let name = module.registered_name(&behavior.name);
+
+            behavior.fqname = name.clone();
+
+            st.std_members.insert(module.qualified_name(&behavior.name), name.clone());
+            st.behaviors.insert(name, behavior);
+        }
+    }
+}
@@ -0,0 +1,27 @@
+//! stdlib Process
+//!
+//! The `std.process` module, for controlling the running program.
+
+use saha_lib::prelude::*;
+
+use crate::utils::{create_core_function, create_core_param};
+
+/// Register module stdlib parts.
+pub fn get_saha_functions() -> Vec<(String, CoreFunction)> {
+    return vec![
+        create_core_function(
+            "exit",
+            vec![create_core_param("code", SahaType::Int, 0)],
+            Box::new(SahaType::Void),
+            exit
+        ),
+    ];
+}
+
+/// Exit the program with an exit code. The call stack is unwound before the
+/// interpreter exits, instead of exiting the process immediately.
+fn exit(args: SahaFunctionArguments) -> SahaCallResult {
+    let code = args["code"].int.unwrap();
+
+    return Err(RuntimeError::exit(code as i32));
+}
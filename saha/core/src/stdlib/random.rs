@@ -0,0 +1,393 @@
+//! stdlib Random
+//!
+//! The `std.random` module, with the `std.random.Rng` random number generator
+//! class. Generators are xoshiro256** generators seeded with SplitMix64, so a
+//! seed produces the same sequence of values on every platform.
+//!
+//! Generators are not suitable for cryptography.
+
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    sync::{Arc, Mutex, OnceLock},
+    time::{SystemTime, UNIX_EPOCH}
+};
+
+use saha_lib::prelude::*;
+use saha_lib::types::functions::positional_arg_name;
+
+use crate::{
+    stdlib::globals::option::SahaOption,
+    utils::{create_core_function, create_core_param, get_new_instref, add_instance_to_symbol_table}
+};
+
+/// Fully qualified name of the `Rng` class.
+pub const RNG_CLASS_NAME: &str = "std.random.Rng";
+
+/// Items and item type of a list argument, along with the lockable list
+/// instance.
+type ListItems = (Vec<Value>, Box<SahaType>, Arc<Mutex<Box<dyn SahaObject>>>);
+
+/// State of the default generator, shared by the whole program.
+static DEFAULT_STATE: OnceLock<Arc<Mutex<RngState>>> = OnceLock::new();
+
+/// Register module stdlib parts.
+pub fn get_saha_functions() -> Vec<(String, CoreFunction)> {
+    let rng_type = SahaType::Name(RNG_CLASS_NAME.to_string(), Vec::new());
+
+    return vec![
+        create_core_function("rng", Vec::new(), Box::new(rng_type), rng),
+    ];
+}
+
+/// Create a new Rng instance from a seed.
+pub fn new_instance(
+    instref: InstRef,
+    args: &SahaFunctionArguments,
+    _type_params: &[Box<SahaType>],
+    _additional_data: &SahaFunctionArguments,
+    create_pos: Option<FilePosition>
+) -> Result<Box<dyn SahaObject>, RuntimeError> {
+    let mut params: SahaFunctionParamDefs = SahaFunctionParamDefs::new();
+    let (pname, param) = create_core_param("seed", SahaType::Int, 0);
+
+    params.insert(pname.to_string(), param);
+
+    let args = params.validate_args(args, &create_pos)?;
+    let seed = args["seed"].int.unwrap();
+
+    let rng_inst = Box::new(SahaRng {
+        instref: instref,
+        state: Arc::new(Mutex::new(RngState::from_seed(seed as i64 as u64)))
+    });
+
+    return Ok(rng_inst);
+}
+
+/// Resolve the return type of an `Rng` method. The type of `choice` depends on
+/// the given list, so it is not resolved.
+pub fn method_type(_type_params: &[Box<SahaType>], method_name: &str) -> Option<SahaType> {
+    return match method_name {
+        "int" => Some(SahaType::Int),
+        "float" => Some(SahaType::Float),
+        "bool" => Some(SahaType::Bool),
+        "shuffle" => Some(SahaType::Void),
+        _ => None
+    };
+}
+
+/// Get the default generator, which is seeded from the operating system when
+/// it is first used. All default generator instances share the same state.
+fn rng(_args: SahaFunctionArguments) -> SahaCallResult {
+    let state = DEFAULT_STATE.get_or_init(|| Arc::new(Mutex::new(RngState::from_seed(os_seed()))));
+
+    let instref = get_new_instref();
+
+    return Ok(add_instance_to_symbol_table(instref, Box::new(SahaRng {
+        instref: instref,
+        state: state.clone()
+    })));
+}
+
+/// Get a seed from the randomly keyed hasher state of the standard library,
+/// mixed with the current time.
+fn os_seed() -> u64 {
+    let mut hasher = RandomState::new().build_hasher();
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_nanos()).unwrap_or(0);
+
+    hasher.write_u128(nanos);
+
+    return hasher.finish();
+}
+
+/// xoshiro256** generator state.
+struct RngState {
+    s: [u64; 4]
+}
+
+impl RngState {
+    /// Expand a seed into a full generator state with SplitMix64.
+    fn from_seed(seed: u64) -> RngState {
+        let mut sm = seed;
+        let mut s = [0; 4];
+
+        for part in s.iter_mut() {
+            sm = sm.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+            let mut z = sm;
+
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+
+            *part = z ^ (z >> 31);
+        }
+
+        return RngState { s: s };
+    }
+
+    /// Get the next 64 random bits.
+    fn next_u64(&mut self) -> u64 {
+        let s = &mut self.s;
+        let result = s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = s[1] << 17;
+
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+
+        return result;
+    }
+
+    /// Get a uniformly distributed value below a bound. Values which would
+    /// bias the result towards small numbers are rejected.
+    fn next_below(&mut self, bound: u64) -> u64 {
+        let threshold = bound.wrapping_neg() % bound;
+
+        loop {
+            let value = self.next_u64();
+
+            if value >= threshold {
+                return value % bound;
+            }
+        }
+    }
+
+    /// Get a float in the range `[0, 1)`.
+    fn next_float(&mut self) -> f64 {
+        return (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+    }
+}
+
+/// SahaRng is the core definition of the `Rng` type in Saha. Clones share the
+/// same generator state.
+#[derive(Clone)]
+struct SahaRng {
+    instref: InstRef,
+    state: Arc<Mutex<RngState>>
+}
+
+impl SahaObject for SahaRng {
+    fn get_instance_ref(&self) -> InstRef {
+        return self.instref;
+    }
+
+    fn is_core_defined(&self) -> bool {
+        return true;
+    }
+
+    fn get_class_name(&self) -> String {
+        return "Rng".to_string();
+    }
+
+    fn get_fully_qualified_class_name(&self) -> String {
+        return RNG_CLASS_NAME.to_string();
+    }
+
+    fn get_implements(&self) -> Vec<String> {
+        return Vec::new();
+    }
+
+    fn get_full_method_name(&mut self, _method_name: &str) -> String {
+        unimplemented!()
+    }
+
+    fn get_method_ref(&mut self, _method_name: &str) -> Result<Arc<Box<dyn SahaCallable>>, RuntimeError> {
+        unimplemented!()
+    }
+
+    fn get_type_params(&self) -> Vec<(char, Box<SahaType>)> {
+        return Vec::new();
+    }
+
+    fn get_named_type(&self) -> Box<SahaType> {
+        return Box::new(SahaType::Name(RNG_CLASS_NAME.to_string(), Vec::new()));
+    }
+
+    fn call_member(&mut self, access: AccessParams, args: SahaFunctionArguments) -> SahaCallResult {
+        if access.is_static_access {
+            return Err(RuntimeError::new(
+                &format!("No static method `{}` defined for `{}`", access.member_name, self.get_class_name()),
+                access.access_file_pos.clone()
+            ));
+        }
+
+        match access.member_name as &str {
+            "int" => self.int(&args, access),
+            "float" => self.float(&args, access),
+            "bool" => self.bool(&args, access),
+            "choice" => self.choice(&args, access),
+            "shuffle" => self.shuffle(&args, access),
+            _ => {
+                return Err(RuntimeError::new(
+                    &format!("No method `{}` defined for `{}`", access.member_name, self.get_class_name()),
+                    access.access_file_pos.clone()
+                ));
+            }
+        }
+    }
+
+    fn access_property(&self, _access: AccessParams) -> SahaCallResult {
+        unimplemented!()
+    }
+
+    fn mutate_property(&mut self, _access: AccessParams, _new_value: Value) -> SahaCallResult {
+        unimplemented!()
+    }
+
+    fn box_clone(&self) -> Box<dyn SahaObject> {
+        return Box::new(self.clone());
+    }
+
+    fn into_iter(&self) -> Box<dyn Iterator<Item = (Value, Value)>> {
+        unimplemented!()
+    }
+
+    fn set_data_from_iter(&mut self, _iterator: Box<dyn Iterator<Item = (Value, Value)>>) {
+        unimplemented!()
+    }
+}
+
+impl SahaRng {
+    /// Get function parameter definitions for the Rng::choice and
+    /// Rng::shuffle methods. The list item type is taken from the given
+    /// list, anything other than a list fails validation.
+    fn list_params(&self, args: &SahaFunctionArguments) -> SahaFunctionParamDefs {
+        let mut list_type = SahaType::Name("List".to_string(), vec![Box::new(SahaType::TypeParam('T'))]);
+
+        let list_arg = args.get("list").or_else(|| args.get(&positional_arg_name(0)));
+
+        if let Some(value) = list_arg.filter(|v| *v.kind == SahaType::Obj) {
+            let inst = saha_lib::SAHA_SYMBOL_TABLE.lock().unwrap().instances.get(&value.obj.unwrap()).cloned();
+
+            if let Some(inst) = inst {
+                let named_type = inst.lock().unwrap().get_named_type();
+
+                if let SahaType::Name(ref name, _) = *named_type {
+                    if name == "List" {
+                        list_type = *named_type;
+                    }
+                }
+            }
+        }
+
+        let mut params: SahaFunctionParamDefs = SahaFunctionParamDefs::new();
+        let (pname, param) = create_core_param("list", list_type, 0);
+
+        params.insert(pname.to_string(), param);
+
+        return params;
+    }
+
+    /// Get the items and item type of a validated list argument, along with
+    /// the lockable list instance.
+    fn list_items(&self, list: &Value) -> ListItems {
+        let inst = saha_lib::SAHA_SYMBOL_TABLE.lock().unwrap().instances.get(&list.obj.unwrap()).cloned().unwrap();
+
+        let (items, item_type) = {
+            let list_inst = inst.lock().unwrap();
+
+            (list_inst.into_iter().map(|(_, v)| v).collect(), list_inst.get_type_params()[0].1.clone())
+        };
+
+        return (items, item_type, inst);
+    }
+
+    /// The Rng::int "method". Returns an int between `min` and `max`,
+    /// inclusive.
+    pub fn int(&mut self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let mut params: SahaFunctionParamDefs = SahaFunctionParamDefs::new();
+
+        for (pname, param) in [create_core_param("min", SahaType::Int, 0), create_core_param("max", SahaType::Int, 1)] {
+            params.insert(pname.to_string(), param);
+        }
+
+        let args = params.validate_args(args, access.access_file_pos)?;
+        let (min, max) = (args["min"].int.unwrap() as i64, args["max"].int.unwrap() as i64);
+
+        if min > max {
+            let err = RuntimeError::new(
+                &format!("Cannot get a random int between `min` {} and a smaller `max` {}", min, max),
+                access.access_file_pos.clone()
+            );
+
+            return Err(err);
+        }
+
+        let mut state = self.state.lock().unwrap();
+        let span = max.wrapping_sub(min) as u64;
+
+        let offset = match span.checked_add(1) {
+            Some(bound) => state.next_below(bound),
+            None => state.next_u64()
+        };
+
+        return Ok(Value::int(min.wrapping_add(offset as i64) as isize));
+    }
+
+    /// The Rng::float "method". Returns a float between 0 and 1, excluding 1.
+    pub fn float(&mut self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let params: SahaFunctionParamDefs = SahaFunctionParamDefs::new(); // no params
+
+        params.validate_args(args, access.access_file_pos)?;
+
+        let value = self.state.lock().unwrap().next_float();
+
+        return Ok(Value::float(noisy_float::prelude::r64(value)));
+    }
+
+    /// The Rng::bool "method".
+    pub fn bool(&mut self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let params: SahaFunctionParamDefs = SahaFunctionParamDefs::new(); // no params
+
+        params.validate_args(args, access.access_file_pos)?;
+
+        let value = self.state.lock().unwrap().next_u64() >> 63 == 1;
+
+        return Ok(Value::bool(value));
+    }
+
+    /// The Rng::choice "method". Returns a random item from a list, or none
+    /// if the list is empty.
+    pub fn choice(&mut self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let args = self.list_params(args).validate_args(args, access.access_file_pos)?;
+
+        let (items, item_type, _) = self.list_items(&args["list"]);
+
+        let item = match items.len() {
+            0 => None,
+            len => {
+                let idx = self.state.lock().unwrap().next_below(len as u64) as usize;
+
+                Some(items[idx].clone())
+            }
+        };
+
+        return Ok(SahaOption::create_value(item, item_type));
+    }
+
+    /// The Rng::shuffle "method". Shuffles the items of a list in place.
+    pub fn shuffle(&mut self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let args = self.list_params(args).validate_args(args, access.access_file_pos)?;
+
+        let (mut items, _, inst) = self.list_items(&args["list"]);
+
+        {
+            let mut state = self.state.lock().unwrap();
+
+            for i in (1..items.len()).rev() {
+                let j = state.next_below(i as u64 + 1) as usize;
+
+                items.swap(i, j);
+            }
+        }
+
+        let shuffled = items.into_iter().enumerate().map(|(idx, v)| (Value::int(idx as isize), v));
+
+        inst.lock().unwrap().set_data_from_iter(Box::new(shuffled));
+
+        return Ok(Value::void());
+    }
+}
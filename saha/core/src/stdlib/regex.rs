@@ -0,0 +1,443 @@
+//! stdlib Regex
+//!
+//! The `std.regex` module. Patterns are compiled with `std.regex.compile` into
+//! `std.regex.Regex` instances, and matches are `std.regex.Match` instances.
+//! Match positions are character offsets into the searched string, not byte
+//! offsets.
+
+use std::{
+    collections::HashMap,
+    convert::TryFrom,
+    sync::Arc
+};
+
+use regex::{Captures, Regex};
+
+use saha_lib::prelude::*;
+
+use crate::{
+    stdlib::globals::{
+        option::SahaOption,
+        result::SahaResult,
+        list::create_list_value
+    },
+    utils::{create_core_function, create_core_param, get_new_instref, add_instance_to_symbol_table}
+};
+
+/// Fully qualified name of the `Regex` class.
+pub const REGEX_CLASS_NAME: &str = "std.regex.Regex";
+
+/// Fully qualified name of the `Match` class.
+pub const MATCH_CLASS_NAME: &str = "std.regex.Match";
+
+/// Register module stdlib parts.
+pub fn get_saha_functions() -> Vec<(String, CoreFunction)> {
+    let regex_type = SahaType::Name(REGEX_CLASS_NAME.to_string(), Vec::new());
+    let result_of_regex = SahaType::Name("Result".to_string(), vec![Box::new(regex_type), Box::new(SahaType::Str)]);
+
+    return vec![
+        create_core_function(
+            "compile",
+            vec![create_core_param("pattern", SahaType::Str, 0)],
+            Box::new(result_of_regex),
+            compile
+        ),
+    ];
+}
+
+/// `Regex` instances are created by compiling a pattern, not with `new`.
+pub fn new_regex_instance(
+    _instref: InstRef,
+    _args: &SahaFunctionArguments,
+    _type_params: &[Box<SahaType>],
+    _additional_data: &SahaFunctionArguments,
+    create_pos: Option<FilePosition>
+) -> Result<Box<dyn SahaObject>, RuntimeError> {
+    let err = RuntimeError::new("`Regex` cannot be created with `new`, use `std.regex.compile` instead", create_pos);
+
+    return Err(err);
+}
+
+/// Resolve the return type of a `Regex` method.
+pub fn regex_method_type(_type_params: &[Box<SahaType>], method_name: &str) -> Option<SahaType> {
+    let match_type = || Box::new(SahaType::Name(MATCH_CLASS_NAME.to_string(), Vec::new()));
+
+    return match method_name {
+        "pattern" | "display" | "replace" | "replaceAll" => Some(SahaType::Str),
+        "isMatch" => Some(SahaType::Bool),
+        "find" => Some(SahaType::Name("Option".to_string(), vec![match_type()])),
+        "findAll" => Some(SahaType::Name("List".to_string(), vec![match_type()])),
+        "split" => Some(SahaType::Name("List".to_string(), vec![Box::new(SahaType::Str)])),
+        _ => None
+    };
+}
+
+/// `Match` instances are created by searching with a `Regex`, not with `new`.
+pub fn new_match_instance(
+    _instref: InstRef,
+    _args: &SahaFunctionArguments,
+    _type_params: &[Box<SahaType>],
+    _additional_data: &SahaFunctionArguments,
+    create_pos: Option<FilePosition>
+) -> Result<Box<dyn SahaObject>, RuntimeError> {
+    let err = RuntimeError::new("`Match` cannot be created with `new`, use `Regex::find` instead", create_pos);
+
+    return Err(err);
+}
+
+/// Resolve the return type of a `Match` method.
+pub fn match_method_type(_type_params: &[Box<SahaType>], method_name: &str) -> Option<SahaType> {
+    return match method_name {
+        "text" | "display" => Some(SahaType::Str),
+        "start" | "end" | "groupCount" => Some(SahaType::Int),
+        "group" | "named" => Some(SahaType::Name("Option".to_string(), vec![Box::new(SahaType::Str)])),
+        _ => None
+    };
+}
+
+/// Compile a regex pattern. Invalid patterns result in a failure describing
+/// the problem.
+fn compile(args: SahaFunctionArguments) -> SahaCallResult {
+    let pattern = args["pattern"].str.clone().unwrap();
+
+    let outcome = match Regex::new(&pattern) {
+        Ok(regex) => {
+            let instref = get_new_instref();
+
+            Ok(add_instance_to_symbol_table(instref, Box::new(SahaRegex {
+                instref: instref,
+                regex: regex
+            })))
+        },
+        Err(err) => Err(Value::str(format!("Invalid regex: {}", err)))
+    };
+
+    let regex_type = SahaType::Name(REGEX_CLASS_NAME.to_string(), Vec::new());
+
+    return Ok(SahaResult::create_value(outcome, Box::new(regex_type), Box::new(SahaType::Str)));
+}
+
+/// Create parameter definitions for method parameters which are all strings.
+fn str_params(names: &[&str]) -> SahaFunctionParamDefs {
+    let mut params: SahaFunctionParamDefs = SahaFunctionParamDefs::new();
+
+    for (position, name) in names.iter().enumerate() {
+        let (pname, param) = create_core_param(name, SahaType::Str, position);
+
+        params.insert(pname.to_string(), param);
+    }
+
+    return params;
+}
+
+/// Convert a byte offset in a string to a character offset.
+fn char_offset(text: &str, byte_offset: usize) -> isize {
+    return text[..byte_offset].chars().count() as isize;
+}
+
+/// Create an `Option<str>` value.
+fn str_option(value: Option<String>) -> Value {
+    return SahaOption::create_value(value.map(Value::str), Box::new(SahaType::Str));
+}
+
+/// SahaRegex is the core definition of the `Regex` type in Saha, a compiled
+/// regex pattern.
+#[derive(Clone)]
+struct SahaRegex {
+    instref: InstRef,
+    regex: Regex
+}
+
+impl SahaObject for SahaRegex {
+    fn get_instance_ref(&self) -> InstRef {
+        return self.instref;
+    }
+
+    fn is_core_defined(&self) -> bool {
+        return true;
+    }
+
+    fn get_class_name(&self) -> String {
+        return "Regex".to_string();
+    }
+
+    fn get_fully_qualified_class_name(&self) -> String {
+        return REGEX_CLASS_NAME.to_string();
+    }
+
+    fn get_implements(&self) -> Vec<String> {
+        return vec!["Displayable".to_string()];
+    }
+
+    fn get_full_method_name(&mut self, _method_name: &str) -> String {
+        unimplemented!()
+    }
+
+    fn get_method_ref(&mut self, _method_name: &str) -> Result<Arc<Box<dyn SahaCallable>>, RuntimeError> {
+        unimplemented!()
+    }
+
+    fn get_type_params(&self) -> Vec<(char, Box<SahaType>)> {
+        return Vec::new();
+    }
+
+    fn get_named_type(&self) -> Box<SahaType> {
+        return Box::new(SahaType::Name(REGEX_CLASS_NAME.to_string(), Vec::new()));
+    }
+
+    fn call_member(&mut self, access: AccessParams, args: SahaFunctionArguments) -> SahaCallResult {
+        if access.is_static_access {
+            return Err(RuntimeError::new(
+                &format!("No static method `{}` defined for `{}`", access.member_name, self.get_class_name()),
+                access.access_file_pos.clone()
+            ));
+        }
+
+        match access.member_name as &str {
+            "pattern" | "display" => {
+                str_params(&[]).validate_args(&args, access.access_file_pos)?;
+
+                return Ok(Value::str(self.regex.as_str().to_string()));
+            },
+            "isMatch" => self.is_match(&args, access),
+            "find" => self.find(&args, access),
+            "findAll" => self.find_all(&args, access),
+            "replace" => self.replace(&args, access, 1),
+            "replaceAll" => self.replace(&args, access, 0),
+            "split" => self.split(&args, access),
+            _ => {
+                return Err(RuntimeError::new(
+                    &format!("No method `{}` defined for `{}`", access.member_name, self.get_class_name()),
+                    access.access_file_pos.clone()
+                ));
+            }
+        }
+    }
+
+    fn access_property(&self, _access: AccessParams) -> SahaCallResult {
+        unimplemented!()
+    }
+
+    fn mutate_property(&mut self, _access: AccessParams, _new_value: Value) -> SahaCallResult {
+        unimplemented!()
+    }
+
+    fn box_clone(&self) -> Box<dyn SahaObject> {
+        return Box::new(self.clone());
+    }
+
+    fn into_iter(&self) -> Box<dyn Iterator<Item = (Value, Value)>> {
+        unimplemented!()
+    }
+
+    fn set_data_from_iter(&mut self, _iterator: Box<dyn Iterator<Item = (Value, Value)>>) {
+        unimplemented!()
+    }
+}
+
+impl SahaRegex {
+    /// Create a new `Match` instance from regex captures.
+    fn create_match(&self, text: &str, captures: &Captures) -> Value {
+        let whole = captures.get(0).unwrap();
+
+        let groups = captures.iter()
+            .map(|group| group.map(|m| m.as_str().to_string()))
+            .collect();
+
+        let names = self.regex.capture_names()
+            .enumerate()
+            .filter_map(|(idx, name)| name.map(|n| (n.to_string(), idx)))
+            .collect();
+
+        let instref = get_new_instref();
+
+        return add_instance_to_symbol_table(instref, Box::new(SahaMatch {
+            instref: instref,
+            start: char_offset(text, whole.start()),
+            end: char_offset(text, whole.end()),
+            groups: groups,
+            names: names
+        }));
+    }
+
+    /// The Regex::isMatch "method".
+    pub fn is_match(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let args = str_params(&["text"]).validate_args(args, access.access_file_pos)?;
+
+        return Ok(Value::bool(self.regex.is_match(&args["text"].str.clone().unwrap())));
+    }
+
+    /// The Regex::find "method". Returns the first match as an `Option<Match>`.
+    pub fn find(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let args = str_params(&["text"]).validate_args(args, access.access_file_pos)?;
+        let text = args["text"].str.clone().unwrap();
+
+        let found = self.regex.captures(&text).map(|captures| self.create_match(&text, &captures));
+
+        return Ok(SahaOption::create_value(found, Box::new(SahaType::Name(MATCH_CLASS_NAME.to_string(), Vec::new()))));
+    }
+
+    /// The Regex::findAll "method". Returns all non-overlapping matches as a
+    /// `List<Match>`.
+    pub fn find_all(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let args = str_params(&["text"]).validate_args(args, access.access_file_pos)?;
+        let text = args["text"].str.clone().unwrap();
+
+        let matches = self.regex.captures_iter(&text)
+            .map(|captures| self.create_match(&text, &captures))
+            .collect();
+
+        return Ok(create_list_value(matches, Box::new(SahaType::Name(MATCH_CLASS_NAME.to_string(), Vec::new()))));
+    }
+
+    /// The Regex::replace and Regex::replaceAll "methods". A limit of 0
+    /// replaces all matches. The replacement can refer to capture groups with
+    /// `$1` or `${name}`, and `$$` is a literal `$`.
+    pub fn replace(&self, args: &SahaFunctionArguments, access: AccessParams, limit: usize) -> SahaCallResult {
+        let args = str_params(&["text", "replacement"]).validate_args(args, access.access_file_pos)?;
+        let text = args["text"].str.clone().unwrap();
+        let replacement = args["replacement"].str.clone().unwrap();
+
+        return Ok(Value::str(self.regex.replacen(&text, limit, replacement.as_str()).to_string()));
+    }
+
+    /// The Regex::split "method". Returns the parts of the text between matches.
+    pub fn split(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let args = str_params(&["text"]).validate_args(args, access.access_file_pos)?;
+        let text = args["text"].str.clone().unwrap();
+
+        let parts = self.regex.split(&text).map(|part| Value::str(part.to_string())).collect();
+
+        return Ok(create_list_value(parts, Box::new(SahaType::Str)));
+    }
+}
+
+/// SahaMatch is the core definition of the `Match` type in Saha, a single
+/// regex match with its capture groups.
+#[derive(Clone)]
+struct SahaMatch {
+    instref: InstRef,
+    start: isize,
+    end: isize,
+    groups: Vec<Option<String>>,
+    names: HashMap<String, usize>
+}
+
+impl SahaObject for SahaMatch {
+    fn get_instance_ref(&self) -> InstRef {
+        return self.instref;
+    }
+
+    fn is_core_defined(&self) -> bool {
+        return true;
+    }
+
+    fn get_class_name(&self) -> String {
+        return "Match".to_string();
+    }
+
+    fn get_fully_qualified_class_name(&self) -> String {
+        return MATCH_CLASS_NAME.to_string();
+    }
+
+    fn get_implements(&self) -> Vec<String> {
+        return vec!["Displayable".to_string()];
+    }
+
+    fn get_full_method_name(&mut self, _method_name: &str) -> String {
+        unimplemented!()
+    }
+
+    fn get_method_ref(&mut self, _method_name: &str) -> Result<Arc<Box<dyn SahaCallable>>, RuntimeError> {
+        unimplemented!()
+    }
+
+    fn get_type_params(&self) -> Vec<(char, Box<SahaType>)> {
+        return Vec::new();
+    }
+
+    fn get_named_type(&self) -> Box<SahaType> {
+        return Box::new(SahaType::Name(MATCH_CLASS_NAME.to_string(), Vec::new()));
+    }
+
+    fn call_member(&mut self, access: AccessParams, args: SahaFunctionArguments) -> SahaCallResult {
+        if access.is_static_access {
+            return Err(RuntimeError::new(
+                &format!("No static method `{}` defined for `{}`", access.member_name, self.get_class_name()),
+                access.access_file_pos.clone()
+            ));
+        }
+
+        match access.member_name as &str {
+            "text" | "display" => self.get(&args, access, Value::str(self.groups[0].clone().unwrap())),
+            "start" => self.get(&args, access, Value::int(self.start)),
+            "end" => self.get(&args, access, Value::int(self.end)),
+            "groupCount" => self.get(&args, access, Value::int(self.groups.len() as isize - 1)),
+            "group" => self.group(&args, access),
+            "named" => self.named(&args, access),
+            _ => {
+                return Err(RuntimeError::new(
+                    &format!("No method `{}` defined for `{}`", access.member_name, self.get_class_name()),
+                    access.access_file_pos.clone()
+                ));
+            }
+        }
+    }
+
+    fn access_property(&self, _access: AccessParams) -> SahaCallResult {
+        unimplemented!()
+    }
+
+    fn mutate_property(&mut self, _access: AccessParams, _new_value: Value) -> SahaCallResult {
+        unimplemented!()
+    }
+
+    fn box_clone(&self) -> Box<dyn SahaObject> {
+        return Box::new(self.clone());
+    }
+
+    fn into_iter(&self) -> Box<dyn Iterator<Item = (Value, Value)>> {
+        unimplemented!()
+    }
+
+    fn set_data_from_iter(&mut self, _iterator: Box<dyn Iterator<Item = (Value, Value)>>) {
+        unimplemented!()
+    }
+}
+
+impl SahaMatch {
+    /// Match "methods" which take no parameters.
+    fn get(&self, args: &SahaFunctionArguments, access: AccessParams, value: Value) -> SahaCallResult {
+        str_params(&[]).validate_args(args, access.access_file_pos)?;
+
+        return Ok(value);
+    }
+
+    /// The Match::group "method". Group 0 is the whole match. Returns none for
+    /// groups which did not participate in the match or do not exist.
+    pub fn group(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let mut params: SahaFunctionParamDefs = SahaFunctionParamDefs::new();
+        let (pname, param) = create_core_param("index", SahaType::Int, 0);
+
+        params.insert(pname.to_string(), param);
+
+        let args = params.validate_args(args, access.access_file_pos)?;
+
+        let group = usize::try_from(args["index"].int.unwrap()).ok()
+            .and_then(|idx| self.groups.get(idx).cloned())
+            .flatten();
+
+        return Ok(str_option(group));
+    }
+
+    /// The Match::named "method". Returns none for named groups which did not
+    /// participate in the match or do not exist.
+    pub fn named(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let args = str_params(&["name"]).validate_args(args, access.access_file_pos)?;
+
+        let group = self.names.get(&args["name"].str.clone().unwrap())
+            .and_then(|idx| self.groups[*idx].clone());
+
+        return Ok(str_option(group));
+    }
+}
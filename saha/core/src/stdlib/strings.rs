@@ -0,0 +1,104 @@
+//! stdlib Strings
+//!
+//! The `std.str` module, with functions for inspecting and transforming `str`
+//! values. Lengths and positions count unicode characters, not bytes.
+
+use saha_lib::prelude::*;
+
+use crate::{
+    stdlib::globals::list::create_list_value,
+    utils::{create_core_function, create_core_param}
+};
+
+/// Register module stdlib parts.
+pub fn get_saha_functions() -> Vec<(String, CoreFunction)> {
+    let value = || create_core_param("value", SahaType::Str, 0);
+    let str_type = || Box::new(SahaType::Str);
+    let list_of_str = SahaType::Name("List".to_string(), vec![Box::new(SahaType::Str)]);
+
+    return vec![
+        create_core_function("length", vec![value()], Box::new(SahaType::Int), length),
+        create_core_function("toUpper", vec![value()], str_type(), to_upper),
+        create_core_function("toLower", vec![value()], str_type(), to_lower),
+        create_core_function("trim", vec![value()], str_type(), trim),
+        create_core_function(
+            "contains",
+            vec![value(), create_core_param("part", SahaType::Str, 1)],
+            Box::new(SahaType::Bool),
+            contains
+        ),
+        create_core_function(
+            "split",
+            vec![value(), create_core_param("separator", SahaType::Str, 1)],
+            Box::new(list_of_str),
+            split
+        ),
+        create_core_function(
+            "replace",
+            vec![
+                value(),
+                create_core_param("from", SahaType::Str, 1),
+                create_core_param("to", SahaType::Str, 2)
+            ],
+            str_type(),
+            replace
+        ),
+    ];
+}
+
+/// Get the `value` argument.
+fn value_arg(args: &SahaFunctionArguments) -> String {
+    return args["value"].str.clone().unwrap();
+}
+
+/// Count the characters of a string.
+fn length(args: SahaFunctionArguments) -> SahaCallResult {
+    return Ok(Value::int(value_arg(&args).chars().count() as isize));
+}
+
+/// Convert a string to uppercase.
+fn to_upper(args: SahaFunctionArguments) -> SahaCallResult {
+    return Ok(Value::str(value_arg(&args).to_uppercase()));
+}
+
+/// Convert a string to lowercase.
+fn to_lower(args: SahaFunctionArguments) -> SahaCallResult {
+    return Ok(Value::str(value_arg(&args).to_lowercase()));
+}
+
+/// Remove leading and trailing whitespace.
+fn trim(args: SahaFunctionArguments) -> SahaCallResult {
+    return Ok(Value::str(value_arg(&args).trim().to_string()));
+}
+
+/// Check if a string contains another string.
+fn contains(args: SahaFunctionArguments) -> SahaCallResult {
+    let part = args["part"].str.clone().unwrap();
+
+    return Ok(Value::bool(value_arg(&args).contains(&part)));
+}
+
+/// Split a string into parts by a separator.
+fn split(args: SahaFunctionArguments) -> SahaCallResult {
+    let separator = args["separator"].str.clone().unwrap();
+
+    if separator.is_empty() {
+        return Err(RuntimeError::new("Cannot split a string with an empty separator", None));
+    }
+
+    let parts: Vec<Value> = value_arg(&args).split(separator.as_str()).map(|p| Value::str(p.to_string())).collect();
+
+    return Ok(create_list_value(parts, Box::new(SahaType::Str)));
+}
+
+/// Replace all occurrences of a string with another string.
+fn replace(args: SahaFunctionArguments) -> SahaCallResult {
+    let from = args["from"].str.clone().unwrap();
+    let to = args["to"].str.clone().unwrap();
+
+    if from.is_empty() {
+        return Err(RuntimeError::new("Cannot replace an empty string", None));
+    }
+
+    return Ok(Value::str(value_arg(&args).replace(&from, &to)));
+}
@@ -0,0 +1,434 @@
+//! stdlib Time
+//!
+//! The `std.time` module, with clocks, sleeping and the `std.time.Duration`
+//! class. Date times are defined in the `datetime` module.
+
+use std::{
+    any::Any,
+    convert::TryFrom,
+    sync::{Arc, OnceLock},
+    thread,
+    time::{Instant, SystemTime, UNIX_EPOCH}
+};
+
+use chrono::TimeDelta;
+
+use noisy_float::prelude::*;
+
+use saha_lib::prelude::*;
+
+use crate::{
+    stdlib::datetime::{DATETIME_CLASS_NAME, utc_now, from_timestamp, parse_datetime},
+    utils::{
+        create_core_function, create_core_param, create_core_param_with_default, get_new_instref, add_instance_to_symbol_table,
+        read_core_instance, is_self_argument
+    }
+};
+
+/// Fully qualified name of the `Duration` class.
+pub const DURATION_CLASS_NAME: &str = "std.time.Duration";
+
+/// Name of a `Duration` constructor parameter, and the function which creates
+/// a duration of that many units.
+type DurationUnit = (&'static str, fn(i64) -> Option<TimeDelta>);
+
+/// Reference point of the monotonic clock.
+static CLOCK_START: OnceLock<Instant> = OnceLock::new();
+
+/// Register module stdlib parts.
+pub fn get_saha_functions() -> Vec<(String, CoreFunction)> {
+    let duration_type = SahaType::Name(DURATION_CLASS_NAME.to_string(), Vec::new());
+    let datetime_type = SahaType::Name(DATETIME_CLASS_NAME.to_string(), Vec::new());
+    let result_of_datetime = SahaType::Name("Result".to_string(), vec![Box::new(datetime_type.clone()), Box::new(SahaType::Str)]);
+
+    return vec![
+        create_core_function("now", Vec::new(), Box::new(duration_type), now),
+        create_core_function("timestamp", Vec::new(), Box::new(SahaType::Int), timestamp),
+        create_core_function("timestampMillis", Vec::new(), Box::new(SahaType::Int), timestamp_millis),
+        create_core_function(
+            "sleep",
+            vec![create_core_param("millis", SahaType::Int, 0)],
+            Box::new(SahaType::Void),
+            sleep
+        ),
+        create_core_function("utcNow", Vec::new(), Box::new(datetime_type.clone()), utc_now),
+        create_core_function(
+            "fromTimestamp",
+            vec![
+                create_core_param("seconds", SahaType::Int, 0),
+                create_core_param_with_default("offsetMinutes", SahaType::Int, Value::int(0), 1)
+            ],
+            Box::new(datetime_type),
+            from_timestamp
+        ),
+        create_core_function(
+            "parseDateTime",
+            vec![create_core_param("text", SahaType::Str, 0)],
+            Box::new(result_of_datetime),
+            parse_datetime
+        ),
+    ];
+}
+
+/// Get the duration of a `Duration` instance value.
+pub fn duration_of(value: &Value) -> Option<TimeDelta> {
+    return read_core_instance(value, |d: &SahaDuration| d.duration);
+}
+
+/// Create a new `Duration` instance, and get the instref value object for it.
+pub fn create_duration_value(duration: TimeDelta) -> Value {
+    let instref = get_new_instref();
+
+    return add_instance_to_symbol_table(instref, Box::new(SahaDuration {
+        instref: instref,
+        duration: duration
+    }));
+}
+
+/// Create a new Duration instance from its parts, which all default to zero.
+pub fn new_instance(
+    instref: InstRef,
+    args: &SahaFunctionArguments,
+    _type_params: &[Box<SahaType>],
+    _additional_data: &SahaFunctionArguments,
+    create_pos: Option<FilePosition>
+) -> Result<Box<dyn SahaObject>, RuntimeError> {
+    let units: [DurationUnit; 5] = [
+        ("days", TimeDelta::try_days),
+        ("hours", TimeDelta::try_hours),
+        ("minutes", TimeDelta::try_minutes),
+        ("seconds", TimeDelta::try_seconds),
+        ("millis", TimeDelta::try_milliseconds)
+    ];
+
+    let mut params: SahaFunctionParamDefs = SahaFunctionParamDefs::new();
+
+    for (idx, (unit, _)) in units.iter().enumerate() {
+        let (pname, param) = create_core_param_with_default(unit, SahaType::Int, Value::int(0), idx);
+
+        params.insert(pname.to_string(), param);
+    }
+
+    let args = params.validate_args(args, &create_pos)?;
+
+    let duration = units.iter().try_fold(TimeDelta::zero(), |total, (unit, to_delta)| {
+        return total.checked_add(&to_delta(args[*unit].int.unwrap() as i64)?);
+    });
+
+    return match duration {
+        Some(duration) => Ok(Box::new(SahaDuration { instref: instref, duration: duration })),
+        None => Err(RuntimeError::new("Duration is too long", create_pos))
+    };
+}
+
+/// Resolve the return type of a `Duration` method.
+pub fn method_type(_type_params: &[Box<SahaType>], method_name: &str) -> Option<SahaType> {
+    return match method_name {
+        "millis" | "compare" => Some(SahaType::Int),
+        "seconds" => Some(SahaType::Float),
+        "isNegative" | "equals" => Some(SahaType::Bool),
+        "abs" | "add" | "sub" | "mul" => Some(SahaType::Name(DURATION_CLASS_NAME.to_string(), Vec::new())),
+        "display" => Some(SahaType::Str),
+        _ => None
+    };
+}
+
+/// Get the time on a monotonic clock, which never goes backwards. Only useful
+/// for measuring elapsed time between two calls.
+fn now(_args: SahaFunctionArguments) -> SahaCallResult {
+    let elapsed = CLOCK_START.get_or_init(Instant::now).elapsed();
+
+    return Ok(create_duration_value(TimeDelta::from_std(elapsed).unwrap_or(TimeDelta::MAX)));
+}
+
+/// Get the wall clock time in seconds since the Unix epoch.
+fn timestamp(_args: SahaFunctionArguments) -> SahaCallResult {
+    let since_epoch = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+
+    return Ok(Value::int(since_epoch.as_secs() as isize));
+}
+
+/// Get the wall clock time in milliseconds since the Unix epoch.
+fn timestamp_millis(_args: SahaFunctionArguments) -> SahaCallResult {
+    let since_epoch = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+
+    return Ok(Value::int(since_epoch.as_millis() as isize));
+}
+
+/// Pause the program for a number of milliseconds.
+fn sleep(args: SahaFunctionArguments) -> SahaCallResult {
+    let millis = args["millis"].int.unwrap();
+
+    if millis < 0 {
+        let err = RuntimeError::new(&format!("Cannot sleep for a negative duration of {} milliseconds", millis), None);
+
+        return Err(err);
+    }
+
+    thread::sleep(std::time::Duration::from_millis(millis as u64));
+
+    return Ok(Value::void());
+}
+
+/// Format a duration in the ISO 8601 duration format, e.g. `PT1M30.5S`.
+fn format_duration(duration: TimeDelta) -> String {
+    let sign = if duration < TimeDelta::zero() { "-" } else { "" };
+    let duration = duration.abs();
+
+    let total_secs = duration.num_seconds();
+    let nanos = duration.subsec_nanos();
+    let (days, hours, minutes, secs) = (total_secs / 86400, total_secs / 3600 % 24, total_secs / 60 % 60, total_secs % 60);
+
+    let mut formatted = format!("{}P", sign);
+
+    if days > 0 {
+        formatted.push_str(&format!("{}D", days));
+    }
+
+    if hours > 0 || minutes > 0 || secs > 0 || nanos > 0 || days == 0 {
+        formatted.push('T');
+    }
+
+    if hours > 0 {
+        formatted.push_str(&format!("{}H", hours));
+    }
+
+    if minutes > 0 {
+        formatted.push_str(&format!("{}M", minutes));
+    }
+
+    if nanos > 0 {
+        let fraction = format!("{:09}", nanos);
+
+        formatted.push_str(&format!("{}.{}S", secs, fraction.trim_end_matches('0')));
+    } else if secs > 0 || (days == 0 && hours == 0 && minutes == 0) {
+        formatted.push_str(&format!("{}S", secs));
+    }
+
+    return formatted;
+}
+
+/// SahaDuration is the core definition of the `Duration` type in Saha, a
+/// signed length of time with nanosecond precision.
+#[derive(Clone)]
+struct SahaDuration {
+    instref: InstRef,
+    duration: TimeDelta
+}
+
+impl SahaObject for SahaDuration {
+    fn get_instance_ref(&self) -> InstRef {
+        return self.instref;
+    }
+
+    fn is_core_defined(&self) -> bool {
+        return true;
+    }
+
+    fn get_class_name(&self) -> String {
+        return "Duration".to_string();
+    }
+
+    fn get_fully_qualified_class_name(&self) -> String {
+        return DURATION_CLASS_NAME.to_string();
+    }
+
+    fn get_implements(&self) -> Vec<String> {
+        return ["Add", "Sub", "Mul", "Equals", "Comparable", "Displayable"].iter().map(|b| b.to_string()).collect();
+    }
+
+    fn get_full_method_name(&mut self, _method_name: &str) -> String {
+        unimplemented!()
+    }
+
+    fn get_method_ref(&mut self, _method_name: &str) -> Result<Arc<Box<dyn SahaCallable>>, RuntimeError> {
+        unimplemented!()
+    }
+
+    fn get_type_params(&self) -> Vec<(char, Box<SahaType>)> {
+        return Vec::new();
+    }
+
+    fn get_named_type(&self) -> Box<SahaType> {
+        return Box::new(SahaType::Name(DURATION_CLASS_NAME.to_string(), Vec::new()));
+    }
+
+    fn call_member(&mut self, access: AccessParams, args: SahaFunctionArguments) -> SahaCallResult {
+        if access.is_static_access {
+            return Err(RuntimeError::new(
+                &format!("No static method `{}` defined for `{}`", access.member_name, self.get_class_name()),
+                access.access_file_pos.clone()
+            ));
+        }
+
+        match access.member_name as &str {
+            "millis" => self.millis(&args, access),
+            "seconds" => self.seconds(&args, access),
+            "isNegative" => self.is_negative(&args, access),
+            "abs" => self.abs(&args, access),
+            "add" => self.add(&args, access),
+            "sub" => self.sub(&args, access),
+            "mul" => self.mul(&args, access),
+            "equals" => self.equals(&args, access),
+            "compare" => self.compare(&args, access),
+            "display" => self.display(&args, access),
+            _ => {
+                return Err(RuntimeError::new(
+                    &format!("No method `{}` defined for `{}`", access.member_name, self.get_class_name()),
+                    access.access_file_pos.clone()
+                ));
+            }
+        }
+    }
+
+    fn access_property(&self, _access: AccessParams) -> SahaCallResult {
+        unimplemented!()
+    }
+
+    fn mutate_property(&mut self, _access: AccessParams, _new_value: Value) -> SahaCallResult {
+        unimplemented!()
+    }
+
+    fn box_clone(&self) -> Box<dyn SahaObject> {
+        return Box::new(self.clone());
+    }
+
+    fn into_iter(&self) -> Box<dyn Iterator<Item = (Value, Value)>> {
+        unimplemented!()
+    }
+
+    fn set_data_from_iter(&mut self, _iterator: Box<dyn Iterator<Item = (Value, Value)>>) {
+        unimplemented!()
+    }
+
+    fn as_any(&self) -> Option<&dyn Any> {
+        return Some(self);
+    }
+}
+
+impl SahaDuration {
+    /// Get function parameter definitions for methods which take another
+    /// duration as `other`.
+    fn other_params(&self) -> SahaFunctionParamDefs {
+        let mut params: SahaFunctionParamDefs = SahaFunctionParamDefs::new();
+        let (pname, param) = create_core_param("other", SahaType::Name(DURATION_CLASS_NAME.to_string(), Vec::new()), 0);
+
+        params.insert(pname.to_string(), param);
+
+        return params;
+    }
+
+    /// Validate the `other` duration argument and get its duration.
+    fn other_duration(&self, args: &SahaFunctionArguments, access: &AccessParams) -> Result<TimeDelta, RuntimeError> {
+        if is_self_argument(args, self.instref) {
+            return Ok(self.duration);
+        }
+
+        let args = self.other_params().validate_args(args, access.access_file_pos)?;
+
+        return Ok(duration_of(&args["other"]).unwrap());
+    }
+
+    /// Get a duration value for a calculation result, which errors if the
+    /// result overflowed.
+    fn duration_result(&self, duration: Option<TimeDelta>, access: &AccessParams) -> SahaCallResult {
+        return match duration {
+            Some(duration) => Ok(create_duration_value(duration)),
+            None => Err(RuntimeError::new("Duration is too long", access.access_file_pos.clone()))
+        };
+    }
+
+    /// The Duration::millis "method". Returns the whole milliseconds in the
+    /// duration.
+    pub fn millis(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let params: SahaFunctionParamDefs = SahaFunctionParamDefs::new(); // no params
+
+        params.validate_args(args, access.access_file_pos)?;
+
+        return Ok(Value::int(self.duration.num_milliseconds() as isize));
+    }
+
+    /// The Duration::seconds "method". Returns the duration in seconds,
+    /// including fractions of a second.
+    pub fn seconds(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let params: SahaFunctionParamDefs = SahaFunctionParamDefs::new(); // no params
+
+        params.validate_args(args, access.access_file_pos)?;
+
+        let seconds = self.duration.num_seconds() as f64 + self.duration.subsec_nanos() as f64 / 1_000_000_000.0;
+
+        return Ok(Value::float(r64(seconds)));
+    }
+
+    /// The Duration::isNegative "method".
+    pub fn is_negative(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let params: SahaFunctionParamDefs = SahaFunctionParamDefs::new(); // no params
+
+        params.validate_args(args, access.access_file_pos)?;
+
+        return Ok(Value::bool(self.duration < TimeDelta::zero()));
+    }
+
+    /// The Duration::abs "method".
+    pub fn abs(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let params: SahaFunctionParamDefs = SahaFunctionParamDefs::new(); // no params
+
+        params.validate_args(args, access.access_file_pos)?;
+
+        return Ok(create_duration_value(self.duration.abs()));
+    }
+
+    /// The Duration::add "method", also used for the `+` operator.
+    pub fn add(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let other = self.other_duration(args, &access)?;
+
+        return self.duration_result(self.duration.checked_add(&other), &access);
+    }
+
+    /// The Duration::sub "method", also used for the `-` operator.
+    pub fn sub(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let other = self.other_duration(args, &access)?;
+
+        return self.duration_result(self.duration.checked_sub(&other), &access);
+    }
+
+    /// The Duration::mul "method", also used for the `*` operator. Multiplies
+    /// the duration with an int.
+    pub fn mul(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let mut params: SahaFunctionParamDefs = SahaFunctionParamDefs::new();
+        let (pname, param) = create_core_param("other", SahaType::Int, 0);
+
+        params.insert(pname.to_string(), param);
+
+        let args = params.validate_args(args, access.access_file_pos)?;
+        let factor = args["other"].int.unwrap();
+
+        let product = i32::try_from(factor).ok().and_then(|f| self.duration.checked_mul(f));
+
+        return self.duration_result(product, &access);
+    }
+
+    /// The Duration::equals "method", also used for the `==` operator.
+    pub fn equals(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let other = self.other_duration(args, &access)?;
+
+        return Ok(Value::bool(self.duration == other));
+    }
+
+    /// The Duration::compare "method", also used for comparison operators.
+    pub fn compare(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let other = self.other_duration(args, &access)?;
+
+        return Ok(Value::int(self.duration.cmp(&other) as isize));
+    }
+
+    /// The Duration::display "method". Durations are displayed in the ISO 8601
+    /// duration format.
+    pub fn display(&self, args: &SahaFunctionArguments, access: AccessParams) -> SahaCallResult {
+        let params: SahaFunctionParamDefs = SahaFunctionParamDefs::new(); // no params
+
+        params.validate_args(args, access.access_file_pos)?;
+
+        return Ok(Value::str(format_duration(self.duration)));
+    }
+}
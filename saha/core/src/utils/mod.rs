@@ -6,6 +6,7 @@ use std::{
 };
 
 use saha_lib::prelude::*;
+use saha_lib::types::objects::BehaviorDefinition;
 
 /// Get a new InstRef to be used on a new instance.
 pub fn get_new_instref() -> InstRef {
@@ -24,6 +25,27 @@ pub fn add_instance_to_symbol_table(instref: InstRef, inst: Box<dyn SahaObject>)
     return Value::obj(instref);
 }
 
+/// Read the data of a core class instance value, if the value is an instance
+/// of the core class `T`.
+pub fn read_core_instance<T: 'static, R>(value: &Value, read: impl FnOnce(&T) -> R) -> Option<R> {
+    if *value.kind != SahaType::Obj {
+        return None;
+    }
+
+    let inst_lockable = saha_lib::SAHA_SYMBOL_TABLE.lock().unwrap().instances.get(&value.obj?).cloned()?;
+    let inst = inst_lockable.lock().unwrap();
+
+    return inst.as_any()?.downcast_ref::<T>().map(read);
+}
+
+/// Check if the only argument of a method call is the called instance itself,
+/// e.g. in `duration + duration`. The called instance is locked while its
+/// methods run, so such an argument cannot be validated or read through the
+/// symbol table.
+pub fn is_self_argument(args: &SahaFunctionArguments, instref: InstRef) -> bool {
+    return args.len() == 1 && args.values().all(|v| *v.kind == SahaType::Obj && v.obj == Some(instref));
+}
+
 /// Create a new core function to be inserted into the global symbol table as a
 /// SahaCallable.
 pub fn create_core_function(
@@ -32,7 +54,7 @@ pub fn create_core_function(
     return_type: Box<SahaType>,
     rust_fn: fn(args: SahaFunctionArguments) -> SahaCallResult
 ) -> (String, CoreFunction) {
-    let mut fn_params: SahaFunctionParamDefs = HashMap::new();
+    let mut fn_params: SahaFunctionParamDefs = SahaFunctionParamDefs::new();
 
     for (pname, p) in params {
         fn_params.insert(pname.to_owned(), p);
@@ -48,4 +70,49 @@ pub fn create_core_function(
     };
 
     return (name.to_owned(), corefn);
-}
\ No newline at end of file
+}
+
+/// Create a new core behavior with a single method, to be inserted into the
+/// global symbol table.
+pub fn create_core_behavior(
+    name: &str,
+    method_name: &str,
+    params: Vec<(&str, FunctionParameter)>,
+    return_type: Box<SahaType>
+) -> BehaviorDefinition {
+    let mut method_params: SahaFunctionParamDefs = SahaFunctionParamDefs::new();
+
+    for (pname, p) in params {
+        method_params.insert(pname.to_owned(), p);
+    }
+
+    let mut methods = HashMap::new();
+
+    methods.insert(method_name.to_owned(), (method_params, return_type));
+
+    return BehaviorDefinition {
+        name: name.to_owned(),
+        fqname: name.to_owned(),
+        visibility: MemberVisibility::Public,
+        methods: methods,
+        extends: Vec::new()
+    };
+}
+
+/// Create a required core function parameter, to be used with
+/// `create_core_function`.
+pub fn create_core_param(name: &str, param_type: SahaType, position: usize) -> (&str, FunctionParameter) {
+    return create_core_param_with_default(name, param_type, Value::void(), position);
+}
+
+/// Create an optional core function parameter, which takes the default value
+/// when omitted from calls.
+pub fn create_core_param_with_default(name: &str, param_type: SahaType, default: Value, position: usize) -> (&str, FunctionParameter) {
+    return (name, FunctionParameter {
+        name: name.to_owned(),
+        param_type: Box::new(param_type),
+        default: default,
+        position: position,
+        is_variadic: false
+    });
+}
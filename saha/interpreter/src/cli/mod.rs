@@ -9,12 +9,25 @@ use structopt::StructOpt;
 #[derive(StructOpt, Debug)]
 #[structopt(name = "saha")]
 pub struct InterpreterArgs {
-    /// Saha entrypoint file, containing a `main()` function
+    /// Saha entrypoint file, containing a `main()` function. Use `-` to read
+    /// the source code from STDIN
     #[structopt(name = "FILE", default_value = "", parse(from_os_str))]
     pub entrypoint: PathBuf,
 
+    /// Read the source code from STDIN instead of a file
+    #[structopt(long = "stdin-source")]
+    pub stdin_source: bool,
+
     #[structopt(short = "V", long = "version")]
     pub version: bool,
+
+    /// Run the functions marked with `@test` instead of `main()`
+    #[structopt(short = "t", long = "test")]
+    pub test: bool,
+
+    /// Arguments passed to the script, given after `--`
+    #[structopt(name = "ARGS", raw(last = "true"))]
+    pub script_args: Vec<String>,
 }
 
 /// Get command line arguments given to the interpreter.
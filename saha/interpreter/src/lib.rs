@@ -26,12 +26,15 @@ use std::{
 use saha_lib::{
     source::files::FilePosition,
     errors::{Error, ParseError, RuntimeError},
-    types::functions::{SahaCallable, UserFunction}
+    types::{
+        attributes::{has_attribute, TEST_ATTRIBUTE},
+        functions::{SahaCallable, UserFunction}
+    }
 };
 
 use saha_tokenizer::{tokenize_file, tokenize_raw_source_code};
 use saha_parser::parse_tokens;
-use saha_core::register_core;
+use saha_core::{register_core, shutdown_core};
 
 use crate::errors::{StartupError, StartupResult};
 
@@ -79,10 +82,15 @@ fn parse_saha_source(args: &cli::InterpreterArgs) -> Result<(), ParseError> {
     return Ok(());
 }
 
-/// Load Saha core, meaning stdlib, extensions, and such.
-fn load_saha_core() -> Result<(), StartupError> {
+/// Load Saha core, meaning stdlib, extensions, and such. Script arguments are
+/// stored for the stdlib to access.
+fn load_saha_core(args: &cli::InterpreterArgs) -> Result<(), StartupError> {
     register_core();
 
+    let mut st = saha_lib::SAHA_SYMBOL_TABLE.lock().unwrap();
+
+    st.script_args = args.script_args.clone();
+
     return Ok(());
 }
 
@@ -105,6 +113,88 @@ fn run_saha_main() -> Result<i32, RuntimeError> {
     return Ok(return_code as i32);
 }
 
+/// Run all functions marked with the `@test` attribute, in name order, and
+/// report the results. Returns a non-zero exit code if any of the tests fail.
+fn run_saha_tests() -> i32 {
+    let mut tests: Vec<UserFunction>;
+
+    {
+        let st = saha_lib::SAHA_SYMBOL_TABLE.lock().unwrap();
+
+        tests = st.functions.values()
+            .filter(|f| has_attribute(&f.get_attributes(), TEST_ATTRIBUTE))
+            .map(|f| (**f).as_userfunction().clone())
+            .collect();
+    }
+
+    tests.sort_by(|a, b| a.source_name.cmp(&b.source_name));
+
+    let mut failed = 0;
+
+    for test in &tests {
+        let result = test.call(HashMap::new(), None, Vec::new(), Some(FilePosition::unknown()));
+
+        match result {
+            Ok(_) => println!("test {} ... ok", test.source_name),
+            Err(ref err) if err.get_exit_code().is_some() => return err.get_exit_code().unwrap(),
+            Err(err) => {
+                failed += 1;
+                println!("test {} ... FAILED\n{}", test.source_name, err.format());
+            }
+        };
+    }
+
+    println!("\n{} passed, {} failed", tests.len() - failed, failed);
+
+    if failed > 0 {
+        return 1;
+    }
+
+    return 0;
+}
+
+/// Run either the `main()` or the `@test` functions of the parsed source code,
+/// depending on the interpreter arguments. Core resources such as open files
+/// are released on every exit path, including `std.process.exit()`.
+fn run_saha_entrypoint(args: &cli::InterpreterArgs) -> i32 {
+    let exit_code = if args.test {
+        run_saha_tests()
+    } else {
+        run_saha_program()
+    };
+
+    let shutdown_errors = shutdown_core();
+
+    for err in &shutdown_errors {
+        eprintln!("{}", err.format());
+    }
+
+    if !shutdown_errors.is_empty() && exit_code == 0 {
+        return 1;
+    }
+
+    return exit_code;
+}
+
+/// Run the `main()` of the parsed source code, and get the exit code.
+fn run_saha_program() -> i32 {
+    let run_result = run_saha_main();
+
+    if run_result.is_err() {
+        let err = run_result.err().unwrap();
+
+        // `std.process.exit()` unwinds the program with an error
+        if let Some(code) = err.get_exit_code() {
+            return code;
+        }
+
+        eprintln!("{}", err.format());
+        return 1;
+    }
+
+    return run_result.ok().unwrap();
+}
+
 /// Attempt to read Saha source code piped in through the STDIN.
 fn try_to_read_source_from_stdin() -> Option<String> {
     let mut buffer = String::new();
@@ -122,7 +212,7 @@ fn try_to_read_source_from_stdin() -> Option<String> {
 }
 
 /// Run the interpreter for simple scripts piped in through STDIN.
-fn run_stdin_interpreter(source: String) -> i32 {
+fn run_stdin_interpreter(args: &cli::InterpreterArgs, source: String) -> i32 {
     let tokenized_source = tokenize_raw_source_code(source);
 
     if tokenized_source.is_err() {
@@ -142,35 +232,34 @@ fn run_stdin_interpreter(source: String) -> i32 {
 
     // At this point we should have core and extensions loaded, and we also have read, tokenized,
     // and parsed our Saha source code. The symbol table is ready and now we just need to call the
-    // application main().
-    let run_result = run_saha_main();
-
-    if run_result.is_err() {
-        eprintln!("{}", run_result.err().unwrap().format());
-        return 1;
-    }
-
-    return run_result.ok().unwrap();
+    // application main(), or the tests.
+    return run_saha_entrypoint(args);
 }
 
 /// Run the interpreter.
 fn run_interpreter(args: &cli::InterpreterArgs) -> i32 {
-    let core_loaded = load_saha_core();
+    let core_loaded = load_saha_core(args);
 
     if core_loaded.is_err() {
         eprintln!("{}", core_loaded.err().unwrap().format());
         return 1;
     }
 
-    let stdin_source = try_to_read_source_from_stdin();
+    let entrypoint = args.entrypoint.to_str().unwrap_or("");
 
-    // Got input via STDIN, work with that and disregard the rest.
-    if stdin_source.is_some() {
-        return run_stdin_interpreter(stdin_source.unwrap());
+    if args.stdin_source && !["", "-"].contains(&entrypoint) {
+        eprintln!("{}", StartupError::new("Cannot read source code from both STDIN and a file", None).format());
+        return 1;
     }
 
-    if args.entrypoint.to_str().unwrap() == "" {
-        eprintln!("{}", StartupError::new("Please provide a Saha source file", None).format());
+    // Source code is read from STDIN when asked to, or when no source file is
+    // given. Otherwise STDIN is left for the program to read as input.
+    if args.stdin_source || ["", "-"].contains(&entrypoint) {
+        if let Some(source) = try_to_read_source_from_stdin() {
+            return run_stdin_interpreter(args, source);
+        }
+
+        eprintln!("{}", StartupError::new("Please provide a Saha source file, or source code through STDIN", None).format());
         return 1;
     }
 
@@ -190,15 +279,8 @@ fn run_interpreter(args: &cli::InterpreterArgs) -> i32 {
 
     // At this point we should have core and extensions loaded, and we also have read, tokenized,
     // and parsed our Saha source code. The symbol table is ready and now we just need to call the
-    // application main().
-    let run_result = run_saha_main();
-
-    if run_result.is_err() {
-        eprintln!("{}", run_result.err().unwrap().format());
-        return 1;
-    }
-
-    return run_result.ok().unwrap();
+    // application main(), or the tests.
+    return run_saha_entrypoint(args);
 }
 
 /// Get the rustc version on which this thing is built with. This information
@@ -39,12 +39,22 @@ pub struct Statement {
 /// Various kinds of statements.
 #[derive(Debug, Clone, PartialEq)]
 pub enum StatementKind {
-    /// Variable name, variable type, value to assign
+    /// Variable name, variable type, value to assign. The type is inferred
+    /// from the assigned value when it is not declared.
     ///
     /// ```saha
     /// var my_var'str = "hello";
+    /// var other_var = "hello";
     /// ```
-    VarDeclaration(Identifier, Box<SahaType>, Option<Box<Expression>>),
+    VarDeclaration(Identifier, Option<Box<SahaType>>, Option<Box<Expression>>),
+
+    /// Tuple destructuring variable declaration. Variable names, and the tuple
+    /// value to destructure. Variable types are taken from the tuple elements.
+    ///
+    /// ```saha
+    /// var (count, name) = get_pair();
+    /// ```
+    VarDestructuring(Vec<Identifier>, Box<Expression>),
 
     /// A one-off expression statement. Example:
     ///
@@ -77,14 +87,19 @@ pub enum StatementKind {
     Loop(Box<Block>),
 
     /// For block, first two are `k` and `v` of loop, followed with the iterable
-    /// thing expression, and last is the block which is looped over.
+    /// thing expression, and last is the block which is looped over. `v` can
+    /// destructure tuple values.
     ///
     /// ```saha
-    /// for (k, v) in my_list {
+    /// for (k, v in my_list) {
+    ///     //
+    /// }
+    ///
+    /// for (k, (a, b) in my_tuple_list) {
     ///     //
     /// }
     /// ```
-    For(Identifier, Identifier, Box<Expression>, Box<Block>),
+    For(Identifier, Binding, Box<Expression>, Box<Block>),
 
     /// Return statement.
     ///
@@ -108,6 +123,17 @@ pub struct Identifier {
     pub type_params: Vec<Box<SahaType>>
 }
 
+/// Name bindings for values, either a single name or names for destructured
+/// tuple elements.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Binding {
+    /// `name`
+    Name(Identifier),
+
+    /// `(name, name, ...)`
+    Tuple(Vec<Identifier>),
+}
+
 /// Expressions.
 #[derive(Debug, Clone, PartialEq)]
 pub struct Expression {
@@ -148,6 +174,20 @@ pub enum ExpressionKind {
     /// ```
     ListDeclaration(Vec<Box<Expression>>),
 
+    /// Tuple declaration.
+    ///
+    /// ```saha
+    /// (value, value, ...)
+    /// ```
+    TupleDeclaration(Vec<Box<Expression>>),
+
+    /// Tuple element access. The tuple expression and the element index.
+    ///
+    /// ```saha
+    /// my_tuple.0
+    /// ```
+    TupleAccess(Box<Expression>, usize),
+
     /// Dictionary declarations.
     ///
     /// ```saha
@@ -192,6 +232,16 @@ pub enum ExpressionKind {
     /// inserting further object access expressions into the first field.
     ObjectAccess(Box<Expression>, AccessKind, Box<Expression>),
 
+    /// Safe navigation through an `Option`. First is the option being
+    /// accessed, then the name the unwrapped value is bound to, and lastly the
+    /// access expression which uses the bound name. Evaluates to none if the
+    /// option is none.
+    ///
+    /// ```saha
+    /// maybe_user?->name
+    /// ```
+    SafeAccess(Box<Expression>, Identifier, Box<Expression>),
+
     /// Newup a class. First is the class name, second is the constructor args,
     /// which are alike function call args. Lastly there are TypeParams for
     /// generics use.
@@ -224,6 +274,7 @@ impl BinOp {
             Token::OpGte(..) => BinOpKind::Gte,
             Token::OpEq(..) => BinOpKind::Eq,
             Token::OpNeq(..) => BinOpKind::Neq,
+            Token::OpCoalesce(..) => BinOpKind::Coalesce,
             _ => return Err(())
         };
 
@@ -256,6 +307,9 @@ pub enum BinOpKind {
     Neq,
     And,
     Or,
+
+    /// `option ?? fallback`
+    Coalesce,
 }
 
 /// Unary operation.
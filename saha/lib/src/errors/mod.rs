@@ -81,6 +81,7 @@ pub struct RuntimeError {
     message: String,
     file_position: Option<FilePosition>,
     saha_error_type: String,
+    exit_code: Option<i32>,
 }
 
 impl Error for RuntimeError {
@@ -89,6 +90,7 @@ impl Error for RuntimeError {
             message: message.to_owned(),
             file_position: pos.clone(),
             saha_error_type: "RuntimeError".to_string(),
+            exit_code: None,
         };
     }
 
@@ -104,3 +106,21 @@ impl Error for RuntimeError {
         return self.file_position.to_owned();
     }
 }
+
+impl RuntimeError {
+    /// Create an error which exits the program with an exit code. Exiting is
+    /// done by propagating this error up the call stack, so the program unwinds
+    /// cleanly before exiting.
+    pub fn exit(code: i32) -> RuntimeError {
+        let mut err = RuntimeError::new(&format!("Exit with code {}", code), None);
+
+        err.exit_code = Some(code);
+
+        return err;
+    }
+
+    /// Get the exit code if this error exits the program.
+    pub fn get_exit_code(&self) -> Option<i32> {
+        return self.exit_code;
+    }
+}
@@ -14,7 +14,12 @@ use std::{
 use crate::prelude::*;
 use crate::{
     ast::*,
-    types::objects::{ClassDefinition, CoreConstructorFn}
+    types::{
+        objects::{ClassDefinition, CoreConstructorFn, MemberVisibility, Property, INIT_METHOD_NAME, ITERABLE_IMPLEMENTS_NAME, SELF_TYPE_NAME},
+        functions::positional_arg_name,
+        behaviors::{display_value, values_equal},
+        attributes::{Attribute, DEPRECATED_ATTRIBUTE, get_attribute}
+    }
 };
 
 type AstResult = Result<Value, RuntimeError>;
@@ -26,12 +31,14 @@ type ResolvedIdentPath = (Option<Value>, Option<SahaType>, Option<AccessKind>, I
 pub struct AstVisitor<'a> {
     ast: &'a Ast,
     self_ref: Option<InstRef>,
+    self_class: Option<String>,
     local_refs: HashMap<String, (Box<SahaType>, Value)>
 }
 
 impl<'a> AstVisitor<'a> {
-    /// Get a new AstVisitor instance for an AST.
-    pub fn new(ast: &'a Ast, visit_args: SahaFunctionArguments) -> AstVisitor<'a> {
+    /// Get a new AstVisitor instance for an AST. Method ASTs are visited with
+    /// the name of the class they belong to.
+    pub fn new(ast: &'a Ast, visit_args: SahaFunctionArguments, self_class: Option<String>) -> AstVisitor<'a> {
         let mut inject_local_refs: HashMap<String, (Box<SahaType>, Value)> = HashMap::new();
         let mut self_ref: Option<InstRef> = None;
 
@@ -47,6 +54,7 @@ impl<'a> AstVisitor<'a> {
         return AstVisitor {
             ast: ast,
             self_ref: self_ref,
+            self_class: self_class,
             local_refs: inject_local_refs
         };
     }
@@ -104,6 +112,14 @@ impl<'a> AstVisitor<'a> {
         return inst.get_named_type();
     }
 
+    /// Get the type of a value, with objects typed by their class names.
+    fn get_value_type(&self, value: &Value) -> Box<SahaType> {
+        return match *value.kind {
+            SahaType::Obj => self.get_object_named_type(value),
+            _ => value.kind.clone()
+        };
+    }
+
     /// Does a value match a type?
     fn is_matching_type(&self, expected: &SahaType, value: &Value) -> bool {
         let is_match = match (expected, *value.kind.clone()) {
@@ -112,6 +128,11 @@ impl<'a> AstVisitor<'a> {
             (SahaType::Int, SahaType::Int) => true,
             (SahaType::Float, SahaType::Float) => true,
             (SahaType::Name(ref exp_name, ref exp_tp), SahaType::Name(ref act_name, ref act_tp)) => exp_name == act_name && exp_tp == act_tp,
+            (SahaType::Tuple(exp_items), SahaType::Tuple(..)) => {
+                let items = value.tuple.clone().unwrap();
+
+                exp_items.len() == items.len() && exp_items.iter().zip(items.iter()).all(|(t, v)| self.is_matching_type(t, v))
+            },
             (SahaType::Name(exp_name, exp_tp), SahaType::Obj) => {
                 let impl_list = self.get_object_implements(&value);
                 let inst_typedname: Box<SahaType> = self.get_object_named_type(&value);
@@ -161,10 +182,10 @@ impl<'a> AstVisitor<'a> {
         if !self.is_matching_type(old_type, &value) {
             let err = RuntimeError::new(
                 &format!(
-                    "Cannot assign mismatching type to variable `{}`, expected `{:?}` but received `{:?}`",
+                    "Cannot assign mismatching type to variable `{}`, expected `{}` but received `{}`",
                     name,
-                    old_type,
-                    value.kind
+                    old_type.to_readable_string(),
+                    value.kind.to_readable_string()
                 ),
                 Some(refpos.clone())
             );
@@ -198,6 +219,13 @@ impl<'a> AstVisitor<'a> {
         return Ok(value.clone());
     }
 
+    /// Get a constant value.
+    fn get_constant(&self, name: &str) -> Option<Value> {
+        let st = crate::SAHA_SYMBOL_TABLE.lock().unwrap();
+
+        return st.constants.get(name).cloned();
+    }
+
     /// Remove a local named ref.
     fn remove_local_ref(&mut self, name: &str) -> AstResult {
         self.local_refs.remove(name);
@@ -267,12 +295,13 @@ impl<'a> AstVisitor<'a> {
         let (res, bail) = match &statement.kind {
             StatementKind::Return(expr) => (self.visit_expression(&expr)?, true),
             StatementKind::VarDeclaration(ident, vartype, vardefault) => (self.visit_variable_declaration_statement(ident, vartype, vardefault)?, false),
+            StatementKind::VarDestructuring(idents, value_expr) => (self.visit_variable_destructuring_statement(idents, value_expr)?, false),
             StatementKind::Expression(expr) => (self.visit_expression(&expr)?, false),
             StatementKind::If(if_cond, if_block, elifs, else_block) => self.visit_if_statement(if_cond, if_block, elifs, else_block)?,
             StatementKind::Loop(loop_block) => self.visit_loop_statement(loop_block)?,
             StatementKind::Break => (Value::void(), true),
             StatementKind::Continue => (Value::void(), false),
-            StatementKind::For(k_name, v_name, iter_expr, block) => self.visit_for_statement(k_name, v_name, iter_expr, block)?
+            StatementKind::For(k_name, v_binding, iter_expr, block) => self.visit_for_statement(k_name, v_binding, iter_expr, block)?
             //_ => unimplemented!("{:?}", statement.kind)
         };
 
@@ -280,7 +309,7 @@ impl<'a> AstVisitor<'a> {
     }
 
     /// Visit a variable declaration.
-    fn visit_variable_declaration_statement(&mut self, var_ident: &Identifier, var_type: &SahaType, var_default: &Option<Box<Expression>>) -> AstResult {
+    fn visit_variable_declaration_statement(&mut self, var_ident: &Identifier, var_type: &Option<Box<SahaType>>, var_default: &Option<Box<Expression>>) -> AstResult {
         let refname = var_ident.identifier.to_owned();
         let refpos = &var_ident.file_position;
 
@@ -292,24 +321,93 @@ impl<'a> AstVisitor<'a> {
             let def_expr = var_default.clone().unwrap();
             default_value = self.visit_expression(&def_expr)?;
 
-            if !self.is_matching_type(var_type, &default_value) {
+            if let Some(ref vtype) = var_type {
+                if !self.is_matching_type(vtype, &default_value) {
+                    let err = RuntimeError::new(
+                        &format!(
+                            "Mismatching type assigned to variable `{}`, expected `{}` but received `{}`",
+                            refname,
+                            vtype.to_readable_string(),
+                            default_value.kind.to_readable_string()
+                        ),
+                        Some(def_expr.file_position)
+                    );
+
+                    return Err(err);
+                }
+            }
+        }
+
+        // undeclared types are inferred from the initial value, and stay fixed
+        // for any later assignments
+        let ref_type = match var_type {
+            Some(vtype) => vtype.to_owned(),
+            None => {
+                if *default_value.kind == SahaType::Void {
+                    let err = RuntimeError::new(
+                        &format!("Cannot infer the type of variable `{}` from a value without a type", refname),
+                        Some(refpos.clone())
+                    );
+
+                    return Err(err);
+                }
+
+                self.get_value_type(&default_value)
+            }
+        };
+
+        self.create_local_ref(refname, (ref_type, default_value), refpos)?;
+
+        return Ok(Value::void());
+    }
+
+    /// Visit a tuple destructuring variable declaration.
+    fn visit_variable_destructuring_statement(&mut self, var_idents: &[Identifier], value_expr: &Expression) -> AstResult {
+        let value = self.visit_expression(value_expr)?;
+
+        for (ident, item) in self.destructure_tuple(var_idents, &value, &value_expr.file_position)? {
+            let item_type = self.get_value_type(&item);
+
+            self.create_local_ref(ident.identifier, (item_type, item), &ident.file_position)?;
+        }
+
+        return Ok(Value::void());
+    }
+
+    /// Pair destructuring names with tuple elements. Elements bound to `_` are
+    /// discarded.
+    fn destructure_tuple(&self, idents: &[Identifier], value: &Value, value_pos: &FilePosition) -> Result<Vec<(Identifier, Value)>, RuntimeError> {
+        let items: Vec<Value> = match *value.kind {
+            SahaType::Tuple(..) => value.tuple.clone().unwrap(),
+            _ => {
                 let err = RuntimeError::new(
-                    &format!(
-                        "Mismatching type assigned to variable `{}`, expected `{:?}` but received `{:?}`",
-                        refname,
-                        var_type.to_readable_string(),
-                        default_value.kind.to_readable_string()
-                    ),
-                    Some(def_expr.file_position)
+                    &format!("Cannot destructure a non-tuple value of type `{}`", value.kind.to_readable_string()),
+                    Some(value_pos.clone())
                 );
 
                 return Err(err);
             }
+        };
+
+        if items.len() != idents.len() {
+            let err = RuntimeError::new(
+                &format!(
+                    "Cannot destructure a tuple of {} elements into {} variables",
+                    items.len(),
+                    idents.len()
+                ),
+                Some(value_pos.clone())
+            );
+
+            return Err(err);
         }
 
-        self.create_local_ref(refname, (Box::new(var_type.to_owned()), default_value), refpos)?;
+        let pairs = idents.iter().cloned()
+            .zip(items)
+            .filter(|(ident, _)| ident.identifier != "_")
+            .collect();
 
-        return Ok(Value::void());
+        return Ok(pairs);
     }
 
     /// Visit an if-elseif-else statement. Returns a bailable result, meaning
@@ -431,14 +529,16 @@ impl<'a> AstVisitor<'a> {
     }
 
     /// Visit a for loop.
-    fn visit_for_statement(&mut self, k_name: &Identifier, v_name: &Identifier, iterable_expr: &Expression, for_block: &Block) -> BailableAstResult {
+    fn visit_for_statement(&mut self, k_name: &Identifier, v_binding: &Binding, iterable_expr: &Expression, for_block: &Block) -> BailableAstResult {
         let iterable = self.visit_expression(iterable_expr)?;
 
         match *iterable.kind {
             SahaType::Obj => {
                 let iterable_impl = self.get_object_implements(&iterable);
 
-                if !iterable_impl.contains(&"List".to_string()) && !iterable_impl.contains(&"Dict".to_string()) {
+                let is_iterable = ["List", "Dict", "Set", ITERABLE_IMPLEMENTS_NAME].iter().any(|c| iterable_impl.contains(&c.to_string()));
+
+                if !is_iterable {
                     let err = RuntimeError::new("Cannot loop over a non-iterable value", Some(iterable_expr.file_position.clone()));
 
                     return Err(err);
@@ -451,11 +551,11 @@ impl<'a> AstVisitor<'a> {
             }
         };
 
-        return self.visit_for_iterable_statement(k_name, v_name, &iterable, for_block);
+        return self.visit_for_iterable_statement(k_name, v_binding, &iterable, for_block);
     }
 
     /// Visit a for loop over a List.
-    fn visit_for_iterable_statement(&mut self, k_name: &Identifier, v_name: &Identifier, iterable: &Value, for_block: &Block) -> BailableAstResult {
+    fn visit_for_iterable_statement(&mut self, k_name: &Identifier, v_binding: &Binding, iterable: &Value, for_block: &Block) -> BailableAstResult {
         let inst_lockable = self.get_instance_lockable_ref(&iterable.obj.unwrap(), for_block.file_position.clone())?;
         let inst = inst_lockable.lock().unwrap();
         let mut inst_iterable = inst.into_iter();
@@ -471,7 +571,18 @@ impl<'a> AstVisitor<'a> {
             let mut inject = HashMap::new();
 
             inject.insert(k_name.identifier.clone(), (idx, k_name.file_position.clone()));
-            inject.insert(v_name.identifier.clone(), (val, v_name.file_position.clone()));
+            match v_binding {
+                Binding::Name(v_name) => {
+                    inject.insert(v_name.identifier.clone(), (val, v_name.file_position.clone()));
+                },
+                Binding::Tuple(v_names) => {
+                    let v_pos = v_names.first().unwrap().file_position.clone();
+
+                    for (v_name, item) in self.destructure_tuple(v_names, &val, &v_pos)? {
+                        inject.insert(v_name.identifier, (item, v_name.file_position));
+                    }
+                }
+            };
 
             return self.visit_block(for_block, inject);
         });
@@ -492,17 +603,25 @@ impl<'a> AstVisitor<'a> {
             ExpressionKind::IdentPath(..) => self.resolve_ident_path_to_value(&expression),
             ExpressionKind::NewInstance(ident, args, typeparams) => self.visit_instance_newup(ident, args, typeparams),
             ExpressionKind::ObjectAccess(lhs, accesskind, rhs) => self.visit_generic_object_access(lhs, accesskind, rhs),
+            ExpressionKind::SafeAccess(option_expr, binding, access_expr) => self.visit_safe_access(option_expr, binding, access_expr),
             ExpressionKind::ListDeclaration(item_exprs) => self.visit_list_shorthand_expression(item_exprs, expr_position),
             ExpressionKind::DictDeclaration(item_exprs) => self.visit_dict_shorthand_expression(item_exprs, expr_position),
+            ExpressionKind::TupleDeclaration(item_exprs) => self.visit_tuple_expression(item_exprs),
+            ExpressionKind::TupleAccess(tuple_expr, index) => self.visit_tuple_access(tuple_expr, *index, expr_position),
             _ => unimplemented!("{:?}", expression.kind)
         }
     }
 
     /// Visit a name assignment node.
     fn visit_assignment(&mut self, ident_path: &Expression, value_expr: &Expression) -> AstResult {
-        let (owner_inst, _, access_kind, property) = self.resolve_ident_path(ident_path)?;
+        let (owner_inst, owner_class_name, access_kind, property) = self.resolve_ident_path(ident_path)?;
         let value = self.visit_expression(value_expr)?;
 
+        if let Some(SahaType::Name(class_name, _)) = owner_class_name {
+            // static property assign with a class name
+            return self.mutate_static_property(&class_name, &property, value);
+        }
+
         if owner_inst.is_none() {
             // local ref assign
             return self.set_local_ref(property.identifier, value, &property.file_position, false);
@@ -510,13 +629,26 @@ impl<'a> AstVisitor<'a> {
             // property assign
             let obj = owner_inst.unwrap();
 
-            let inst_lockable = self.get_instance_lockable_ref(&obj.obj.unwrap(), property.file_position.clone())?;
-
             let is_static_access = match access_kind.unwrap() {
                 AccessKind::Static => true,
                 _ => false
             };
 
+            if let Some(class_name) = self.get_static_property_owner(&obj, &property)? {
+                if !is_static_access {
+                    let err = RuntimeError::new(
+                        &format!("Attempted to mutate static property `{}` unstatically on class `{}`", property.identifier, class_name),
+                        Some(property.file_position)
+                    );
+
+                    return Err(err);
+                }
+
+                return self.mutate_static_property(&class_name, &property, value);
+            }
+
+            let inst_lockable = self.get_instance_lockable_ref(&obj.obj.unwrap(), property.file_position.clone())?;
+
             let access = AccessParams {
                 is_static_access: is_static_access,
                 member_name: &property.identifier,
@@ -530,9 +662,20 @@ impl<'a> AstVisitor<'a> {
 
     /// Visit and resolve an identifier path expression to a value.
     fn resolve_ident_path_to_value(&mut self, ident_path: &Expression) -> AstResult {
-        let (root_inst, _, acckind, member) = self.resolve_ident_path(ident_path)?;
+        let (root_inst, root_class_name, acckind, member) = self.resolve_ident_path(ident_path)?;
+
+        if let Some(SahaType::Name(class_name, _)) = root_class_name {
+            return self.access_static_property(&class_name, &member);
+        }
 
         if root_inst.is_none() {
+            // local variables shadow constants
+            if !self.local_refs.contains_key(&member.identifier) {
+                if let Some(value) = self.get_constant(&member.identifier) {
+                    return Ok(value);
+                }
+            }
+
             return self.get_local_ref(&member.identifier, &ident_path.file_position);
         }
 
@@ -556,14 +699,66 @@ impl<'a> AstVisitor<'a> {
             BinOpKind::Or => self.visit_binop_or(lhs_expr, rhs_expr, &binop.file_position),
             BinOpKind::Eq => self.visit_binop_eq(lhs_expr, rhs_expr, &binop.file_position),
             BinOpKind::Neq => self.visit_binop_neq(lhs_expr, rhs_expr, &binop.file_position),
+            BinOpKind::Coalesce => self.visit_binop_coalesce(lhs_expr, rhs_expr, &binop.file_position),
         }
     }
 
+    /// Call an operator behavior method on the left hand side object, if the
+    /// object implements the operator behavior. The right hand side operand is
+    /// passed in as the `other` argument.
+    fn call_operator_method(&mut self, lhs: &Value, rhs: &Value, behavior: &str, method: &str, op_pos: &FilePosition) -> Result<Option<Value>, RuntimeError> {
+        if *lhs.kind != SahaType::Obj || !self.get_object_implements(lhs).contains(&behavior.to_string()) {
+            return Ok(None);
+        }
+
+        let callable = Identifier {
+            file_position: op_pos.clone(),
+            identifier: method.to_string(),
+            type_params: Vec::new()
+        };
+
+        let mut args: SahaFunctionArguments = HashMap::new();
+
+        args.insert("other".to_string(), rhs.clone());
+
+        let result = self.call_method(lhs, &AccessKind::Instance, &callable, args)?;
+
+        return Ok(Some(result));
+    }
+
+    /// Compare an object with another value through the `Equals` behavior of
+    /// the object. Values of different types are never equal, so `equals` is
+    /// only called when both operands have the same type.
+    fn call_equals_method(&mut self, lhs: &Value, rhs: &Value, op_pos: &FilePosition) -> Result<Option<bool>, RuntimeError> {
+        if *lhs.kind != SahaType::Obj || !self.get_object_implements(lhs).contains(&"Equals".to_string()) {
+            return Ok(None);
+        }
+
+        if self.get_value_type(lhs) != self.get_value_type(rhs) {
+            return Ok(Some(false));
+        }
+
+        let result = self.call_operator_method(lhs, rhs, "Equals", "equals", op_pos)?;
+
+        return Ok(result.map(|r| r.bool.unwrap()));
+    }
+
     /// Visit binop expression.
     fn visit_binop_add(&mut self, lhs: &Expression, rhs: &Expression, op_pos: &FilePosition) -> AstResult {
         let lhs_value: Value = self.visit_expression(lhs)?;
         let rhs_value: Value = self.visit_expression(rhs)?;
 
+        if let Some(result) = self.call_operator_method(&lhs_value, &rhs_value, "Add", "add", op_pos)? {
+            return Ok(result);
+        }
+
+        // displayable objects can be concatenated to strings
+        if let (SahaType::Str, SahaType::Obj) | (SahaType::Obj, SahaType::Str) = (&*lhs_value.kind, &*rhs_value.kind) {
+            let pos = Some(op_pos.clone());
+
+            return Ok(Value::str(format!("{}{}", display_value(&lhs_value, &pos)?, display_value(&rhs_value, &pos)?)));
+        }
+
         let (lk, rk) = (lhs_value.kind, rhs_value.kind);
         let (lkstr, rkstr) = (format!("{:?}", lk), format!("{:?}", rk));
 
@@ -589,6 +784,10 @@ impl<'a> AstVisitor<'a> {
         let lhs_value: Value = self.visit_expression(lhs)?;
         let rhs_value: Value = self.visit_expression(rhs)?;
 
+        if let Some(result) = self.call_operator_method(&lhs_value, &rhs_value, "Sub", "sub", op_pos)? {
+            return Ok(result);
+        }
+
         let (lk, rk) = (lhs_value.kind, rhs_value.kind);
         let (lkstr, rkstr) = (format!("{:?}", lk), format!("{:?}", rk));
 
@@ -613,6 +812,10 @@ impl<'a> AstVisitor<'a> {
         let lhs_value: Value = self.visit_expression(lhs)?;
         let rhs_value: Value = self.visit_expression(rhs)?;
 
+        if let Some(result) = self.call_operator_method(&lhs_value, &rhs_value, "Mul", "mul", op_pos)? {
+            return Ok(result);
+        }
+
         let (lk, rk) = (lhs_value.kind, rhs_value.kind);
         let (lkstr, rkstr) = (format!("{:?}", lk), format!("{:?}", rk));
 
@@ -677,6 +880,10 @@ impl<'a> AstVisitor<'a> {
         let lhs_value = self.visit_expression(lhs)?;
         let rhs_value: Value = self.visit_expression(rhs)?;
 
+        if let Some(result) = self.call_operator_method(&lhs_value, &rhs_value, "Comparable", "compare", op_pos)? {
+            return Ok(Value::bool(result.int.unwrap() > 0));
+        }
+
         let (lk, rk) = (lhs_value.kind, rhs_value.kind);
         let (lkstr, rkstr) = (format!("{:?}", lk), format!("{:?}", rk));
 
@@ -701,6 +908,10 @@ impl<'a> AstVisitor<'a> {
         let lhs_value = self.visit_expression(lhs)?;
         let rhs_value: Value = self.visit_expression(rhs)?;
 
+        if let Some(result) = self.call_operator_method(&lhs_value, &rhs_value, "Comparable", "compare", op_pos)? {
+            return Ok(Value::bool(result.int.unwrap() >= 0));
+        }
+
         let (lk, rk) = (lhs_value.kind, rhs_value.kind);
         let (lkstr, rkstr) = (format!("{:?}", lk), format!("{:?}", rk));
 
@@ -725,6 +936,10 @@ impl<'a> AstVisitor<'a> {
         let lhs_value = self.visit_expression(lhs)?;
         let rhs_value: Value = self.visit_expression(rhs)?;
 
+        if let Some(result) = self.call_operator_method(&lhs_value, &rhs_value, "Comparable", "compare", op_pos)? {
+            return Ok(Value::bool(result.int.unwrap() < 0));
+        }
+
         let (lk, rk) = (lhs_value.kind, rhs_value.kind);
         let (lkstr, rkstr) = (format!("{:?}", lk), format!("{:?}", rk));
 
@@ -749,6 +964,10 @@ impl<'a> AstVisitor<'a> {
         let lhs_value = self.visit_expression(lhs)?;
         let rhs_value: Value = self.visit_expression(rhs)?;
 
+        if let Some(result) = self.call_operator_method(&lhs_value, &rhs_value, "Comparable", "compare", op_pos)? {
+            return Ok(Value::bool(result.int.unwrap() <= 0));
+        }
+
         let (lk, rk) = (lhs_value.kind, rhs_value.kind);
         let (lkstr, rkstr) = (format!("{:?}", lk), format!("{:?}", rk));
 
@@ -773,6 +992,15 @@ impl<'a> AstVisitor<'a> {
         let lhs_value = self.visit_expression(lhs)?;
         let rhs_value: Value = self.visit_expression(rhs)?;
 
+        if let Some(is_equal) = self.call_equals_method(&lhs_value, &rhs_value, op_pos)? {
+            return Ok(Value::bool(is_equal));
+        }
+
+        // tuples are compared element-wise, including objects in them
+        if let (SahaType::Tuple(..), SahaType::Tuple(..)) = (&*lhs_value.kind, &*rhs_value.kind) {
+            return Ok(Value::bool(values_equal(&lhs_value, &rhs_value, &Some(op_pos.clone()))?));
+        }
+
         let (lk, rk) = (lhs_value.kind, rhs_value.kind);
         let (lkstr, rkstr) = (format!("{:?}", lk), format!("{:?}", rk));
 
@@ -800,6 +1028,15 @@ impl<'a> AstVisitor<'a> {
         let lhs_value = self.visit_expression(lhs)?;
         let rhs_value: Value = self.visit_expression(rhs)?;
 
+        if let Some(is_equal) = self.call_equals_method(&lhs_value, &rhs_value, op_pos)? {
+            return Ok(Value::bool(!is_equal));
+        }
+
+        // tuples are compared element-wise, including objects in them
+        if let (SahaType::Tuple(..), SahaType::Tuple(..)) = (&*lhs_value.kind, &*rhs_value.kind) {
+            return Ok(Value::bool(!values_equal(&lhs_value, &rhs_value, &Some(op_pos.clone()))?));
+        }
+
         let (lk, rk) = (lhs_value.kind, rhs_value.kind);
         let (lkstr, rkstr) = (format!("{:?}", lk), format!("{:?}", rk));
 
@@ -902,6 +1139,252 @@ impl<'a> AstVisitor<'a> {
         return Ok(Value::bool(false));
     }
 
+    /// Visit binop expression. The right side is only evaluated if the left
+    /// side option is none.
+    fn visit_binop_coalesce(&mut self, lhs: &Expression, rhs: &Expression, op_pos: &FilePosition) -> AstResult {
+        let lhs_value = self.visit_expression(lhs)?;
+
+        let (option_type, inner_value) = self.unwrap_option(&lhs_value, "??", op_pos)?;
+
+        if let Some(value) = inner_value {
+            return Ok(value);
+        }
+
+        let rhs_value = self.visit_expression(rhs)?;
+
+        if !self.is_matching_type(&option_type, &rhs_value) {
+            let err = RuntimeError::new(
+                &format!(
+                    "Invalid right operand for `??`, expected `{}` but received `{}`",
+                    option_type.to_readable_string(),
+                    self.get_value_type(&rhs_value).to_readable_string()
+                ),
+                Some(op_pos.clone())
+            );
+
+            return Err(err);
+        }
+
+        return Ok(rhs_value);
+    }
+
+    /// Visit a safe navigation access, `option?->member`. Evaluates to none if
+    /// the option is none, otherwise the member access result wrapped in an
+    /// option. Either way the option is typed by the statically known type of
+    /// the accessed member. Core methods whose return type depends on their
+    /// arguments are typed by the returned value instead, which requires the
+    /// option to be some.
+    fn visit_safe_access(&mut self, option_expr: &Expression, binding: &Identifier, access_expr: &Expression) -> AstResult {
+        let option_value = self.visit_expression(option_expr)?;
+
+        let (option_type, inner_value) = self.unwrap_option(&option_value, "?->", &binding.file_position)?;
+
+        let static_type = match (self.get_access_chain_type(&option_type, access_expr), &inner_value) {
+            (Ok(result_type), _) => Some(result_type),
+            (Err(_), Some(_)) => None,
+            (Err(err), None) => return Err(err)
+        };
+
+        let inner_value = match inner_value {
+            Some(v) => v,
+            None => {
+                let result_type = static_type.unwrap();
+                let option_type = Self::get_option_type(&result_type).unwrap_or(result_type);
+
+                return self.create_option(None, option_type, &binding.file_position);
+            }
+        };
+
+        self.local_refs.insert(binding.identifier.clone(), (inner_value.kind.clone(), inner_value));
+
+        let result = self.visit_expression(access_expr);
+
+        self.local_refs.remove(&binding.identifier);
+
+        let result = result?;
+        let result_type = static_type.unwrap_or_else(|| self.get_value_type(&result));
+
+        // optional members are not wrapped again
+        if Self::get_option_type(&result_type).is_some() {
+            return Ok(result);
+        }
+
+        return self.create_option(Some(result), result_type, &binding.file_position);
+    }
+
+    /// Get the type parameter of an `Option` type, or `None` for other types.
+    fn get_option_type(value_type: &SahaType) -> Option<Box<SahaType>> {
+        return match value_type {
+            SahaType::Name(n, tps) if n == "Option" && tps.len() == 1 => Some(tps[0].clone()),
+            _ => None
+        };
+    }
+
+    /// Get the type of a safe navigation access chain, resolved from the type
+    /// of the unwrapped option value without evaluating the chain.
+    fn get_access_chain_type(&self, base_type: &SahaType, access_expr: &Expression) -> Result<Box<SahaType>, RuntimeError> {
+        return match &access_expr.kind {
+            ExpressionKind::IdentPath(_, members) => self.get_member_path_type(base_type, members, false),
+            ExpressionKind::FunctionCall(callee, _) => match &callee.kind {
+                ExpressionKind::IdentPath(_, members) => self.get_member_path_type(base_type, members, true),
+                _ => unreachable!()
+            },
+            ExpressionKind::ObjectAccess(lhs, access_kind, rhs) => {
+                let lhs_type = self.get_access_chain_type(base_type, lhs)?;
+
+                let (member, is_call) = match &rhs.kind {
+                    ExpressionKind::IdentPath(member, _) => (member, false),
+                    ExpressionKind::FunctionCall(callee, _) => match &callee.kind {
+                        ExpressionKind::IdentPath(member, _) => (member, true),
+                        _ => unreachable!()
+                    },
+                    _ => unreachable!()
+                };
+
+                self.get_member_path_type(&lhs_type, &[(access_kind.clone(), member.clone())], is_call)
+            },
+            _ => unreachable!()
+        };
+    }
+
+    /// Get the type of accessing a path of members on a type. The last member
+    /// is a method if the path is called.
+    fn get_member_path_type(&self, owner_type: &SahaType, members: &[(AccessKind, Identifier)], is_call: bool) -> Result<Box<SahaType>, RuntimeError> {
+        let mut member_type = Box::new(owner_type.clone());
+
+        for (idx, (_, member)) in members.iter().enumerate() {
+            let is_method = is_call && idx == members.len() - 1;
+
+            member_type = match self.get_member_type(&member_type, &member.identifier, is_method) {
+                Some(t) => t,
+                None => {
+                    let err = RuntimeError::new(
+                        &format!(
+                            "Cannot resolve the type of `{}` on `{}` for safe navigation",
+                            member.identifier,
+                            member_type.to_readable_string()
+                        ),
+                        Some(member.file_position.clone())
+                    );
+
+                    return Err(err);
+                }
+            };
+        }
+
+        return Ok(member_type);
+    }
+
+    /// Get the declared type of a property, or the return type of a method, of
+    /// a type. Class members are known from their definitions, core classes
+    /// resolve their method return types, and primitive values have their
+    /// value method return types.
+    fn get_member_type(&self, owner_type: &SahaType, member: &str, is_method: bool) -> Option<Box<SahaType>> {
+        let (class_name, type_params) = match owner_type {
+            SahaType::Name(n, tps) => (n, tps),
+            _ if is_method => return owner_type.get_value_method_type(member).map(Box::new),
+            _ => return None
+        };
+
+        let st = crate::SAHA_SYMBOL_TABLE.lock().unwrap();
+
+        let classdef = match st.classes.get(class_name) {
+            Some(classdef) => classdef,
+            None if is_method => return st.core_method_types.get(class_name)?(type_params, member).map(Box::new),
+            None => return None
+        };
+
+        let member_type = if is_method {
+            st.methods.get(&format!("{}#{}", class_name, member))?.get_return_type()
+        } else {
+            classdef.properties.get(member)?.prop_type.clone()
+        };
+
+        if *member_type == SahaType::Name(SELF_TYPE_NAME.to_string(), Vec::new()) {
+            return Some(Box::new(owner_type.clone()));
+        }
+
+        let type_param_map: HashMap<char, Box<SahaType>> = classdef.type_params.iter()
+            .zip(type_params)
+            .map(|((c, _), t)| (*c, t.clone()))
+            .collect();
+
+        return Some(Box::new(member_type.with_type_params(&type_param_map)));
+    }
+
+    /// Get the type parameter and the contained value of an `Option` value.
+    /// Errors if the value is not an option.
+    fn unwrap_option(&mut self, value: &Value, operator: &str, pos: &FilePosition) -> Result<(Box<SahaType>, Option<Value>), RuntimeError> {
+        let value_type = self.get_value_type(value);
+
+        let option_type = match *value_type {
+            SahaType::Name(ref n, ref tps) if n == "Option" && tps.len() == 1 => tps[0].clone(),
+            _ => {
+                let err = RuntimeError::new(
+                    &format!(
+                        "Invalid left operand for `{}`, expected an `Option` but received `{}`",
+                        operator,
+                        value_type.to_readable_string()
+                    ),
+                    Some(pos.clone())
+                );
+
+                return Err(err);
+            }
+        };
+
+        let method = |name: &str| Identifier {
+            file_position: pos.clone(),
+            identifier: name.to_string(),
+            type_params: Vec::new()
+        };
+
+        let is_some = self.call_method(value, &AccessKind::Instance, &method("isSome"), HashMap::new())?;
+
+        if !is_some.bool.unwrap() {
+            return Ok((option_type, None));
+        }
+
+        let inner = self.call_method(value, &AccessKind::Instance, &method("unwrap"), HashMap::new())?;
+
+        return Ok((option_type, Some(inner)));
+    }
+
+    /// Create a new `Option` instance, which is none if no value is given.
+    fn create_option(&mut self, value: Option<Value>, option_type: Box<SahaType>, pos: &FilePosition) -> AstResult {
+        let mut option_data: SahaFunctionArguments = HashMap::new();
+
+        if let Some(v) = value {
+            option_data.insert("value".to_string(), v);
+        }
+
+        let option_instref: InstRef;
+        let new_inst_method_ref: CoreConstructorFn;
+
+        {
+            let st = crate::SAHA_SYMBOL_TABLE.lock().unwrap();
+
+            option_instref = st.create_instref();
+            new_inst_method_ref = st.core_classes["Option"];
+        };
+
+        let option_instance = self.create_new_core_instance(
+            option_instref,
+            new_inst_method_ref,
+            &HashMap::new(),
+            &[option_type],
+            &option_data,
+            Some(pos.clone())
+        )?;
+
+        {
+            let mut st = crate::SAHA_SYMBOL_TABLE.lock().unwrap();
+            st.instances.insert(option_instref, Arc::new(Mutex::new(option_instance)));
+        }
+
+        return Ok(Value::obj(option_instref));
+    }
+
     fn visit_unop(&mut self, unop: &UnaryOp, expr: &Expression) -> AstResult {
         let expr_value = self.visit_expression(expr)?;
 
@@ -1008,6 +1491,49 @@ impl<'a> AstVisitor<'a> {
         return Ok(inst_val);
     }
 
+    /// Visit a tuple declaration (`(...)` delimited, comma separated items).
+    fn visit_tuple_expression(&mut self, items_exprs: &[Box<Expression>]) -> AstResult {
+        let mut items: Vec<Value> = Vec::new();
+        let mut item_types: Vec<Box<SahaType>> = Vec::new();
+
+        for item_expr in items_exprs {
+            let item = self.visit_expression(item_expr)?;
+
+            item_types.push(self.get_value_type(&item));
+            items.push(item);
+        }
+
+        return Ok(Value::tuple(items, item_types));
+    }
+
+    /// Visit a tuple element access expression.
+    fn visit_tuple_access(&mut self, tuple_expr: &Expression, index: usize, pos: FilePosition) -> AstResult {
+        let value = self.visit_expression(tuple_expr)?;
+
+        let items: Vec<Value> = match *value.kind {
+            SahaType::Tuple(..) => value.tuple.unwrap(),
+            _ => {
+                let err = RuntimeError::new(
+                    &format!("Cannot access element `{}` of a non-tuple value of type `{}`", index, value.kind.to_readable_string()),
+                    Some(pos)
+                );
+
+                return Err(err);
+            }
+        };
+
+        if index >= items.len() {
+            let err = RuntimeError::new(
+                &format!("Tuple index `{}` is out of bounds for a tuple of {} elements", index, items.len()),
+                Some(pos)
+            );
+
+            return Err(err);
+        }
+
+        return Ok(items[index].clone());
+    }
+
     /// Visit a dictionary shorthand declaration (`{...}` delimited).
     fn visit_dict_shorthand_expression(&mut self, items_exprs: &[(Box<Expression>, Box<Expression>)], pos: FilePosition) -> AstResult {
         let mut value_hmap: HashMap<String, Value> = HashMap::new();
@@ -1120,21 +1646,28 @@ impl<'a> AstVisitor<'a> {
                 if members.is_empty() {
                     member = root.clone();
                 } else {
-                    let maybe_a_static_class_call: bool = members.len() == 1 && members.first().unwrap().0 == AccessKind::Static;
+                    let maybe_a_static_class_access: bool = members.first().unwrap().0 == AccessKind::Static;
 
                     let mut memberpath = members.clone();
-                    let resolved_local_value = self.resolve_local_name(&root);
-
-                    if resolved_local_value.is_err() && maybe_a_static_class_call {
-                        return Ok((
-                            None,
-                            Some(SahaType::Name(root.identifier.clone(), root.type_params.clone())),
-                            Some(AccessKind::Static),
-                            members.first().unwrap().1.clone()
-                        ));
+                    let mut resolved_local_value = self.resolve_local_name(root);
+
+                    if resolved_local_value.is_err() && maybe_a_static_class_access {
+                        if members.len() == 1 {
+                            return Ok((
+                                None,
+                                Some(SahaType::Name(root.identifier.clone(), root.type_params.clone())),
+                                Some(AccessKind::Static),
+                                members.first().unwrap().1.clone()
+                            ));
+                        }
+
+                        // static property of a class being accessed further
+                        let (_, static_prop) = memberpath.remove(0);
+
+                        resolved_local_value = self.access_static_property(&root.identifier, &static_prop);
                     }
 
-                    let mut obj_being_accessed: Option<Value> = resolved_local_value.ok();
+                    let mut obj_being_accessed: Option<Value> = Some(resolved_local_value?);
 
                     loop {
                         let (acckind, mname) = memberpath.remove(0);
@@ -1188,13 +1721,26 @@ impl<'a> AstVisitor<'a> {
             }
         };
 
-        let inst_lockable = self.get_instance_lockable_ref(&obj.obj.unwrap(), property_name.file_position.clone())?;
-
         let is_static_access = match access_kind {
             AccessKind::Static => true,
             _ => false
         };
 
+        if let Some(class_name) = self.get_static_property_owner(obj, &property_name)? {
+            if !is_static_access {
+                let err = RuntimeError::new(
+                    &format!("Attempted to access static property `{}` unstatically on class `{}`", property_name.identifier, class_name),
+                    Some(property_name.file_position)
+                );
+
+                return Err(err);
+            }
+
+            return self.access_static_property(&class_name, &property_name);
+        }
+
+        let inst_lockable = self.get_instance_lockable_ref(&obj.obj.unwrap(), property_name.file_position.clone())?;
+
         let access = AccessParams {
             is_static_access: is_static_access,
             member_name: &property_name.identifier,
@@ -1205,6 +1751,103 @@ impl<'a> AstVisitor<'a> {
         return inst_lockable.lock().unwrap().access_property(access);
     }
 
+    /// Get the class name of an object if the object class has a static
+    /// property with the given name.
+    fn get_static_property_owner(&self, obj: &Value, property_name: &Identifier) -> Result<Option<String>, RuntimeError> {
+        let inst_lockable = self.get_instance_lockable_ref(&obj.obj.unwrap(), property_name.file_position.clone())?;
+        let class_name = inst_lockable.lock().unwrap().get_fully_qualified_class_name();
+
+        let st = crate::SAHA_SYMBOL_TABLE.lock().unwrap();
+
+        if st.static_properties.contains_key(&format!("{}#{}", class_name, property_name.identifier)) {
+            return Ok(Some(class_name));
+        }
+
+        return Ok(None);
+    }
+
+    /// Get the class-level static property for reading or writing, checking
+    /// that the property exists and is visible in the current context.
+    fn get_static_property(&self, class_name: &str, property_name: &Identifier, action: &str) -> Result<Property, RuntimeError> {
+        let prop_pos = Some(property_name.file_position.clone());
+
+        let st = crate::SAHA_SYMBOL_TABLE.lock().unwrap();
+
+        if !st.classes.contains_key(class_name) {
+            let err = RuntimeError::new(&format!("Cannot {} property of unknown class `{}`", action, class_name), prop_pos);
+
+            return Err(err);
+        }
+
+        let prop = match st.static_properties.get(&format!("{}#{}", class_name, property_name.identifier)) {
+            Some(p) => p.clone(),
+            None => {
+                let err = RuntimeError::new(
+                    &format!("Attempted to {} undefined static property `{}` on class `{}`", action, property_name.identifier, class_name),
+                    prop_pos
+                );
+
+                return Err(err);
+            }
+        };
+
+        if prop.visibility == MemberVisibility::Private && self.self_class.as_deref() != Some(class_name) {
+            let err = RuntimeError::new(
+                &format!("Attempted to {} private property `{}` on class `{}`", action, property_name.identifier, class_name),
+                prop_pos
+            );
+
+            return Err(err);
+        }
+
+        return Ok(prop);
+    }
+
+    /// Access (get) a static property value stored for a class.
+    fn access_static_property(&self, class_name: &str, property_name: &Identifier) -> AstResult {
+        let prop = self.get_static_property(class_name, property_name, "access")?;
+
+        match prop.value {
+            Some(val) => Ok(val),
+            None => {
+                let err = RuntimeError::new(
+                    &format!("Attempted to access uninitialized static property `{}` on class `{}`", property_name.identifier, class_name),
+                    Some(property_name.file_position.clone())
+                );
+
+                return Err(err);
+            }
+        }
+    }
+
+    /// Mutate (set) a static property value stored for a class.
+    fn mutate_static_property(&self, class_name: &str, property_name: &Identifier, new_value: Value) -> AstResult {
+        let mut prop = self.get_static_property(class_name, property_name, "mutate")?;
+
+        if !self.is_matching_type(&prop.prop_type, &new_value) {
+            let err = RuntimeError::new(
+                &format!(
+                    "Type mismatch when attempting to mutate property `{}` on class `{}`, expected `{:?}` but received `{:?}`",
+                    property_name.identifier,
+                    class_name,
+                    prop.prop_type,
+                    self.get_value_type(&new_value)
+                ),
+                Some(property_name.file_position.clone())
+            );
+
+            return Err(err);
+        }
+
+        prop.value = Some(new_value);
+
+        let mut st = crate::SAHA_SYMBOL_TABLE.lock().unwrap();
+
+        st.static_properties.insert(format!("{}#{}", class_name, property_name.identifier), prop);
+
+        return Ok(Value::void());
+    }
+
     /// Resolve an identifier name to a local ref table value.
     fn resolve_local_name(&mut self, name: &Identifier) -> AstResult {
         let refvalue: Value = self.get_local_ref(&name.identifier, &name.file_position)?;
@@ -1217,10 +1860,17 @@ impl<'a> AstVisitor<'a> {
 
         match &args.kind {
             ExpressionKind::CallableArgs(vargs) => {
-                for varg in vargs {
+                for (idx, varg) in vargs.iter().enumerate() {
                     match &varg.kind {
                         ExpressionKind::CallableArg(argname, argval) => {
-                            call_args.insert(argname.identifier.clone(), self.visit_expression(&argval)?);
+                            // unnamed args are positional, and are keyed by their index
+                            let key = if argname.identifier.is_empty() {
+                                positional_arg_name(idx)
+                            } else {
+                                argname.identifier.clone()
+                            };
+
+                            call_args.insert(key, self.visit_expression(argval)?);
                         },
                         _ => unreachable!()
                     };
@@ -1253,6 +1903,8 @@ impl<'a> AstVisitor<'a> {
             func = funcopt.clone();
         }
 
+        self.warn_if_deprecated(&format!("Function `{}`", func.get_source_name()), &func.get_attributes(), &callable.file_position);
+
         let call_args: SahaFunctionArguments = self.parse_callable_args(args)?;
 
         return func.call(call_args, None, Vec::new(), Some(callable.file_position.clone()));
@@ -1319,13 +1971,11 @@ impl<'a> AstVisitor<'a> {
     /// Call a static method with just the class name, no instance.
     fn call_static_method_with_classname(
         &mut self,
-        _class: &SahaType,
+        class: &SahaType,
         _access_kind: &AccessKind,
-        _callable: &Identifier,
-        _args: &Expression
+        callable: &Identifier,
+        args: &Expression
     ) -> AstResult {
-        unimplemented!("Static access is not ready");
-        /*
         let call_args: SahaFunctionArguments = self.parse_callable_args(args)?;
 
         let memberpos = callable.file_position.clone();
@@ -1334,20 +1984,12 @@ impl<'a> AstVisitor<'a> {
         if let SahaType::Name(ref n, ref ty) = *class {
             let static_method_name = format!("{}#{}", n, membername);
             let method_ref: Arc<Box<dyn SahaCallable>>;
-            let call_ty: Vec<(char, Box<SahaType>)>;
-
-            let access = AccessParams {
-                is_static_access: true,
-                member_name: &membername,
-                accessor_instref: &self.self_ref.clone(),
-                access_file_pos: &Some(memberpos.clone())
-            };
 
             {
                 let st = crate::SAHA_SYMBOL_TABLE.lock().unwrap();
 
-                if !st.classes.contains_key(n) && !st.core_classes.contains_key(n) {
-                    let err = RuntimeError::new(&format!("Cannot call method of unknown class `{}`", n), Some(memberpos.clone()));
+                if !st.classes.contains_key(n) {
+                    let err = RuntimeError::new(&format!("Cannot call method of unknown class `{}`", n), Some(memberpos));
 
                     return Err(err);
                 }
@@ -1361,20 +2003,29 @@ impl<'a> AstVisitor<'a> {
                     return Err(err);
                 }
 
-                let class_ty = self.get_class_type_params(n);
+                method_ref = Arc::clone(&st.methods[&static_method_name]);
+            }
+
+            if !method_ref.is_static() {
+                let err = RuntimeError::new(
+                    &format!("Attempted to call instance method `{}` statically on class `{}`", membername, n),
+                    Some(memberpos)
+                );
 
-                call_ty = class_ty.into_iter().zip(ty.clone().into_iter()).collect::<Vec<(char, Box<SahaType>)>>();
-                method_ref = Arc::clone(st.methods.get(&static_method_name).unwrap());
+                return Err(err);
             }
 
-            return self.call_instance_member(
-                None,
-                method_ref,
-                access,
-                call_args,
-                call_ty,
-                n.to_string()
-            );
+            let class_ty = self.get_class_type_params(n);
+            let call_ty = class_ty.into_iter().zip(ty.clone()).collect::<Vec<(char, Box<SahaType>)>>();
+
+            let access = AccessParams {
+                is_static_access: true,
+                member_name: &membername,
+                accessor_instref: &self.self_ref.clone(),
+                access_file_pos: &Some(memberpos.clone())
+            };
+
+            return self.call_instance_member(None, &method_ref, access, &call_args, call_ty, n);
         } else {
             let err = RuntimeError::new(
                 &format!("Cannot call static method `{}` of value `{}`", membername, class.to_readable_string()),
@@ -1383,7 +2034,6 @@ impl<'a> AstVisitor<'a> {
 
             return Err(err);
         }
-        */
     }
 
     /// Call an instance method.
@@ -1407,7 +2057,8 @@ impl<'a> AstVisitor<'a> {
                 _ => false
             }
         } else {
-            false
+            // static calls are internal when made from inside the class
+            self.self_class.as_deref() == Some(classname)
         };
 
         let member_is_static = method_ref.is_static();
@@ -1451,6 +2102,10 @@ impl<'a> AstVisitor<'a> {
             return Err(err);
         }
 
+        if let Some(pos) = access_pos {
+            self.warn_if_deprecated(&format!("Method `{}` of class `{}`", member, classname), &method_ref.get_attributes(), pos);
+        }
+
         // clone here to prevent any accidental side effects
         let mut call_args: SahaFunctionArguments = args.clone();
 
@@ -1462,13 +2117,47 @@ impl<'a> AstVisitor<'a> {
         return method_ref.call(call_args, Some(actual_return_type), Vec::new(), access_pos.clone());
     }
 
+    /// Print a warning to stderr when a declaration marked with `@deprecated`
+    /// is used. Each source position is warned about once.
+    fn warn_if_deprecated(&self, subject: &str, attributes: &[Attribute], use_pos: &FilePosition) {
+        let attr = match get_attribute(attributes, DEPRECATED_ATTRIBUTE) {
+            Some(a) => a,
+            None => return
+        };
+
+        {
+            let mut st = crate::SAHA_SYMBOL_TABLE.lock().unwrap();
+
+            if st.deprecation_warnings.contains(use_pos) {
+                return;
+            }
+
+            st.deprecation_warnings.push(use_pos.clone());
+        }
+
+        let message = match attr.args.first() {
+            Some(msg) => format!(": {}", msg.str.clone().unwrap_or_default()),
+            None => String::new()
+        };
+
+        eprintln!("Warning: {} is deprecated{}\nat {:?}", subject, message, use_pos);
+    }
+
     /// Visit a newup expression.
     fn visit_instance_newup(&mut self, ident: &Identifier, args: &Expression, typeparams: &[Box<SahaType>]) -> AstResult {
         let newup_args: SahaFunctionArguments = self.parse_callable_args(args)?;
+
+        return self.newup_instance(&ident.identifier, newup_args, typeparams, &ident.file_position);
+    }
+
+    /// Create a new instance of a class and call its `init` method. Instances
+    /// of classes with a private `init` can only be created inside the class.
+    pub(crate) fn newup_instance(&mut self, class_name: &str, newup_args: SahaFunctionArguments, typeparams: &[Box<SahaType>], pos: &FilePosition) -> AstResult {
         let inst_val: Value;
         let new_instref: InstRef;
         let mut user_inst_def: Option<ClassDefinition> = None;
         let mut core_inst_def: Option<CoreConstructorFn> = None;
+        let mut init_method_ref: Option<Arc<Box<dyn SahaCallable>>> = None;
         let created_inst: Box<dyn SahaObject>;
 
         {
@@ -1476,24 +2165,40 @@ impl<'a> AstVisitor<'a> {
 
             new_instref = st.create_instref();
 
-            if st.classes.contains_key(&ident.identifier) {
-                user_inst_def = st.classes.get(&ident.identifier).cloned();
+            if st.classes.contains_key(class_name) {
+                user_inst_def = st.classes.get(class_name).cloned();
+                init_method_ref = st.methods.get(&format!("{}#{}", class_name, INIT_METHOD_NAME)).cloned();
             }
 
-            if st.core_classes.contains_key(&ident.identifier) {
-                core_inst_def = Some(st.core_classes[&ident.identifier]);
+            if st.core_classes.contains_key(class_name) {
+                core_inst_def = Some(st.core_classes[class_name]);
+            }
+        }
+
+        if let Some(ref def) = user_inst_def {
+            self.warn_if_deprecated(&format!("Class `{}`", class_name), &def.attributes, pos);
+        }
+
+        if let Some(ref init) = init_method_ref {
+            if !init.is_public() && self.self_class.as_deref() != Some(class_name) {
+                let err = RuntimeError::new(
+                    &format!("Cannot create instance of class `{}` outside of the class, its `{}` method is private", class_name, INIT_METHOD_NAME),
+                    Some(pos.clone())
+                );
+
+                return Err(err);
             }
         }
 
         // we run instance creation outside the symboltable lockup lifetime to
         // prevent race conditions when locking
         created_inst = match (user_inst_def, core_inst_def) {
-            (Some(def), None) => self.create_new_instance(new_instref, &def, newup_args, typeparams, &Some(ident.file_position.clone()))?,
-            (None, Some(fnref)) => self.create_new_core_instance(new_instref, fnref, &newup_args, typeparams, &HashMap::new(), Some(ident.file_position.clone()))?,
+            (Some(def), None) => self.create_new_instance(new_instref, &def, newup_args, typeparams, &Some(pos.clone()))?,
+            (None, Some(fnref)) => self.create_new_core_instance(new_instref, fnref, &newup_args, typeparams, &HashMap::new(), Some(pos.clone()))?,
             _ => {
                 let err = RuntimeError::new(
-                    &format!("Cannot instantiate unknown class `{}`", ident.identifier),
-                    Some(ident.file_position.clone())
+                    &format!("Cannot instantiate unknown class `{}`", class_name),
+                    Some(pos.clone())
                 );
 
                 return Err(err);
@@ -1507,9 +2212,94 @@ impl<'a> AstVisitor<'a> {
 
         inst_val = Value::obj(new_instref);
 
+        if let Some(init) = init_method_ref {
+            return self.call_init_method(inst_val, &init, class_name, pos);
+        }
+
         return Ok(inst_val);
     }
 
+    /// Call the `init` method of a newly created instance. If `init` returns a
+    /// `Result`, the newup results in a `Result` containing the instance on
+    /// success, or the `init` failure value.
+    fn call_init_method(&mut self, inst_val: Value, init: &Arc<Box<dyn SahaCallable>>, class_name: &str, pos: &FilePosition) -> AstResult {
+        let instref = inst_val.obj.unwrap();
+        let inst_tparams;
+        let inst_type;
+
+        {
+            let inst_lockable = self.get_instance_lockable_ref(&instref, pos.clone())?;
+            let inst = inst_lockable.lock().unwrap();
+
+            inst_tparams = inst.get_type_params();
+            inst_type = inst.get_named_type();
+        }
+
+        let access = AccessParams {
+            is_static_access: false,
+            member_name: INIT_METHOD_NAME,
+            accessor_instref: &Some(instref),
+            access_file_pos: &Some(pos.clone())
+        };
+
+        let init_result = self.call_instance_member(Some(instref), init, access, &HashMap::new(), inst_tparams, class_name)?;
+
+        let fail_type = match *init.get_return_type() {
+            SahaType::Name(ref n, ref tps) if n == "Result" && tps.len() == 2 => tps[1].clone(),
+            _ => return Ok(inst_val)
+        };
+
+        let method = |name: &str| Identifier {
+            file_position: pos.clone(),
+            identifier: name.to_string(),
+            type_params: Vec::new()
+        };
+
+        let is_success = self.call_method(&init_result, &AccessKind::Instance, &method("isSuccess"), HashMap::new())?;
+
+        if is_success.bool.unwrap() {
+            return self.create_result(true, inst_val, inst_type, fail_type, pos);
+        }
+
+        let failure = self.call_method(&init_result, &AccessKind::Instance, &method("unwrap"), HashMap::new())?;
+
+        return self.create_result(false, failure, inst_type, fail_type, pos);
+    }
+
+    /// Create a new `Result` instance which has either succeeded or failed
+    /// with a value.
+    fn create_result(&mut self, is_success: bool, value: Value, success_type: Box<SahaType>, fail_type: Box<SahaType>, pos: &FilePosition) -> AstResult {
+        let mut result_data: SahaFunctionArguments = HashMap::new();
+
+        result_data.insert((if is_success { "success" } else { "failure" }).to_string(), value);
+
+        let result_instref: InstRef;
+        let new_inst_method_ref: CoreConstructorFn;
+
+        {
+            let st = crate::SAHA_SYMBOL_TABLE.lock().unwrap();
+
+            result_instref = st.create_instref();
+            new_inst_method_ref = st.core_classes["Result"];
+        };
+
+        let result_instance = self.create_new_core_instance(
+            result_instref,
+            new_inst_method_ref,
+            &HashMap::new(),
+            &[success_type, fail_type],
+            &result_data,
+            Some(pos.clone())
+        )?;
+
+        {
+            let mut st = crate::SAHA_SYMBOL_TABLE.lock().unwrap();
+            st.instances.insert(result_instref, Arc::new(Mutex::new(result_instance)));
+        }
+
+        return Ok(Value::obj(result_instref));
+    }
+
     /// Create a new core class instance.
     fn create_new_core_instance(
         &mut self,
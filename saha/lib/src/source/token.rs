@@ -30,6 +30,10 @@ pub enum Token {
     /// `->` sequence. Marks object instance access.
     ObjectAccess(FilePosition),
 
+    /// `?->` sequence. Marks object instance access through an `Option`,
+    /// which short-circuits on none.
+    SafeObjectAccess(FilePosition),
+
     /// `::` sequence. Marks object static access.
     StaticAccess(FilePosition),
 
@@ -42,6 +46,9 @@ pub enum Token {
     /// `?` character.
     QuestionMark(FilePosition),
 
+    /// `@` character. Begins an attribute, e.g. `@derive(Equals)`.
+    At(FilePosition),
+
     /// '&' character.
     Ampersand(FilePosition),
 
@@ -72,6 +79,9 @@ pub enum Token {
     /// `}` character.
     CurlyClose(FilePosition),
 
+    /// `...` used for variadic parameters.
+    Ellipsis(FilePosition),
+
     /// Import with `use` keyword, denotes imports from other codebases.
     Import(FilePosition, Import),
 
@@ -99,6 +109,9 @@ pub enum Token {
     /// Literal boolean value, meaning either `true` or `false` in source code.
     BooleanValue(FilePosition, bool),
 
+    /// Tuple element index, e.g. the `.0` in `my_tuple.0`.
+    TupleIndex(FilePosition, usize),
+
     // TYPE DECLARATIONS
 
     /// `str` declaration.
@@ -154,6 +167,9 @@ pub enum Token {
     /// `||` characters.
     OpOr(FilePosition),
 
+    /// `??` characters.
+    OpCoalesce(FilePosition),
+
     /// `|>` characters.
     OpPipe(FilePosition),
 
@@ -171,6 +187,9 @@ pub enum Token {
     /// `behavior` keyword.
     KwBehavior(FilePosition),
 
+    /// `type` keyword.
+    KwType(FilePosition),
+
     /// `var` keyword.
     KwVar(FilePosition),
 
@@ -210,6 +229,9 @@ pub enum Token {
     /// `implements` keyword.
     KwImplements(FilePosition),
 
+    /// `extends` keyword.
+    KwExtends(FilePosition),
+
     /// `function` keyword.
     KwFunction(FilePosition),
 
@@ -233,12 +255,15 @@ impl Display for Token {
             Token::IntegerValue(_, _) => "Literal integer".to_string(),
             Token::FloatValue(_, _) => "Literal float".to_string(),
             Token::BooleanValue(_, b) => format!("Boolean [{:?}]", b),
+            Token::TupleIndex(_, i) => format!("Tuple index [{:?}]", i),
             Token::Name(_, _, orig) => format!("Name [{:?}]", orig),
             Token::ObjectAccess(_) => "Object access".to_string(),
+            Token::SafeObjectAccess(_) => "Safe object access".to_string(),
             Token::StaticAccess(_) => "Static access".to_string(),
             Token::Comma(_) => "Comma".to_string(),
             Token::Colon(_) => "Colon".to_string(),
             Token::QuestionMark(_) => "Question mark".to_string(),
+            Token::At(_) => "At [@]".to_string(),
             Token::SingleQuote(_) => "Single quote".to_string(),
             Token::Ampersand(_) => "Ampersand [&]".to_string(),
             Token::Pipe(_) => "Pipe [|]".to_string(),
@@ -249,6 +274,7 @@ impl Display for Token {
             Token::BraceClose(_) => "Brace close".to_string(),
             Token::CurlyOpen(_) => "Curly brace open".to_string(),
             Token::CurlyClose(_) => "Curly brace close".to_string(),
+            Token::Ellipsis(_) => "Ellipsis".to_string(),
             Token::Import(_, _) => "Import definition".to_string(),
             Token::Assign(_) => "Assignment".to_string(),
             Token::Eof(_) => "EOF".to_string(),
@@ -272,12 +298,14 @@ impl Display for Token {
             Token::OpLte(_) => "Operator [<=]".to_string(),
             Token::OpAnd(_) => "Operator [&&]".to_string(),
             Token::OpOr(_) => "Operator [||]".to_string(),
+            Token::OpCoalesce(_) => "Operator [??]".to_string(),
             Token::OpPipe(_) => "Operator [|>]".to_string(),
 
             Token::KwUse(_) => "Keyword [use]".to_string(),
             Token::KwAs(_) => "Keyword [as]".to_string(),
             Token::KwClass(_) => "Keyword [class]".to_string(),
             Token::KwBehavior(_) => "Keyword [behavior]".to_string(),
+            Token::KwType(_) => "Keyword [type]".to_string(),
             Token::KwVar(_) => "Keyword [var]".to_string(),
             Token::KwProperty(_) => "Keyword [property]".to_string(),
             Token::KwConstant(_) => "Keyword [constant]".to_string(),
@@ -291,6 +319,7 @@ impl Display for Token {
             Token::KwElseif(_) => "Keyword [elseif]".to_string(),
             Token::KwElse(_) => "Keyword [else]".to_string(),
             Token::KwImplements(_) => "Keyword [implements]".to_string(),
+            Token::KwExtends(_) => "Keyword [extends]".to_string(),
             Token::KwFunction(_) => "Keyword [function]".to_string(),
             Token::KwMethod(_) => "Keyword [method]".to_string(),
             Token::KwPublic(_) => "Keyword [public]".to_string(),
@@ -312,11 +341,12 @@ impl Token {
     /// means all "math" is done before comparisons, and comparisons precede "true/false" checks.
     pub fn get_precedence(&self) -> i8 {
         return match self {
-            Token::OpAnd(..) | Token::OpOr(..) => 0,
+            Token::OpCoalesce(..) => 0,
+            Token::OpAnd(..) | Token::OpOr(..) => 1,
             Token::OpEq(..) | Token::OpNeq(..) | Token::OpGt(..)
-            | Token::OpGte(..) | Token::OpLt(..) | Token::OpLte(..) => 1,
-            Token::OpAdd(..) | Token::OpSub(..) => 2,
-            Token::OpDiv(..) | Token::OpMul(..) => 3,
+            | Token::OpGte(..) | Token::OpLt(..) | Token::OpLte(..) => 2,
+            Token::OpAdd(..) | Token::OpSub(..) => 3,
+            Token::OpDiv(..) | Token::OpMul(..) => 4,
             _ => -1
         };
     }
@@ -337,10 +367,12 @@ impl Token {
             Token::Name(f, ..) => f.clone(),
             Token::Assign(f, ..) => f.clone(),
             Token::ObjectAccess(f, ..) => f.clone(),
+            Token::SafeObjectAccess(f, ..) => f.clone(),
             Token::StaticAccess(f, ..) => f.clone(),
             Token::Comma(f, ..) => f.clone(),
             Token::Colon(f, ..) => f.clone(),
             Token::QuestionMark(f, ..) => f.clone(),
+            Token::At(f, ..) => f.clone(),
             Token::SingleQuote(f, ..) => f.clone(),
             Token::Ampersand(f, ..) => f.clone(),
             Token::Pipe(f, ..) => f.clone(),
@@ -351,6 +383,7 @@ impl Token {
             Token::BraceClose(f, ..) => f.clone(),
             Token::CurlyOpen(f, ..) => f.clone(),
             Token::CurlyClose(f, ..) => f.clone(),
+            Token::Ellipsis(f, ..) => f.clone(),
             Token::Import(f, ..) => f.clone(),
             Token::Eof(f, ..) => f.clone(),
             Token::Eob => FilePosition::unknown(),
@@ -358,6 +391,7 @@ impl Token {
             Token::IntegerValue(f, ..) => f.clone(),
             Token::FloatValue(f, ..) => f.clone(),
             Token::BooleanValue(f, ..) => f.clone(),
+            Token::TupleIndex(f, ..) => f.clone(),
             Token::TypeBoolean(f, ..) => f.clone(),
             Token::TypeFloat(f, ..) => f.clone(),
             Token::TypeInteger(f, ..) => f.clone(),
@@ -375,11 +409,13 @@ impl Token {
             Token::OpLte(f, ..) => f.clone(),
             Token::OpAnd(f, ..) => f.clone(),
             Token::OpOr(f, ..) => f.clone(),
+            Token::OpCoalesce(f, ..) => f.clone(),
             Token::OpPipe(f, ..) => f.clone(),
             Token::KwUse(f, ..) => f.clone(),
             Token::KwAs(f, ..) => f.clone(),
             Token::KwClass(f, ..) => f.clone(),
             Token::KwBehavior(f, ..) => f.clone(),
+            Token::KwType(f, ..) => f.clone(),
             Token::KwVar(f, ..) => f.clone(),
             Token::KwProperty(f, ..) => f.clone(),
             Token::KwConstant(f, ..) => f.clone(),
@@ -393,6 +429,7 @@ impl Token {
             Token::KwElseif(f, ..) => f.clone(),
             Token::KwElse(f, ..) => f.clone(),
             Token::KwImplements(f, ..) => f.clone(),
+            Token::KwExtends(f, ..) => f.clone(),
             Token::KwFunction(f, ..) => f.clone(),
             Token::KwMethod(f, ..) => f.clone(),
             Token::KwPublic(f, ..) => f.clone(),
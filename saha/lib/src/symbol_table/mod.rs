@@ -16,7 +16,9 @@ use crate::prelude::*;
 use crate::types::objects::{
     BehaviorDefinition,
     ClassDefinition,
-    CoreConstructorFn
+    CoreConstructorFn,
+    CoreMethodTypeFn,
+    Property
 };
 
 
@@ -29,6 +31,10 @@ pub struct SymbolTable {
     /// letters and underscores.
     pub constants: HashMap<String, Value>,
 
+    /// Visibility of user declared constants, keyed by constant name. Core
+    /// constants are public and not listed here.
+    pub constant_visibilities: HashMap<String, MemberVisibility>,
+
     /// Functions are top-level function declarations defined with the
     /// `function` keyword.
     pub functions: HashMap<String, Box<dyn SahaCallable>>,
@@ -52,6 +58,15 @@ pub struct SymbolTable {
     /// to create new instances of them.
     pub core_classes: HashMap<String, CoreConstructorFn>,
 
+    /// Method return type resolvers of core-defined classes, keyed by the
+    /// same names as `core_classes`.
+    pub core_method_types: HashMap<String, CoreMethodTypeFn>,
+
+    /// Standard library members, keyed by their fully qualified import name,
+    /// e.g. `std.io.print_line`. Values are the names under which the member
+    /// is registered into `functions`, `behaviors` or `core_classes`.
+    pub std_members: HashMap<String, String>,
+
     /// Class methods. These are the same as functions, but the naming
     /// convention goes as such:
     ///
@@ -67,6 +82,17 @@ pub struct SymbolTable {
     /// receive no `self` parameter.
     pub methods: HashMap<String, Arc<Box<dyn SahaCallable>>>,
 
+    /// Static class properties. These are stored per class instead of per
+    /// instance, and use the same naming convention as methods:
+    ///
+    /// ```txt
+    /// fully.qualified.className#propertyName
+    /// ```
+    ///
+    /// Values are initialized from the property defaults when the class is
+    /// loaded, and are shared by everything that accesses them.
+    pub static_properties: HashMap<String, Property>,
+
     /// Class instances (data) are stored here. They are behind an Arc and a
     /// Mutex to keep things consistent in case multiple points of an
     /// application want to modify or interact with the same instance at the
@@ -75,18 +101,32 @@ pub struct SymbolTable {
     /// Instances contain only the data of a class instance, methods are stored
     /// centrally in another HashMap.
     pub instances: HashMap<InstRef, Arc<Mutex<Box<dyn SahaObject>>>>,
+
+    /// Source positions which have already produced a deprecation warning, so
+    /// each use of a deprecated declaration is warned about only once.
+    pub deprecation_warnings: Vec<FilePosition>,
+
+    /// Arguments given to the running script after `--` on the command line,
+    /// e.g. `a` and `b` in `saha_interpreter script.saha -- a b`.
+    pub script_args: Vec<String>,
 }
 
 impl Default for SymbolTable {
     fn default() -> SymbolTable {
         return SymbolTable {
             constants: HashMap::new(),
+            constant_visibilities: HashMap::new(),
             functions: HashMap::new(),
             behaviors: HashMap::new(),
             classes: HashMap::new(),
             core_classes: HashMap::new(),
+            core_method_types: HashMap::new(),
+            std_members: HashMap::new(),
             methods: HashMap::new(),
+            static_properties: HashMap::new(),
             instances: HashMap::new(),
+            deprecation_warnings: Vec::new(),
+            script_args: Vec::new(),
         };
     }
 }
@@ -102,6 +142,11 @@ impl SymbolTable {
         self.constants = constants;
     }
 
+    /// Add constants to the symbol table constants collection.
+    pub fn add_constants(&mut self, constants: HashMap<String, Value>) {
+        self.constants.extend(constants);
+    }
+
     /// Add a new function/callable.
     pub fn add_function(&mut self, func: Box<dyn SahaCallable>) {
         let fn_name = func.get_name().clone();
@@ -118,6 +163,20 @@ impl SymbolTable {
         self.methods.insert(fq_method_name, Arc::new(method.clone()));
     }
 
+    /// Add a new static property, initialized with its default value.
+    pub fn add_static_property(&mut self, class_name: &str, property: &Property) {
+        let fq_prop_name = format!("{}#{}", class_name, property.name);
+
+        let mut static_prop = property.clone();
+
+        static_prop.value = match *property.default.kind {
+            SahaType::Void => None,
+            _ => Some(property.default.clone())
+        };
+
+        self.static_properties.insert(fq_prop_name, static_prop);
+    }
+
     /// Insert a new object instance to the symbol table, and then return the
     /// instref value.
     pub fn create_object_instance(
@@ -0,0 +1,37 @@
+//! Saha attributes
+//!
+//! Attributes attach metadata to declarations, e.g. `@deprecated("Use bar()")`
+//! on a function. They are stored on functions, classes, methods and
+//! properties, and can be queried by the runtime and tooling.
+
+use crate::prelude::*;
+
+/// Functions marked with this attribute are run by the interpreter test runner.
+pub const TEST_ATTRIBUTE: &str = "test";
+
+/// Using declarations marked with this attribute produces a warning. Takes an
+/// optional message, e.g. `@deprecated("Use bar() instead")`.
+pub const DEPRECATED_ATTRIBUTE: &str = "deprecated";
+
+/// Classes marked with this attribute get generated behavior methods, e.g.
+/// `@derive(Equals, Displayable)`.
+pub const DERIVE_ATTRIBUTE: &str = "derive";
+
+/// A single attribute with its arguments. Arguments are literal values, or
+/// names which are stored as `Name` values.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Attribute {
+    pub name: String,
+    pub args: Vec<Value>,
+    pub source_position: FilePosition
+}
+
+/// Find an attribute by name.
+pub fn get_attribute<'a>(attributes: &'a [Attribute], name: &str) -> Option<&'a Attribute> {
+    return attributes.iter().find(|a| a.name == name);
+}
+
+/// Does a collection of attributes contain an attribute?
+pub fn has_attribute(attributes: &[Attribute], name: &str) -> bool {
+    return get_attribute(attributes, name).is_some();
+}
@@ -0,0 +1,492 @@
+//! Saha structural behaviors
+//!
+//! The `Equals`, `Hashable`, `Displayable` and `Cloneable` behaviors define
+//! how values are compared, hashed, displayed and copied. Primitive values have
+//! these built in, and classes can either implement the behaviors by hand or
+//! derive them with `@derive(...)`.
+
+use std::{
+    cell::RefCell,
+    collections::{HashMap, hash_map::DefaultHasher},
+    hash::{Hash, Hasher},
+    sync::{Arc, Mutex}
+};
+
+use crate::prelude::*;
+
+/// Behaviors which classes can derive with the `@derive(...)` attribute.
+pub const DERIVABLE_BEHAVIORS: [&str; 4] = ["Equals", "Hashable", "Displayable", "Cloneable"];
+
+/// Derived method name, parameters, return type and implementation.
+type DerivedMethod = (&'static str, Vec<FunctionParameter>, SahaType, fn(SahaFunctionArguments) -> SahaCallResult);
+
+thread_local! {
+    /// Instances copied by the deep clone in progress, mapped to their
+    /// copies. Nested clones share these, so an instance referenced many
+    /// times is copied once, and a reference cycle ends at the instance which
+    /// is already being copied.
+    static DEEP_CLONES: RefCell<Option<HashMap<InstRef, Value>>> = const { RefCell::new(None) };
+}
+
+/// Get the lockable instance for an object value.
+fn get_instance(obj: &Value, pos: &Option<FilePosition>) -> Result<Arc<Mutex<Box<dyn SahaObject>>>, RuntimeError> {
+    let st = crate::SAHA_SYMBOL_TABLE.lock().unwrap();
+
+    return match st.instances.get(&obj.obj.unwrap()) {
+        Some(inst) => Ok(inst.clone()),
+        None => Err(RuntimeError::new("Cannot access undefined instance", pos.clone()))
+    };
+}
+
+/// Does an object implement a behavior?
+pub fn implements_behavior(obj: &Value, behavior: &str) -> Result<bool, RuntimeError> {
+    let inst = get_instance(obj, &None)?;
+
+    let implements = inst.lock().unwrap().get_implements();
+
+    return Ok(implements.contains(&behavior.to_string()));
+}
+
+/// Call a public method on an object. User instance locks are released before
+/// the call is made, as the method body may need to access the instance.
+pub fn call_object_method(obj: &Value, method_name: &str, args: SahaFunctionArguments, pos: &Option<FilePosition>) -> SahaCallResult {
+    let instref = obj.obj.unwrap();
+    let inst_lockable = get_instance(obj, pos)?;
+    let method_ref: Arc<Box<dyn SahaCallable>>;
+
+    {
+        let mut inst = inst_lockable.lock().unwrap();
+
+        if inst.is_core_defined() {
+            let access = AccessParams {
+                member_name: method_name,
+                is_static_access: false,
+                access_file_pos: pos,
+                accessor_instref: &Some(instref)
+            };
+
+            return inst.call_member(access, args);
+        }
+
+        method_ref = match inst.get_method_ref(method_name) {
+            Ok(m) => m,
+            Err(e) => return Err(RuntimeError::new(&e.get_message(), pos.clone()))
+        };
+    }
+
+    let mut call_args = args;
+
+    call_args.insert("self".to_string(), obj.clone());
+
+    return method_ref.call(call_args, None, Vec::new(), pos.clone());
+}
+
+/// Compare two values structurally. Objects implementing `Equals` are compared
+/// with their `equals` method, other objects by identity.
+pub fn values_equal(a: &Value, b: &Value, pos: &Option<FilePosition>) -> Result<bool, RuntimeError> {
+    match (&*a.kind, &*b.kind) {
+        (SahaType::Obj, SahaType::Obj) => {
+            if a.obj == b.obj {
+                return Ok(true);
+            }
+
+            let a_class = get_instance(a, pos)?.lock().unwrap().get_named_type();
+            let b_class = get_instance(b, pos)?.lock().unwrap().get_named_type();
+
+            if a_class != b_class || !implements_behavior(a, "Equals")? {
+                return Ok(false);
+            }
+
+            let mut args: SahaFunctionArguments = HashMap::new();
+
+            args.insert("other".to_string(), b.clone());
+
+            let result = call_object_method(a, "equals", args, pos)?;
+
+            return Ok(result.bool.unwrap_or(false));
+        },
+        (SahaType::Tuple(..), SahaType::Tuple(..)) => items_equal(&a.tuple.clone().unwrap(), &b.tuple.clone().unwrap(), pos),
+        _ => Ok(a == b)
+    }
+}
+
+/// Compare two sequences of values item by item.
+pub fn items_equal(a_items: &[Value], b_items: &[Value], pos: &Option<FilePosition>) -> Result<bool, RuntimeError> {
+    if a_items.len() != b_items.len() {
+        return Ok(false);
+    }
+
+    for (a_item, b_item) in a_items.iter().zip(b_items.iter()) {
+        if !values_equal(a_item, b_item, pos)? {
+            return Ok(false);
+        }
+    }
+
+    return Ok(true);
+}
+
+/// Hash a value. Objects implementing `Hashable` are hashed with their `hash`
+/// method, other objects by identity.
+pub fn value_hash(value: &Value, pos: &Option<FilePosition>) -> Result<isize, RuntimeError> {
+    let mut hasher = DefaultHasher::new();
+
+    match *value.kind {
+        SahaType::Str => value.str.hash(&mut hasher),
+        SahaType::Int => value.int.hash(&mut hasher),
+        SahaType::Float => value.float.unwrap().raw().to_bits().hash(&mut hasher),
+        SahaType::Bool => value.bool.hash(&mut hasher),
+        SahaType::Tuple(..) => return items_hash(&value.tuple.clone().unwrap(), pos),
+        SahaType::Obj => {
+            if !implements_behavior(value, "Hashable")? {
+                value.obj.hash(&mut hasher);
+            } else {
+                let result = call_object_method(value, "hash", HashMap::new(), pos)?;
+
+                return Ok(result.int.unwrap());
+            }
+        },
+        _ => {}
+    };
+
+    return Ok(hasher.finish() as isize);
+}
+
+/// Hash a sequence of values, where the order of the values matters.
+pub fn items_hash(items: &[Value], pos: &Option<FilePosition>) -> Result<isize, RuntimeError> {
+    let mut hasher = DefaultHasher::new();
+
+    for item in items {
+        value_hash(item, pos)?.hash(&mut hasher);
+    }
+
+    return Ok(hasher.finish() as isize);
+}
+
+/// Get the display string of a value. Objects need to implement `Displayable`
+/// to be displayed.
+pub fn display_value(value: &Value, pos: &Option<FilePosition>) -> Result<String, RuntimeError> {
+    let display = match *value.kind {
+        SahaType::Str => value.str.clone().unwrap(),
+        SahaType::Int => value.int.unwrap().to_string(),
+        SahaType::Float => value.float.unwrap().to_string(),
+        SahaType::Bool => value.bool.unwrap().to_string(),
+        SahaType::Tuple(..) => {
+            let mut items: Vec<String> = Vec::new();
+
+            for item in value.tuple.clone().unwrap() {
+                items.push(display_nested_value(&item, pos)?);
+            }
+
+            format!("({})", items.join(", "))
+        },
+        SahaType::Obj => {
+            if !implements_behavior(value, "Displayable")? {
+                let class_name = get_instance(value, pos)?.lock().unwrap().get_fully_qualified_class_name();
+
+                let err = RuntimeError::new(
+                    &format!("Cannot display instance of class `{}`, it does not implement `Displayable`", class_name),
+                    pos.clone()
+                );
+
+                return Err(err);
+            }
+
+            let result = call_object_method(value, "display", HashMap::new(), pos)?;
+
+            result.str.unwrap()
+        },
+        _ => "void".to_string()
+    };
+
+    return Ok(display);
+}
+
+/// Copy a value. Objects implementing `Cloneable` are copied with their
+/// `clone` method, other objects are shared by reference even in a deep copy.
+/// A deep copy copies the values inside objects as well.
+pub fn clone_value(value: &Value, deep: bool, pos: &Option<FilePosition>) -> SahaCallResult {
+    if deep {
+        return run_deep_clone(|| copy_value(value, true, pos));
+    }
+
+    return copy_value(value, false, pos);
+}
+
+/// Run a deep clone. The instances copied are recorded until the outermost
+/// deep clone finishes, so that nested clones find the copies made before.
+pub fn run_deep_clone(clone: impl FnOnce() -> SahaCallResult) -> SahaCallResult {
+    let is_outermost = DEEP_CLONES.with(|clones| {
+        let mut clones = clones.borrow_mut();
+
+        if clones.is_some() {
+            return false;
+        }
+
+        *clones = Some(HashMap::new());
+
+        return true;
+    });
+
+    let result = clone();
+
+    if is_outermost {
+        DEEP_CLONES.with(|clones| *clones.borrow_mut() = None);
+    }
+
+    return result;
+}
+
+/// Record the copy made of an instance in the deep clone in progress.
+/// `clone` methods record the copy before cloning the values inside it, so
+/// that values referring back to the original get the copy instead.
+pub fn add_deep_clone(original: InstRef, copy: &Value) {
+    DEEP_CLONES.with(|clones| {
+        if let Some(clones) = clones.borrow_mut().as_mut() {
+            clones.insert(original, copy.clone());
+        }
+    });
+}
+
+/// Get the copy already made of an instance in the deep clone in progress.
+fn get_deep_clone(original: InstRef) -> Option<Value> {
+    return DEEP_CLONES.with(|clones| {
+        clones.borrow().as_ref().and_then(|clones| clones.get(&original).cloned())
+    });
+}
+
+/// Copy a value, see `clone_value`.
+fn copy_value(value: &Value, deep: bool, pos: &Option<FilePosition>) -> SahaCallResult {
+    match *value.kind {
+        SahaType::Tuple(ref item_types) => {
+            let mut items: Vec<Value> = Vec::new();
+
+            for item in value.tuple.clone().unwrap() {
+                items.push(copy_value(&item, deep, pos)?);
+            }
+
+            return Ok(Value::tuple(items, item_types.clone()));
+        },
+        SahaType::Obj => {
+            // the original may be locked while its own values are copied, so
+            // copies are looked up before touching the instance
+            if deep {
+                if let Some(copy) = get_deep_clone(value.obj.unwrap()) {
+                    return Ok(copy);
+                }
+            }
+
+            if !implements_behavior(value, "Cloneable")? {
+                return Ok(value.clone());
+            }
+
+            let mut args: SahaFunctionArguments = HashMap::new();
+
+            args.insert("deep".to_string(), Value::bool(deep));
+
+            let copy = call_object_method(value, "clone", args, pos)?;
+
+            if deep {
+                add_deep_clone(value.obj.unwrap(), &copy);
+            }
+
+            return Ok(copy);
+        },
+        _ => Ok(value.clone())
+    }
+}
+
+/// Display a value which is a part of another value, strings are quoted.
+pub fn display_nested_value(value: &Value, pos: &Option<FilePosition>) -> Result<String, RuntimeError> {
+    return match *value.kind {
+        SahaType::Str => Ok(format!("{:?}", value.str.clone().unwrap())),
+        _ => display_value(value, pos)
+    };
+}
+
+/// Can a non-object value be used where a structural behavior is expected?
+/// Primitives have all of them built in.
+pub fn is_primitive_behavior_value(behavior: &str, value: &Value) -> bool {
+    let is_primitive = matches!(*value.kind, SahaType::Str | SahaType::Int | SahaType::Float | SahaType::Bool);
+
+    return is_primitive && DERIVABLE_BEHAVIORS.contains(&behavior);
+}
+
+/// Get the non-static property values of an object, sorted by property name.
+pub fn get_property_values(obj: &Value) -> Result<Vec<(String, Value)>, RuntimeError> {
+    let inst_lockable = get_instance(obj, &None)?;
+    let fqname = inst_lockable.lock().unwrap().get_fully_qualified_class_name();
+
+    let mut prop_names: Vec<String> = {
+        let st = crate::SAHA_SYMBOL_TABLE.lock().unwrap();
+
+        match st.classes.get(&fqname) {
+            Some(c) => c.properties.values().filter(|p| !p.is_static).map(|p| p.name.clone()).collect(),
+            None => Vec::new()
+        }
+    };
+
+    prop_names.sort();
+
+    let inst = inst_lockable.lock().unwrap();
+
+    let mut values: Vec<(String, Value)> = Vec::new();
+
+    for name in prop_names {
+        let access = AccessParams {
+            member_name: &name,
+            is_static_access: false,
+            access_file_pos: &None,
+            accessor_instref: &obj.obj
+        };
+
+        let value = inst.access_property(access)?;
+
+        values.push((name, value));
+    }
+
+    return Ok(values);
+}
+
+/// Get a derived method implementation for a derivable behavior. The class
+/// type is used as the return type of methods returning `Self`.
+pub fn get_derived_method(behavior: &str, class_type: &SahaType) -> Option<CoreFunction> {
+    let (name, params, return_type, fn_ref): DerivedMethod = match behavior {
+        "Equals" => ("equals", vec![FunctionParameter {
+            name: "other".to_string(),
+            param_type: Box::new(SahaType::Name("Equals".to_string(), Vec::new())),
+            default: Value::void(),
+            position: 0,
+            is_variadic: false
+        }], SahaType::Bool, derived_equals),
+        "Hashable" => ("hash", Vec::new(), SahaType::Int, derived_hash),
+        "Displayable" => ("display", Vec::new(), SahaType::Str, derived_display),
+        "Cloneable" => ("clone", vec![FunctionParameter {
+            name: "deep".to_string(),
+            param_type: Box::new(SahaType::Bool),
+            default: Value::bool(false),
+            position: 0,
+            is_variadic: false
+        }], class_type.clone(), derived_clone),
+        _ => return None
+    };
+
+    return Some(CoreFunction {
+        name: name.to_string(),
+        params: params.into_iter().map(|p| (p.name.clone(), p)).collect(),
+        return_type: Box::new(return_type),
+        fn_ref: fn_ref,
+        is_public: true,
+        is_static: false
+    });
+}
+
+/// Derived `Equals::equals`, all properties must be equal.
+fn derived_equals(args: SahaFunctionArguments) -> SahaCallResult {
+    let this = &args["self"];
+    let other = &args["other"];
+
+    let this_type = get_instance(this, &None)?.lock().unwrap().get_named_type();
+    let other_type = get_instance(other, &None)?.lock().unwrap().get_named_type();
+
+    if this_type != other_type {
+        return Ok(Value::bool(false));
+    }
+
+    let this_values = get_property_values(this)?;
+    let other_values = get_property_values(other)?;
+
+    for ((_, this_value), (_, other_value)) in this_values.iter().zip(other_values.iter()) {
+        if !values_equal(this_value, other_value, &None)? {
+            return Ok(Value::bool(false));
+        }
+    }
+
+    return Ok(Value::bool(true));
+}
+
+/// Derived `Hashable::hash`, combines the hashes of all properties.
+fn derived_hash(args: SahaFunctionArguments) -> SahaCallResult {
+    let this = &args["self"];
+    let mut hasher = DefaultHasher::new();
+
+    get_instance(this, &None)?.lock().unwrap().get_fully_qualified_class_name().hash(&mut hasher);
+
+    for (name, value) in get_property_values(this)? {
+        name.hash(&mut hasher);
+        value_hash(&value, &None)?.hash(&mut hasher);
+    }
+
+    return Ok(Value::int(hasher.finish() as isize));
+}
+
+/// Derived `Displayable::display`, e.g. `Point(x = 1, y = 2)`.
+fn derived_display(args: SahaFunctionArguments) -> SahaCallResult {
+    let this = &args["self"];
+    let class_name = get_instance(this, &None)?.lock().unwrap().get_class_name();
+    let mut props: Vec<String> = Vec::new();
+
+    for (name, value) in get_property_values(this)? {
+        props.push(format!("{} = {}", name, display_nested_value(&value, &None)?));
+    }
+
+    return Ok(Value::str(format!("{}({})", class_name, props.join(", "))));
+}
+
+/// Derived `Cloneable::clone`, creates a new instance with the same property
+/// values without calling `init`. A deep clone clones the property values as
+/// well.
+fn derived_clone(args: SahaFunctionArguments) -> SahaCallResult {
+    let this = &args["self"];
+    let deep = args["deep"].bool.unwrap();
+
+    let (fqname, inst_type_params) = {
+        let inst_lockable = get_instance(this, &None)?;
+        let inst = inst_lockable.lock().unwrap();
+
+        (inst.get_fully_qualified_class_name(), inst.get_type_params())
+    };
+
+    let props: SahaFunctionArguments = get_property_values(this)?.into_iter().collect();
+
+    let (classdef, new_instref) = {
+        let st = crate::SAHA_SYMBOL_TABLE.lock().unwrap();
+
+        (st.classes[&fqname].clone(), st.create_instref())
+    };
+
+    // type params are given in the order the class declares them
+    let type_params: Vec<Box<SahaType>> = classdef.type_params.iter().map(|(c, _)| {
+        inst_type_params.iter().find(|(ic, _)| ic == c).map(|(_, t)| t.clone()).unwrap()
+    }).collect();
+
+    let add_copy = |props: SahaFunctionArguments| -> SahaCallResult {
+        // the symbol table is locked again when validating object property values
+        let new_inst = classdef.create_new_instance(new_instref, props, &type_params, &None)?;
+
+        let mut st = crate::SAHA_SYMBOL_TABLE.lock().unwrap();
+
+        st.instances.insert(new_instref, Arc::new(Mutex::new(new_inst)));
+
+        return Ok(Value::obj(new_instref));
+    };
+
+    if !deep {
+        return add_copy(props);
+    }
+
+    return run_deep_clone(|| {
+        // the copy exists before its property values are cloned, references
+        // back to this instance get the copy, it is replaced once done
+        let copy = add_copy(props.clone())?;
+
+        add_deep_clone(this.obj.unwrap(), &copy);
+
+        let mut cloned_props: SahaFunctionArguments = HashMap::new();
+
+        for (name, value) in props {
+            cloned_props.insert(name, clone_value(&value, true, &None)?);
+        }
+
+        return add_copy(cloned_props);
+    });
+}
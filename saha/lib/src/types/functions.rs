@@ -2,12 +2,17 @@
 
 use std::collections::HashMap;
 use std::any::Any;
+use std::sync::{Arc, Mutex};
+
+use indexmap::IndexMap;
 
 use crate::{
     ast::Ast,
     types::{
         Value, SahaType,
-        objects::MemberVisibility
+        objects::MemberVisibility,
+        behaviors::is_primitive_behavior_value,
+        attributes::Attribute
     },
     errors::{Error, RuntimeError},
     source::files::FilePosition,
@@ -18,27 +23,134 @@ use crate::{
 /// or a RuntimeError.
 pub type SahaCallResult = Result<Value, RuntimeError>;
 
-/// Collection of Saha function parameter definitions.
-pub type SahaFunctionParamDefs = HashMap<String, FunctionParameter>;
+/// Collection of Saha function parameter definitions, in declaration order.
+/// Positional arguments are bound to parameters in this order.
+pub type SahaFunctionParamDefs = IndexMap<String, FunctionParameter>;
 
 /// A function call argument collection. Names mapped to Saha Values.
 pub type SahaFunctionArguments = HashMap<String, Value>;
 
+/// Prefix for positional call argument names. Positional arguments are keyed
+/// with their index, e.g. `#0`, which cannot clash with parameter names.
+pub const POSITIONAL_ARG_PREFIX: &str = "#";
+
+/// Get the argument collection key for a positional argument.
+pub fn positional_arg_name(index: usize) -> String {
+    return format!("{}{}", POSITIONAL_ARG_PREFIX, index);
+}
+
 /// A single function parameter which Saha functions can accept.
 #[derive(Debug, Clone, PartialEq)]
 pub struct FunctionParameter {
     pub name: String,
     pub param_type: Box<SahaType>,
-    pub default: Value
+    pub default: Value,
+
+    /// Declaration index of the parameter, which matches its index in the
+    /// parameter definitions.
+    pub position: usize,
+
+    /// Variadic parameters, e.g. `...rest'List<int>`, collect any remaining
+    /// positional arguments into a list.
+    pub is_variadic: bool
+}
+
+impl FunctionParameter {
+    /// Get a readable string of the parameter as it would appear in source
+    /// code, e.g. `label'str = "n/a"`.
+    pub fn to_readable_string(&self) -> String {
+        if self.is_variadic {
+            return format!("...{}'{}", self.name, self.param_type.to_readable_string());
+        }
+
+        let default = match *self.default.kind {
+            SahaType::Str => format!("{:?}", self.default.str.clone().unwrap()),
+            SahaType::Int => self.default.int.unwrap().to_string(),
+            SahaType::Float => self.default.float.unwrap().to_string(),
+            SahaType::Bool => self.default.bool.unwrap().to_string(),
+            _ => return format!("{}'{}", self.name, self.param_type.to_readable_string())
+        };
+
+        return format!("{}'{} = {}", self.name, self.param_type.to_readable_string(), default);
+    }
+}
+
+/// Get a readable list of parameters for error messages.
+fn readable_param_list(params: &SahaFunctionParamDefs) -> String {
+    let param_strs: Vec<String> = params.values().map(|p| p.to_readable_string()).collect();
+
+    return param_strs.join(", ");
+}
+
+/// Does an argument match a parameter type? Objects are matched using their
+/// class names and the behaviors they implement.
+fn is_matching_arg_type(param_type: &SahaType, arg: &Value) -> bool {
+    if *param_type == SahaType::Any {
+        return true;
+    }
+
+    if *arg.kind != SahaType::Obj {
+        return match param_type {
+            SahaType::Name(n, tps) if tps.is_empty() && is_primitive_behavior_value(n, arg) => true,
+            _ => *param_type == *arg
+        };
+    }
+
+    let (wanted_name, wanted_tps) = match param_type {
+        SahaType::Name(n, tps) => (n, tps),
+        _ => return false
+    };
+
+    let inst_lockable = match crate::SAHA_SYMBOL_TABLE.lock().unwrap().instances.get(&arg.obj.unwrap()) {
+        Some(i) => i.clone(),
+        None => return false
+    };
+
+    let inst = inst_lockable.lock().unwrap();
+
+    if inst.get_implements().contains(wanted_name) {
+        return true;
+    }
+
+    return match *inst.get_named_type() {
+        SahaType::Name(ref n, ref tps) => n == wanted_name && tps == wanted_tps,
+        _ => false
+    };
+}
+
+/// Create a new `List` instance from variadic arguments.
+fn create_variadic_list(items: Vec<Value>, item_type: Box<SahaType>, call_pos: &Option<FilePosition>) -> Result<Value, RuntimeError> {
+    let mut item_args: SahaFunctionArguments = HashMap::new();
+
+    for (idx, item) in items.into_iter().enumerate() {
+        item_args.insert(format!("item_{}", idx), item);
+    }
+
+    let list_instref;
+    let new_inst_method_ref;
+
+    {
+        let st = crate::SAHA_SYMBOL_TABLE.lock().unwrap();
+
+        list_instref = st.create_instref();
+        new_inst_method_ref = st.core_classes["List"];
+    }
+
+    let list_instance = new_inst_method_ref(list_instref, &HashMap::new(), &[item_type], &item_args, call_pos.to_owned())?;
+
+    {
+        let mut st = crate::SAHA_SYMBOL_TABLE.lock().unwrap();
+        st.instances.insert(list_instref, Arc::new(Mutex::new(list_instance)));
+    }
+
+    return Ok(Value::obj(list_instref));
 }
 
 /// Anything that needs to validate call arguments.
 pub trait ValidatesArgs {
-    /// Validate a collection of function/method call arguments.
+    /// Validate a collection of function/method call arguments. Returns the
+    /// arguments with defaults filled in for missing optional arguments.
     fn validate_args(&self, args: &SahaFunctionArguments, call_pos: &Option<FilePosition>) -> Result<SahaFunctionArguments, RuntimeError>;
-
-    /// Validate args in case there is only a single parameter defined.
-    fn validate_single_param_args(&self, args: &SahaFunctionArguments, call_pos: &Option<FilePosition>) -> Result<SahaFunctionArguments, RuntimeError>;
 }
 
 /// Anything which can be called in Saha. Functions and methods mainly.
@@ -64,6 +176,9 @@ pub trait SahaCallable: Send + Sync {
     /// Is this a public member callable?
     fn is_public(&self) -> bool;
 
+    /// Get the attributes the callable was declared with.
+    fn get_attributes(&self) -> Vec<Attribute>;
+
     /// Clone for boxed self.
     fn box_clone(&self) -> Box<dyn SahaCallable>;
 
@@ -117,7 +232,9 @@ pub struct UserFunction {
     pub return_type: Box<SahaType>,
     pub ast: Ast,
     pub visibility: MemberVisibility,
-    pub is_static: bool
+    pub is_static: bool,
+    pub class_name: Option<String>,
+    pub attributes: Vec<Attribute>
 }
 
 impl SahaCallable for CoreFunction {
@@ -129,7 +246,18 @@ impl SahaCallable for CoreFunction {
             None => self.return_type.clone()
         };
 
-        let res = (self.fn_ref)(validated_args.clone())?;
+        // core functions do not know where they were called from, so errors
+        // without a position get the call position attached
+        let res = match (self.fn_ref)(validated_args.clone()) {
+            Ok(r) => r,
+            Err(e) => {
+                if e.get_file_position().is_some() || e.get_exit_code().is_some() {
+                    return Err(e);
+                }
+
+                return Err(RuntimeError::new(&e.get_message(), call_source_position));
+            }
+        };
 
         match *res.kind {
             SahaType::Obj => {
@@ -162,7 +290,9 @@ impl SahaCallable for CoreFunction {
                     inst_impl.push(inst.get_fully_qualified_class_name());
                 }
 
-                if inst_impl.contains(&wanted_name) == false || res.kind != ret_type {
+                // the returned value kind is always `Obj`, so only the class and
+                // behavior names can be compared here
+                if inst_impl.contains(&wanted_name) == false {
                     let err = RuntimeError::new(
                         &format!(
                             "Return type mismatch for `{}`, expected `{:?}` but received `{:?}`",
@@ -220,6 +350,10 @@ impl SahaCallable for CoreFunction {
         return self.is_static;
     }
 
+    fn get_attributes(&self) -> Vec<Attribute> {
+        return Vec::new();
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
@@ -238,8 +372,9 @@ impl SahaCallable for UserFunction {
             None => self.return_type.clone()
         };
 
-        // clone the args to miminize possibility of side effects
-        let mut ast_visitor = AstVisitor::new(&self.ast, validated_args.clone());
+        // validated args have defaults filled in for omitted optional
+        // arguments, clone the args to miminize possibility of side effects
+        let mut ast_visitor = AstVisitor::new(&self.ast, validated_args.clone(), self.class_name.clone());
 
         let res = ast_visitor.start()?;
 
@@ -339,6 +474,10 @@ impl SahaCallable for UserFunction {
         return self.is_static;
     }
 
+    fn get_attributes(&self) -> Vec<Attribute> {
+        return self.attributes.clone();
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
@@ -349,28 +488,46 @@ impl SahaCallable for UserFunction {
 }
 
 impl ValidatesArgs for SahaFunctionParamDefs {
-    /// Validate args in a situation where there is only a single parameter defined, which means
-    /// we can call the function with no parameter name defined (to make code a little leaner).
-    fn validate_single_param_args(&self, args: &SahaFunctionArguments, call_pos: &Option<FilePosition>) -> Result<SahaFunctionArguments, RuntimeError> {
-        let mut validation_args: SahaFunctionArguments = args.clone();
-        let mut validated_args = args.clone();
-
-        if validation_args.contains_key("self") {
-            // remove self or this single params validation will explode randomly as the arg order
-            // might be different on each call
-            validation_args.remove("self");
+    fn validate_args(&self, args: &SahaFunctionArguments, call_pos: &Option<FilePosition>) -> Result<SahaFunctionArguments, RuntimeError> {
+        let params: Vec<&FunctionParameter> = self.values().collect();
+        let mut named_args: SahaFunctionArguments = HashMap::new();
+        let mut positional_args: Vec<(usize, Value)> = Vec::new();
+
+        for (name, arg) in args {
+            match name.strip_prefix(POSITIONAL_ARG_PREFIX) {
+                Some(idx) => positional_args.push((idx.parse::<usize>().unwrap(), arg.clone())),
+                None => {
+                    named_args.insert(name.clone(), arg.clone());
+                }
+            };
         }
 
-        let param_name = self.keys().nth(0).unwrap();
-        let param = self.values().nth(0).unwrap();
-        let param_default = &param.default;
-        let param_type = param.param_type.clone();
+        positional_args.sort_by_key(|(idx, _)| *idx);
+
+        for name in named_args.keys() {
+            if name == "self" {
+                continue;
+            }
 
-        if let SahaType::Void = *param_default.kind {
-            if validation_args.is_empty() {
-                // no arg and no default, which is a no-no
+            let param = match self.get(name) {
+                Some(p) => p,
+                None => {
+                    let err = RuntimeError::new(
+                        &format!(
+                            "Invalid arguments, unknown argument `{}`, expected parameters `{}`",
+                            name,
+                            readable_param_list(self)
+                        ),
+                        call_pos.to_owned()
+                    );
+
+                    return Err(err);
+                }
+            };
+
+            if param.is_variadic {
                 let err = RuntimeError::new(
-                    &format!("Invalid arguments, argument `{}` missing", param_name),
+                    &format!("Invalid arguments, variadic argument `{}` cannot be given by name", name),
                     call_pos.to_owned()
                 );
 
@@ -378,63 +535,110 @@ impl ValidatesArgs for SahaFunctionParamDefs {
             }
         }
 
-        let arg = validation_args.values().nth(0).unwrap();
-
-        // arg type mismatch
-        if *param_type != *arg {
-            let err = RuntimeError::new(
-                &format!(
-                    "Invalid argument, `{}` is expected to be a `{}`, found `{}` instead",
-                    param_name,
-                    param_type.to_readable_string(),
-                    arg.kind.to_readable_string()
-                ),
-                call_pos.to_owned()
-            );
-
-            return Err(err);
-        }
+        let variadic_param = params.iter().find(|p| p.is_variadic);
+        let positional_count = positional_args.len();
+        let mut variadic_args: Vec<Value> = Vec::new();
+        let mut validated_args = named_args.clone();
+
+        // bind positional args to parameters in declaration order
+        for (idx, arg) in positional_args {
+            let param = match params.get(idx) {
+                Some(p) if !p.is_variadic => p,
+                _ => {
+                    if variadic_param.is_some() {
+                        variadic_args.push(arg);
+
+                        continue;
+                    }
+
+                    let err = RuntimeError::new(
+                        &format!(
+                            "Invalid arguments, expected at most {} positional arguments but received {}",
+                            params.len(),
+                            positional_count
+                        ),
+                        call_pos.to_owned()
+                    );
+
+                    return Err(err);
+                }
+            };
+
+            if validated_args.contains_key(&param.name) {
+                let err = RuntimeError::new(
+                    &format!("Invalid arguments, argument `{}` given both by position and by name", param.name),
+                    call_pos.to_owned()
+                );
 
-        // all OK for this arg, just replace the given arg with a name in case the
-        // given arg was an unnamed arg
-        if validation_args.contains_key("") {
-            validated_args.insert(param_name.clone(), validation_args.get("").unwrap().clone());
-            validated_args.remove("");
+                return Err(err);
+            }
+
+            validated_args.insert(param.name.clone(), arg);
         }
 
-        return Ok(validated_args.clone());
-    }
+        if let Some(param) = variadic_param {
+            let item_type = match *param.param_type {
+                SahaType::Name(_, ref tps) if tps.len() == 1 => tps[0].clone(),
+                _ => unreachable!()
+            };
 
-    fn validate_args(&self, args: &SahaFunctionArguments, call_pos: &Option<FilePosition>) -> Result<SahaFunctionArguments, RuntimeError> {
-        if (args.len() == 1 && args.contains_key("self") == false) || (args.len() == 2 && args.contains_key("self")) {
-            // if a function accepts only a single argument, we allow calling without setting a
-            // parameter name (will use `""` internally)
-            return self.validate_single_param_args(&args, call_pos);
+            for arg in &variadic_args {
+                if !is_matching_arg_type(&item_type, arg) {
+                    let err = RuntimeError::new(
+                        &format!(
+                            "Invalid argument, `{}` expects values of type `{}`, found `{}` instead",
+                            param.name,
+                            item_type.to_readable_string(),
+                            arg.kind.to_readable_string()
+                        ),
+                        call_pos.to_owned()
+                    );
+
+                    return Err(err);
+                }
+            }
+
+            let list = create_variadic_list(variadic_args, item_type, call_pos)?;
+
+            validated_args.insert(param.name.clone(), list);
         }
 
-        for (name, ref param) in self {
+        for param in params {
+            if param.is_variadic {
+                continue;
+            }
+
+            let name = &param.name;
             let param_type = param.param_type.clone();
             let param_default = param.default.to_owned();
 
             // arg missing, see if default is provided
-            if args.contains_key(name) == false {
+            if !validated_args.contains_key(name) {
                 match *param_default.kind {
                     SahaType::Void => {
                         let err = RuntimeError::new(
-                            &format!("Invalid arguments, argument `{}` missing", name),
+                            &format!(
+                                "Invalid arguments, argument `{}` missing, expected parameters `{}`",
+                                name,
+                                readable_param_list(self)
+                            ),
                             call_pos.to_owned()
                         );
 
                         return Err(err);
                     }
-                    _ => ()
+                    _ => {
+                        validated_args.insert(name.clone(), param_default);
+
+                        continue;
+                    }
                 };
             }
 
-            let arg = args.get(name).unwrap();
+            let arg = validated_args.get(name).unwrap();
 
             // arg type mismatch
-            if *param_type != *arg {
+            if !is_matching_arg_type(&param_type, arg) {
                 let err = RuntimeError::new(
                     &format!(
                         "Invalid argument, `{}` is expected to be a `{}`, found `{}` instead",
@@ -451,6 +655,6 @@ impl ValidatesArgs for SahaFunctionParamDefs {
             // all OK for this arg, continue loop
         }
 
-        return Ok(args.clone());
+        return Ok(validated_args);
     }
 }
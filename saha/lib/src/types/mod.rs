@@ -4,6 +4,8 @@
 
 pub mod objects;
 pub mod functions;
+pub mod behaviors;
+pub mod attributes;
 mod value_methods;
 
 use noisy_float::prelude::*;
@@ -21,13 +23,13 @@ use crate::prelude::*;
 
 use crate::{
     ast::AccessKind,
-    types::value_methods::ValueMethodFn
+    types::value_methods::ValueMethod
 };
 
 lazy_static! {
-    pub static ref str_methods: HashMap<String, (SahaFunctionParamDefs, ValueMethodFn)> = value_methods::get_str_methods();
-    pub static ref int_methods: HashMap<String, (SahaFunctionParamDefs, ValueMethodFn)> = value_methods::get_int_methods();
-    pub static ref float_methods: HashMap<String, (SahaFunctionParamDefs, ValueMethodFn)> = value_methods::get_float_methods();
+    pub static ref str_methods: HashMap<String, ValueMethod> = value_methods::get_str_methods();
+    pub static ref int_methods: HashMap<String, ValueMethod> = value_methods::get_int_methods();
+    pub static ref float_methods: HashMap<String, ValueMethod> = value_methods::get_float_methods();
 }
 
 /// UUID as bytes
@@ -60,8 +62,15 @@ pub enum SahaType {
     /// Type parameter, consisting of a single uppercase character.
     TypeParam(char),
 
+    /// Tuples, e.g. `(int, str)`. Contains the types of the tuple elements.
+    Tuple(Vec<Box<SahaType>>),
+
     /// Internal void type.
     Void,
+
+    /// Any value, for core function parameters which accept values of all
+    /// types. It cannot be written in Saha source code.
+    Any,
 }
 
 impl SahaType {
@@ -73,17 +82,54 @@ impl SahaType {
             SahaType::Int => "int".to_string(),
             SahaType::Float => "float".to_string(),
             SahaType::Name(n, tp) => {
-                if tp.len() == 0 {
+                if tp.is_empty() {
                     n.to_owned()
                 } else {
-                    format!("{}<{:?}>", n.to_owned(), tp)
+                    let param_strs: Vec<String> = tp.iter().map(|t| t.to_readable_string()).collect();
+
+                    format!("{}<{}>", n.to_owned(), param_strs.join(", "))
                 }
             },
             SahaType::Obj => "object".to_string(),
-            SahaType::TypeParam(c) => format!("Type param {}", c),
+            SahaType::TypeParam(c) => c.to_string(),
+            SahaType::Tuple(items) => {
+                let item_strs: Vec<String> = items.iter().map(|i| i.to_readable_string()).collect();
+
+                format!("({})", item_strs.join(", "))
+            },
+            SahaType::Any => "any".to_string(),
             _ => "void".to_string()
         }
     }
+
+    /// Get the type with type parameters replaced, including type parameters
+    /// nested in names and tuples. Unknown type parameters are kept as-is.
+    pub fn with_type_params(&self, type_params: &HashMap<char, Box<SahaType>>) -> SahaType {
+        return match self {
+            SahaType::TypeParam(c) => type_params.get(c).map(|t| *t.clone()).unwrap_or_else(|| self.clone()),
+            SahaType::Name(n, tps) => SahaType::Name(
+                n.clone(),
+                tps.iter().map(|t| Box::new(t.with_type_params(type_params))).collect()
+            ),
+            SahaType::Tuple(items) => SahaType::Tuple(
+                items.iter().map(|t| Box::new(t.with_type_params(type_params))).collect()
+            ),
+            _ => self.clone()
+        };
+    }
+
+    /// Get the return type of a method of a primitive value type, e.g.
+    /// `int->toString()`.
+    pub fn get_value_method_type(&self, method_name: &str) -> Option<SahaType> {
+        let valuemethods = match self {
+            SahaType::Int => &*int_methods,
+            SahaType::Str => &*str_methods,
+            SahaType::Float => &*float_methods,
+            _ => return None
+        };
+
+        return valuemethods.get(method_name).map(|(_, return_type, _)| return_type.clone());
+    }
 }
 
 impl From<Value> for SahaType {
@@ -114,6 +160,7 @@ pub struct Value {
     pub bool: Option<bool>,
     pub name: Option<String>,
     pub obj: Option<InstRef>,
+    pub tuple: Option<Vec<Value>>,
     pub void: ()
 }
 
@@ -129,6 +176,7 @@ impl PartialEq for Value {
             (SahaType::Float, SahaType::Float) => self.float.unwrap() == other.float.unwrap(),
             (SahaType::Obj, SahaType::Obj) => self.obj.unwrap() == other.obj.unwrap(),
             (SahaType::Void, SahaType::Void) => true,
+            (SahaType::Tuple(..), SahaType::Tuple(..)) => self.tuple == other.tuple,
             (SahaType::Name(ln, ltp), SahaType::Name(rn, rtp)) => {
                 // classname and type params must match
                 ln == rn && ltp == rtp
@@ -150,6 +198,7 @@ impl Default for Value {
             float: None,
             name: None,
             obj: None,
+            tuple: None,
             void: ()
         };
     }
@@ -161,6 +210,7 @@ impl Debug for Value {
 
         let value_str = match *k {
             SahaType::Void => "void".to_string(),
+            SahaType::Any => "any".to_string(),
             SahaType::Int => format!("{}", self.int.unwrap()),
             SahaType::Float => format!("{}", self.float.unwrap()),
             SahaType::Bool => format!("{}", self.bool.unwrap()),
@@ -173,7 +223,12 @@ impl Debug for Value {
                 }
             },
             SahaType::Obj => format!("{:?}", self.obj.unwrap()),
-            SahaType::TypeParam(c) => format!("TypeParam {}", c)
+            SahaType::TypeParam(c) => format!("TypeParam {}", c),
+            SahaType::Tuple(..) => {
+                let item_strs: Vec<String> = self.tuple.clone().unwrap().iter().map(|i| format!("{:?}", i)).collect();
+
+                format!("({})", item_strs.join(", "))
+            }
         };
 
         return write!(f, "Value::{:?}({})", self.kind, value_str);
@@ -246,6 +301,17 @@ impl Value {
         return v;
     }
 
+    /// Create a new tuple value. Item types are given separately, as object
+    /// items should be typed with their class names instead of plain `Obj`.
+    pub fn tuple(items: Vec<Value>, item_types: Vec<Box<SahaType>>) -> Value {
+        let mut v = Value::new();
+
+        v.kind = Box::new(SahaType::Tuple(item_types));
+        v.tuple = Some(items);
+
+        return v;
+    }
+
     /// Create a new void value.
     pub fn void() -> Value {
         return Value::new();
@@ -275,11 +341,11 @@ impl Value {
             return Err(err);
         }
 
-        let (mparams, mfn) = valuemethods.get(method_name).unwrap();
+        let (mparams, _, mfn) = valuemethods.get(method_name).unwrap();
 
-        mparams.validate_args(args, &Some(call_pos.clone()))?;
+        let validated_args = mparams.validate_args(args, &Some(call_pos.clone()))?;
 
-        return mfn(self.clone(), args.clone());
+        return mfn(self.clone(), validated_args);
     }
 }
 
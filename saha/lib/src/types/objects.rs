@@ -1,15 +1,38 @@
 //! Saha object types
 
 use std::{
+    any::Any,
     collections::HashMap,
     sync::Arc
 };
 
 use crate::prelude::*;
+use crate::{
+    ast::{Ast, Block},
+    interpreter::AstVisitor,
+    types::attributes::Attribute
+};
+
+/// Name of the optional class method which is called after a new instance has
+/// been created. A private `init` restricts newups to inside the class itself.
+pub const INIT_METHOD_NAME: &str = "init";
+
+/// Type name which behavior method signatures can use to refer to the type of
+/// the implementing class.
+pub const SELF_TYPE_NAME: &str = "Self";
+
+/// Implements name for core objects other than the core collections which can
+/// be looped over with `for`, such as lazily read file lines.
+pub const ITERABLE_IMPLEMENTS_NAME: &str = "Iterable";
 
 /// Helper type for core class constructors.
 pub type CoreConstructorFn = fn(instref: InstRef, args: &SahaFunctionArguments, param_types: &[Box<SahaType>], additional_data: &SahaFunctionArguments, create_pos: Option<FilePosition>) -> Result<Box<dyn SahaObject>, RuntimeError>;
 
+/// Helper type for resolving the return types of core class methods from the
+/// class type params, without an instance. Resolves to `None` for unknown
+/// methods and for methods whose return type depends on the arguments.
+pub type CoreMethodTypeFn = fn(param_types: &[Box<SahaType>], method_name: &str) -> Option<SahaType>;
+
 /// A helper struct for constructing object member access, either method or
 /// property.
 #[derive(Clone, Copy)]
@@ -74,6 +97,13 @@ pub trait SahaObject: Send {
     /// Load object data from an iterator. Only implemented for iterable type objects such as `List`
     /// and `Dict`.
     fn set_data_from_iter(&mut self, iterator: Box<Iterator<Item = (Value, Value)>>);
+
+    /// Get the object as `Any`, so core classes can read the data of other
+    /// core class instances by downcasting. `None` for objects which do not
+    /// expose their data.
+    fn as_any(&self) -> Option<&dyn Any> {
+        return None;
+    }
 }
 
 impl Clone for Box<dyn SahaObject> {
@@ -90,7 +120,8 @@ pub struct Property {
     pub default: Value,
     pub is_static: bool,
     pub visibility: MemberVisibility,
-    pub value: Option<Value>
+    pub value: Option<Value>,
+    pub attributes: Vec<Attribute>
 }
 
 impl Property {
@@ -205,10 +236,6 @@ impl ValidatesArgs for ObjProperties {
 
         return Ok(args.clone());
     }
-
-    fn validate_single_param_args(&self, args: &SahaFunctionArguments, _call_pos: &Option<FilePosition>) -> Result<SahaFunctionArguments, RuntimeError> {
-        return Ok(args.clone());
-    }
 }
 
 /// A class definition, or a blueprint in other words. From these actual
@@ -217,9 +244,11 @@ impl ValidatesArgs for ObjProperties {
 pub struct ClassDefinition {
     pub name: String,
     pub fqname: String,
+    pub visibility: MemberVisibility,
     pub properties: ObjProperties,
     pub implements: Vec<String>,
-    pub type_params: Vec<(char, Box<SahaType>)>
+    pub type_params: Vec<(char, Box<SahaType>)>,
+    pub attributes: Vec<Attribute>
 }
 
 impl ClassDefinition {
@@ -284,7 +313,11 @@ impl ClassDefinition {
             tyidx += 1;
         }
 
-        let parsed_properties = self.get_parsed_properties(&received_typarams, create_pos)?;
+        // static properties live in the symbol table, not in instances
+        let parsed_properties: ObjProperties = self.get_parsed_properties(&received_typarams, create_pos)?
+            .into_iter()
+            .filter(|(_, p)| !p.is_static)
+            .collect();
 
         parsed_properties.validate_args(&args, create_pos)?;
 
@@ -300,7 +333,8 @@ impl ClassDefinition {
                     default: p.default.clone(),
                     is_static: p.is_static,
                     visibility: p.visibility.clone(),
-                    value: Some(arg_val)
+                    value: Some(arg_val),
+                    attributes: p.attributes.clone()
                 };
 
                 inst_props.insert(pname.to_string(), arg_prop);
@@ -323,6 +357,20 @@ impl ClassDefinition {
     }
 }
 
+/// Create a new class instance the same way a newup expression outside of the
+/// class does, including calling the class `init` method. Used by core code
+/// which creates instances of user classes, e.g. JSON decoding.
+pub fn newup_instance(class_name: &str, args: SahaFunctionArguments, typeparams: &[Box<SahaType>], pos: &FilePosition) -> SahaCallResult {
+    let ast = Ast {
+        entrypoint: Box::new(Block {
+            file_position: pos.clone(),
+            statements: Vec::new()
+        })
+    };
+
+    return AstVisitor::new(&ast, HashMap::new(), None).newup_instance(class_name, args, typeparams, pos);
+}
+
 /// Behavior definition, name and the required methods (no actual callable, just
 /// expected params and expected return type).
 pub struct BehaviorDefinition {
@@ -332,9 +380,15 @@ pub struct BehaviorDefinition {
     /// Fully qualified behavior name.
     pub fqname: String,
 
+    /// Whether the behavior was declared with `pub` in its module.
+    pub visibility: MemberVisibility,
+
     /// Method collection. Keyed by method name, tuple contains param definitions for the method,
     /// and the return type for the method.
-    pub methods: HashMap<String, (SahaFunctionParamDefs, Box<SahaType>)>
+    pub methods: HashMap<String, (SahaFunctionParamDefs, Box<SahaType>)>,
+
+    /// Fully qualified names of the behaviors this behavior extends.
+    pub extends: Vec<String>
 }
 
 /// Class member visibility, e.g. public or private.
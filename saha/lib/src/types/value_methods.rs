@@ -8,30 +8,34 @@ use std::collections::HashMap;
 
 use crate::types::{
     Value,
+    SahaType,
     functions::{SahaFunctionParamDefs, SahaFunctionArguments, SahaCallResult}
 };
 
 pub type ValueMethodFn = fn(caller: Value, args: SahaFunctionArguments) -> SahaCallResult;
 
+/// Value method parameters, return type and implementation.
+pub type ValueMethod = (SahaFunctionParamDefs, SahaType, ValueMethodFn);
+
 /// Get value methods that are tied to `str` values.
-pub fn get_str_methods() -> HashMap<String, (SahaFunctionParamDefs, ValueMethodFn)> {
+pub fn get_str_methods() -> HashMap<String, ValueMethod> {
     return HashMap::new();
 }
 
 /// Get value methods that are tied to `int` values.
-pub fn get_int_methods() -> HashMap<String, (SahaFunctionParamDefs, ValueMethodFn)> {
-    let mut fns: HashMap<String, (SahaFunctionParamDefs, ValueMethodFn)> = HashMap::new();
+pub fn get_int_methods() -> HashMap<String, ValueMethod> {
+    let mut fns: HashMap<String, ValueMethod> = HashMap::new();
 
-    fns.insert("toString".to_string(), (HashMap::new(), int_to_string));
-    fns.insert("toFloat".to_string(), (HashMap::new(), int_to_float));
+    fns.insert("toString".to_string(), (SahaFunctionParamDefs::new(), SahaType::Str, int_to_string));
+    fns.insert("toFloat".to_string(), (SahaFunctionParamDefs::new(), SahaType::Float, int_to_float));
 
     return fns;
 }
 
-pub fn get_float_methods() -> HashMap<String, (SahaFunctionParamDefs, ValueMethodFn)> {
-    let mut fns: HashMap<String, (SahaFunctionParamDefs, ValueMethodFn)> = HashMap::new();
+pub fn get_float_methods() -> HashMap<String, ValueMethod> {
+    let mut fns: HashMap<String, ValueMethod> = HashMap::new();
 
-    fns.insert("toString".to_string(), (HashMap::new(), float_to_string));
+    fns.insert("toString".to_string(), (SahaFunctionParamDefs::new(), SahaType::Str, float_to_string));
 
     return fns;
 }
@@ -16,6 +16,7 @@ use saha_lib::{
 };
 
 use crate::{
+    parse_table::{TypeAliases, expand_type_aliases},
     parser::{
         TokenType,
         PR,
@@ -30,7 +31,8 @@ pub struct AstParser<'a> {
     ntok: Option<&'a Token>,
     tokidx: usize,
     shadow: &'a [Token],
-    tokens: Peekable<Iter<'a, Token>>
+    tokens: Peekable<Iter<'a, Token>>,
+    type_aliases: TypeAliases
 }
 
 impl<'a> ParsesTokens for AstParser<'a> {
@@ -114,10 +116,16 @@ impl<'a> AstParser<'a> {
             ntok: None,
             shadow: &tokens,
             tokidx: 0,
-            tokens: tokens.iter().peekable()
+            tokens: tokens.iter().peekable(),
+            type_aliases: TypeAliases::new()
         };
     }
 
+    /// Set the type aliases to expand when parsing type declarations.
+    pub fn set_type_aliases(&mut self, type_aliases: &TypeAliases) {
+        self.type_aliases = type_aliases.clone();
+    }
+
     /// Start AST parsing.
     pub fn start_parse(&mut self) -> PR<Ast> {
         {
@@ -215,9 +223,34 @@ impl<'a> AstParser<'a> {
 
     /// Parse a type declaration.
     fn parse_type_declaration(&mut self, parse_param_types: bool) -> PR<Box<SahaType>> {
-        self.consume_next(vec!["name", "typestring", "typeboolean", "typeinteger", "typefloat"])?;
+        self.consume_next(vec!["name", "typestring", "typeboolean", "typeinteger", "typefloat", "("])?;
+
+        let type_pos = self.ctok.unwrap().get_file_position();
 
         let typ = match self.ctok.unwrap() {
+            Token::ParensOpen(pos) => {
+                let mut item_types = Vec::new();
+
+                loop {
+                    item_types.push(self.parse_type_declaration(parse_param_types)?);
+
+                    match self.ntok.unwrap() {
+                        Token::Comma(..) => self.consume_next(vec![","])?,
+                        _ => break
+                    };
+                }
+
+                self.consume_next(vec![")"])?;
+
+                if item_types.len() < 2 {
+                    return Err(ParseError::new(
+                        "Tuple types require at least two element types",
+                        Some(pos.to_owned())
+                    ));
+                }
+
+                SahaType::Tuple(item_types)
+            },
             Token::TypeBoolean(..) => SahaType::Bool,
             Token::TypeString(..) => SahaType::Str,
             Token::TypeInteger(..) => SahaType::Int,
@@ -262,6 +295,15 @@ impl<'a> AstParser<'a> {
             _ => unreachable!()
         };
 
+        let typ = expand_type_aliases(&self.type_aliases, typ, &type_pos)?;
+
+        // `T?` is shorthand for `Option<T>`
+        if let Token::QuestionMark(..) = self.ntok.unwrap() {
+            self.consume_next(vec!["?"])?;
+
+            return Ok(Box::new(SahaType::Name("Option".to_string(), vec![Box::new(typ)])));
+        }
+
         return Ok(Box::new(typ));
     }
 
@@ -274,6 +316,10 @@ impl<'a> AstParser<'a> {
             _ => unreachable!()
         };
 
+        if let Token::ParensOpen(..) = self.ntok.unwrap() {
+            return self.parse_variable_destructuring_statement(statement_pos.to_owned());
+        }
+
         self.consume_next(vec!["name"])?;
 
         let (ident_pos, ident_val) = match self.ctok.unwrap() {
@@ -287,10 +333,21 @@ impl<'a> AstParser<'a> {
             type_params: Vec::new()
         };
 
-        // variable type
-        self.consume_next(vec!["'"])?;
+        // variable type, which is inferred from the value if omitted
+        self.consume_next(vec!["'", "="])?;
 
-        let var_type = self.parse_type_declaration(true)?;
+        if let Token::Assign(..) = self.ctok.unwrap() {
+            let value_expr: Box<Expression> = self.parse_expression(0)?;
+
+            let stmt = Statement {
+                file_position: statement_pos.to_owned(),
+                kind: StatementKind::VarDeclaration(identifier, None, Some(value_expr))
+            };
+
+            return Ok(Box::new(stmt));
+        }
+
+        let var_type = Some(self.parse_type_declaration(true)?);
 
         // if we don't have an assignment we return an uninited variable
         if let Token::EndStatement(..) = self.ntok.unwrap() {
@@ -314,6 +371,60 @@ impl<'a> AstParser<'a> {
         return Ok(Box::new(stmt));
     }
 
+    /// Parse a tuple destructuring variable declaration, the part after the
+    /// `var` keyword.
+    fn parse_variable_destructuring_statement(&mut self, statement_pos: FilePosition) -> PR<Box<Statement>> {
+        let identifiers = self.parse_tuple_binding()?;
+
+        self.consume_next(vec!["="])?;
+
+        let value_expr: Box<Expression> = self.parse_expression(0)?;
+
+        let stmt = Statement {
+            file_position: statement_pos,
+            kind: StatementKind::VarDestructuring(identifiers, value_expr)
+        };
+
+        return Ok(Box::new(stmt));
+    }
+
+    /// Parse a parenthesized list of names that tuple elements are bound to.
+    fn parse_tuple_binding(&mut self) -> PR<Vec<Identifier>> {
+        self.consume_next(vec!["("])?;
+
+        let binding_pos = self.ctok.unwrap().get_file_position();
+        let mut identifiers: Vec<Identifier> = Vec::new();
+
+        loop {
+            self.consume_next(vec!["name"])?;
+
+            identifiers.push(match self.ctok.unwrap() {
+                Token::Name(pos, _, n) => Identifier {
+                    file_position: pos.to_owned(),
+                    identifier: n.to_owned(),
+                    type_params: Vec::new()
+                },
+                _ => unreachable!()
+            });
+
+            match self.ntok.unwrap() {
+                Token::Comma(..) => self.consume_next(vec![","])?,
+                _ => break
+            };
+        }
+
+        self.consume_next(vec![")"])?;
+
+        if identifiers.len() < 2 {
+            return Err(ParseError::new(
+                "Tuple destructuring requires at least two names",
+                Some(binding_pos)
+            ));
+        }
+
+        return Ok(identifiers);
+    }
+
     /// Parse a statement which is a bare expression.
     fn parse_expression_statement(&mut self) -> PR<Box<Statement>> {
         let stmt = Statement {
@@ -453,17 +564,23 @@ impl<'a> AstParser<'a> {
         };
 
         self.consume_next(vec![","])?;
-        self.consume_next(vec!["name"])?;
 
-        let vname = match self.ctok.unwrap() {
-            Token::Name(_, _, n) => n.clone(),
-            _ => unreachable!()
-        };
+        let vbinding = match self.ntok.unwrap() {
+            Token::ParensOpen(..) => Binding::Tuple(self.parse_tuple_binding()?),
+            _ => {
+                self.consume_next(vec!["name"])?;
 
-        let vident = Identifier {
-            file_position: self.ctok.unwrap().get_file_position(),
-            identifier: vname,
-            type_params: Vec::new()
+                let vname = match self.ctok.unwrap() {
+                    Token::Name(_, _, n) => n.clone(),
+                    _ => unreachable!()
+                };
+
+                Binding::Name(Identifier {
+                    file_position: self.ctok.unwrap().get_file_position(),
+                    identifier: vname,
+                    type_params: Vec::new()
+                })
+            }
         };
 
         self.consume_next(vec!["in"])?;
@@ -475,7 +592,7 @@ impl<'a> AstParser<'a> {
         let for_block = self.parse_block(false)?;
 
         let stmt = Statement {
-            kind: StatementKind::For(kident, vident, iterable_expr, for_block.1),
+            kind: StatementKind::For(kident, vbinding, iterable_expr, for_block.1),
             file_position: for_pos
         };
 
@@ -489,13 +606,6 @@ impl<'a> AstParser<'a> {
         let ntok: &Token = self.ntok.unwrap_or(&Token::Eob);
         let next_precedence = ntok.get_precedence();
 
-        match ntok {
-            Token::ObjectAccess(..) | Token::StaticAccess(..) => {
-                return self.parse_generic_object_access(expression);
-            },
-            _ => {}
-        };
-
         if next_precedence < minimum_op_precedence {
             // non-operator or lesser precedence
             return Ok(expression);
@@ -513,13 +623,18 @@ impl<'a> AstParser<'a> {
             "name", "stringval", "integerval", "floatval", "booleanval"
         ])?;
 
-        let primary: Box<Expression> = match self.ctok.unwrap() {
+        let mut primary: Box<Expression> = match self.ctok.unwrap() {
             Token::ParensOpen(..) => {
                 let expr = self.parse_expression(0)?;
 
-                self.consume_next(vec![")"])?;
+                match self.ntok.unwrap() {
+                    Token::Comma(..) => self.parse_tuple_creation(expr)?,
+                    _ => {
+                        self.consume_next(vec![")"])?;
 
-                expr
+                        expr
+                    }
+                }
             },
             Token::BraceOpen(..) => self.parse_list_creation_shorthand()?,
             Token::CurlyOpen(..) => self.parse_dict_creation_shorthand()?,
@@ -543,9 +658,90 @@ impl<'a> AstParser<'a> {
             _ => unreachable!()
         };
 
+        // postfix accesses, tuple elements `.0` and members of the primary value
+        loop {
+            primary = match self.ntok.unwrap() {
+                Token::TupleIndex(pos, index) => {
+                    self.consume_next(vec!["tupleindex"])?;
+
+                    Box::new(Expression {
+                        file_position: pos.to_owned(),
+                        kind: ExpressionKind::TupleAccess(primary, *index)
+                    })
+                },
+                Token::ObjectAccess(..) | Token::StaticAccess(..) => self.parse_generic_object_access(primary)?,
+                Token::SafeObjectAccess(..) => self.parse_safe_access(primary)?,
+                _ => break
+            };
+        }
+
         return Ok(primary);
     }
 
+    /// Parse a safe navigation access, `option?->member`. The unwrapped option
+    /// value is bound to a name which cannot appear in source code, and the
+    /// rest of the access chain is resolved against it.
+    fn parse_safe_access(&mut self, option_expr: Box<Expression>) -> PR<Box<Expression>> {
+        self.consume_next(vec!["?->"])?;
+
+        let pos = self.ctok.unwrap().get_file_position();
+
+        self.consume_next(vec!["name"])?;
+
+        let binding = Identifier {
+            file_position: pos.clone(),
+            identifier: format!("?{}", self.tokidx),
+            type_params: Vec::new()
+        };
+
+        let member = self.parse_path_identifier()?;
+        let access_path = self.parse_ident_path_members(binding.clone(), vec![(AccessKind::Instance, member)])?;
+
+        let mut access_expr = match self.ntok.unwrap() {
+            Token::ParensOpen(..) => self.parse_function_call(access_path)?,
+            _ => access_path
+        };
+
+        // the rest of the chain short-circuits as well
+        while let Token::ObjectAccess(..) | Token::StaticAccess(..) = self.ntok.unwrap() {
+            access_expr = self.parse_generic_object_access(access_expr)?;
+        }
+
+        return Ok(Box::new(Expression {
+            file_position: pos,
+            kind: ExpressionKind::SafeAccess(option_expr, binding, access_expr)
+        }));
+    }
+
+    /// Parse a tuple creation expression, after its first item has been parsed.
+    fn parse_tuple_creation(&mut self, first_item: Box<Expression>) -> PR<Box<Expression>> {
+        let tuple_pos = first_item.file_position.clone();
+        let mut tuple_expr: Vec<Box<Expression>> = vec![first_item];
+
+        while let Token::Comma(..) = self.ntok.unwrap() {
+            self.consume_next(vec![","])?;
+
+            if let Token::ParensClose(..) = self.ntok.unwrap() {
+                // trailing comma
+                break;
+            }
+
+            tuple_expr.push(self.parse_expression(0)?);
+        }
+
+        self.consume_next(vec![")"])?;
+
+        // tuple types require two element types, so 1-tuples could not be typed
+        if tuple_expr.len() < 2 {
+            return Err(ParseError::new("Tuples require at least two items", Some(tuple_pos)));
+        }
+
+        return Ok(Box::new(Expression {
+            kind: ExpressionKind::TupleDeclaration(tuple_expr),
+            file_position: tuple_pos
+        }));
+    }
+
     /// Parse bracedelimited list creation expression.
     fn parse_list_creation_shorthand(&mut self) -> PR<Box<Expression>> {
         let list_pos = self.ctok.unwrap().get_file_position();
@@ -619,7 +815,7 @@ impl<'a> AstParser<'a> {
     }
 
     /// Parse a generic object access expression where some member of something is being
-    /// accessed.
+    /// accessed. The member is either a property or a method call.
     fn parse_generic_object_access(&mut self, lhs_expr: Box<Expression>) -> PR<Box<Expression>> {
         self.consume_next(vec!["->", "::"])?;
 
@@ -630,7 +826,20 @@ impl<'a> AstParser<'a> {
         };
 
         let epos = self.ctok.unwrap().get_file_position();
-        let rhs_expr = self.parse_expression(0)?;
+
+        self.consume_next(vec!["name"])?;
+
+        let member = self.parse_path_identifier()?;
+
+        let member_path = Box::new(Expression {
+            file_position: member.file_position.clone(),
+            kind: ExpressionKind::IdentPath(member, Vec::new())
+        });
+
+        let rhs_expr = match self.ntok.unwrap() {
+            Token::ParensOpen(..) => self.parse_function_call(member_path)?,
+            _ => member_path
+        };
 
         let expr = Expression {
             file_position: epos,
@@ -643,7 +852,7 @@ impl<'a> AstParser<'a> {
     /// Parse a binary operation. First we parse the op and then the RHS
     /// expression. Then we check if we should parse another binop.
     fn parse_binop_expression(&mut self, lhs_expr: Box<Expression>) -> PR<Box<Expression>> {
-        self.consume_next(vec!["+", "-", "*", "/", "&&", "||", "==", ">", "<", ">=", "<="])?;
+        self.consume_next(vec!["+", "-", "*", "/", "&&", "||", "==", "!=", ">", "<", ">=", "<=", "??"])?;
 
         let op_token = self.ctok.unwrap();
 
@@ -727,10 +936,16 @@ impl<'a> AstParser<'a> {
 
     /// Parse a path of identifiers separated by `->` or `::`.
     fn parse_ident_path(&mut self) -> PR<Box<Expression>> {
+        let root = self.parse_path_identifier()?;
+
+        return self.parse_ident_path_members(root, Vec::new());
+    }
+
+    /// Parse the current name token as an identifier in an identifier path.
+    fn parse_path_identifier(&mut self) -> PR<Identifier> {
         let curtok = self.ctok.unwrap();
-        let mut path_items: Vec<(AccessKind, Identifier)> = Vec::new();
 
-        let root: Identifier = match curtok {
+        return match curtok {
             Token::Name(pos, alias, _) => {
                 let typeparams: Vec<Box<SahaType>>;
 
@@ -740,17 +955,20 @@ impl<'a> AstParser<'a> {
                     typeparams = Vec::new();
                 }
 
-                Identifier {
+                Ok(Identifier {
                     file_position: pos.clone(),
                     identifier: alias.to_string(),
                     type_params: typeparams
-                }
+                })
             },
-            _ => return Err(ParseError::new(
+            _ => Err(ParseError::new(
                 &format!("Unexpected `{}`, expected name", curtok), Some(curtok.get_file_position())
             ))
         };
+    }
 
+    /// Parse the `->` or `::` separated members of an identifier path.
+    fn parse_ident_path_members(&mut self, root: Identifier, mut path_items: Vec<(AccessKind, Identifier)>) -> PR<Box<Expression>> {
         let mut next_is_access_token: bool = match self.ntok.unwrap() {
             Token::StaticAccess(..) | Token::ObjectAccess(..) => true,
             _ => false
@@ -767,24 +985,7 @@ impl<'a> AstParser<'a> {
 
             self.consume_next(vec!["name"])?;
 
-            let item_ident: Identifier = match self.ctok.unwrap() {
-                Token::Name(pos, alias, _) => {
-                    let typeparams: Vec<Box<SahaType>>;
-
-                    if let Token::OpLt(..) = self.ntok.unwrap() {
-                        typeparams = self.parse_new_instance_type_params()?;
-                    } else {
-                        typeparams = Vec::new();
-                    }
-
-                    Identifier {
-                        file_position: pos.clone(),
-                        identifier: alias.to_string(),
-                        type_params: typeparams
-                    }
-                },
-                _ => unreachable!()
-            };
+            let item_ident: Identifier = self.parse_path_identifier()?;
 
             path_items.push((access_kind, item_ident));
 
@@ -808,7 +1009,6 @@ impl<'a> AstParser<'a> {
 
         let call_pos = self.ctok.unwrap().get_file_position();
 
-        // FIXME allow single parameter functions to leave out the parameter name
         let call_args: Box<Expression> = self.parse_callable_args(true)?;
 
         self.consume_next(vec![")"])?;
@@ -822,9 +1022,11 @@ impl<'a> AstParser<'a> {
     }
 
     /// Parse function call arguments that are wrapped in parentheses. Also used
-    /// for new instance args.
-    fn parse_callable_args(&mut self, allow_unnamed_single_param: bool) -> PR<Box<Expression>> {
+    /// for new instance args, which do not allow positional arguments.
+    /// Positional arguments must come before named arguments.
+    fn parse_callable_args(&mut self, allow_positional: bool) -> PR<Box<Expression>> {
         let mut args: Vec<Box<Expression>> = Vec::new();
+        let mut arg_names: Vec<String> = Vec::new();
         let args_pos = self.ctok.unwrap().get_file_position();
 
         loop {
@@ -840,13 +1042,35 @@ impl<'a> AstParser<'a> {
                 _ => {
                     let (is_named_arg, arg_expr) = self.parse_callable_arg()?;
 
-                    args.push(arg_expr);
+                    let arg_name = match &arg_expr.kind {
+                        ExpressionKind::CallableArg(ident, _) => ident.identifier.clone(),
+                        _ => unreachable!()
+                    };
+
+                    if is_named_arg {
+                        if arg_names.contains(&arg_name) {
+                            return Err(ParseError::new(
+                                &format!("Duplicate argument `{}`", arg_name),
+                                Some(arg_expr.file_position)
+                            ));
+                        }
 
-                    if allow_unnamed_single_param && !is_named_arg {
-                        break
-                    } else {
-                        continue
+                        arg_names.push(arg_name);
+                    } else if !allow_positional {
+                        return Err(ParseError::new(
+                            "Positional arguments are not allowed here, arguments must be named",
+                            Some(arg_expr.file_position)
+                        ));
+                    } else if !arg_names.is_empty() {
+                        return Err(ParseError::new(
+                            "Positional arguments cannot follow named arguments",
+                            Some(arg_expr.file_position)
+                        ));
                     }
+
+                    args.push(arg_expr);
+
+                    continue
                 }
             }
         };
@@ -921,7 +1145,7 @@ impl<'a> AstParser<'a> {
             _ => unreachable!()
         };
 
-        let typeparams: Vec<Box<SahaType>>;
+        let mut typeparams: Vec<Box<SahaType>>;
 
         if let Token::OpLt(..) = self.ntok.unwrap() {
             typeparams = self.parse_new_instance_type_params()?;
@@ -929,6 +1153,26 @@ impl<'a> AstParser<'a> {
             typeparams = Vec::new();
         }
 
+        let mut cname = cname.clone();
+
+        // newing up a type alias instantiates the class it refers to
+        if let Some(alias) = self.type_aliases.get(&cname) {
+            let alias_name = alias.source_name.clone();
+
+            match expand_type_aliases(&self.type_aliases, SahaType::Name(cname.clone(), typeparams), cname_pos)? {
+                SahaType::Name(n, tps) => {
+                    cname = n;
+                    typeparams = tps;
+                },
+                _ => {
+                    return Err(ParseError::new(
+                        &format!("Type alias `{}` does not refer to a class and cannot be instantiated", alias_name),
+                        Some(cname_pos.to_owned())
+                    ));
+                }
+            };
+        }
+
         self.consume_next(vec!["("])?;
 
         let newup_args = self.parse_callable_args(false)?;
@@ -940,7 +1184,7 @@ impl<'a> AstParser<'a> {
             kind: ExpressionKind::NewInstance(
                 Identifier {
                     file_position: cname_pos.clone(),
-                    identifier: cname.clone(),
+                    identifier: cname,
                     type_params: Vec::new()
                 },
                 newup_args,
@@ -997,6 +1241,7 @@ impl<'a> AstParser<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::parse_table::TypeAliasDefinition;
 
     fn testfilepos() -> FilePosition {
         return FilePosition::unknown();
@@ -1059,7 +1304,7 @@ mod tests {
                     type_params: Vec::new()
                 }, ident.to_owned());
 
-                assert_eq!(Box::new(SahaType::Str), vartype.to_owned());
+                assert_eq!(Some(Box::new(SahaType::Str)), vartype.to_owned());
 
                 assert_eq!(Box::new(Expression {
                     file_position: testfilepos(),
@@ -1186,4 +1431,358 @@ mod tests {
             _ => panic!("Unexpected statement kind, expected an expression statement")
         };
     }
+
+    #[test]
+    fn test_tuples_are_parsed() {
+        let tokens = vec![
+            Token::KwVar(testfilepos()),
+            Token::ParensOpen(testfilepos()),
+            Token::Name(testfilepos(), "a".to_string(), "a".to_string()),
+            Token::Comma(testfilepos()),
+            Token::Name(testfilepos(), "b".to_string(), "b".to_string()),
+            Token::ParensClose(testfilepos()),
+            Token::Assign(testfilepos()),
+            Token::ParensOpen(testfilepos()),
+            Token::IntegerValue(testfilepos(), 1),
+            Token::Comma(testfilepos()),
+            Token::ParensOpen(testfilepos()),
+            Token::StringValue(testfilepos(), "x".to_string()),
+            Token::Comma(testfilepos()),
+            Token::BooleanValue(testfilepos(), true),
+            Token::ParensClose(testfilepos()),
+            Token::ParensClose(testfilepos()),
+            Token::TupleIndex(testfilepos(), 1),
+            Token::EndStatement(testfilepos()),
+            Token::Eob
+        ];
+
+        // above is
+        // var (a, b) = (1, ("x", true)).1;
+
+        let mut parser = AstParser::new(&tokens);
+
+        let ast = parser.start_parse();
+
+        if ast.is_err() {
+            eprintln!("{:?}", ast.err().unwrap().get_message());
+            panic!();
+        }
+
+        let ast = ast.ok().unwrap();
+        let mut statements = ast.entrypoint.statements.clone();
+
+        assert_eq!(1, statements.len());
+
+        fn literal(value: Value) -> Box<Expression> {
+            return Box::new(Expression {
+                file_position: testfilepos(),
+                kind: ExpressionKind::LiteralValue(value)
+            });
+        }
+
+        fn ident(name: &str) -> Identifier {
+            return Identifier {
+                file_position: testfilepos(),
+                identifier: name.to_string(),
+                type_params: Vec::new()
+            };
+        }
+
+        let expected_expr = Box::new(Expression {
+            file_position: testfilepos(),
+            kind: ExpressionKind::TupleAccess(Box::new(Expression {
+                file_position: testfilepos(),
+                kind: ExpressionKind::TupleDeclaration(vec![
+                    literal(Value::int(1)),
+                    Box::new(Expression {
+                        file_position: testfilepos(),
+                        kind: ExpressionKind::TupleDeclaration(vec![
+                            literal(Value::str("x".to_string())),
+                            literal(Value::bool(true)),
+                        ])
+                    })
+                ])
+            }), 1)
+        });
+
+        match statements.pop().unwrap().kind {
+            StatementKind::VarDestructuring(idents, expr) => {
+                assert_eq!(vec![ident("a"), ident("b")], idents);
+                assert_eq!(expected_expr, expr);
+            },
+            _ => panic!("Unexpected statement kind, expected a destructuring statement")
+        };
+    }
+
+    #[test]
+    fn test_tuple_types_are_parsed() {
+        let tokens = vec![
+            Token::KwVar(testfilepos()),
+            Token::Name(testfilepos(), "t".to_string(), "t".to_string()),
+            Token::SingleQuote(testfilepos()),
+            Token::ParensOpen(testfilepos()),
+            Token::TypeInteger(testfilepos()),
+            Token::Comma(testfilepos()),
+            Token::Name(testfilepos(), "List".to_string(), "List".to_string()),
+            Token::OpLt(testfilepos()),
+            Token::TypeString(testfilepos()),
+            Token::OpGt(testfilepos()),
+            Token::ParensClose(testfilepos()),
+            Token::EndStatement(testfilepos()),
+            Token::Eob
+        ];
+
+        let mut parser = AstParser::new(&tokens);
+        let ast = parser.start_parse().ok().unwrap();
+
+        match &ast.entrypoint.statements[0].kind {
+            StatementKind::VarDeclaration(_, vartype, _) => {
+                assert_eq!(Some(Box::new(SahaType::Tuple(vec![
+                    Box::new(SahaType::Int),
+                    Box::new(SahaType::Name("List".to_string(), vec![Box::new(SahaType::Str)]))
+                ]))), vartype.to_owned());
+            },
+            _ => panic!("Unexpected statement kind, expected a variable declaration")
+        };
+    }
+
+    #[test]
+    fn test_single_item_tuples_are_rejected() {
+        let tokens = vec![
+            Token::KwVar(testfilepos()),
+            Token::Name(testfilepos(), "t".to_string(), "t".to_string()),
+            Token::Assign(testfilepos()),
+            Token::ParensOpen(testfilepos()),
+            Token::IntegerValue(testfilepos(), 1),
+            Token::Comma(testfilepos()),
+            Token::ParensClose(testfilepos()),
+            Token::EndStatement(testfilepos()),
+            Token::Eob
+        ];
+
+        // above is
+        // var t = (1,);
+
+        let err = AstParser::new(&tokens).start_parse().err().unwrap();
+
+        assert_eq!("Tuples require at least two items", err.get_message());
+    }
+
+    #[test]
+    fn test_variable_types_can_be_omitted_for_inference() {
+        let tokens = vec![
+            Token::KwVar(testfilepos()),
+            Token::Name(testfilepos(), "foo".to_string(), "foo".to_string()),
+            Token::Assign(testfilepos()),
+            Token::IntegerValue(testfilepos(), 1),
+            Token::EndStatement(testfilepos()),
+            Token::Eob
+        ];
+
+        let mut parser = AstParser::new(&tokens);
+        let ast = parser.start_parse().ok().unwrap();
+
+        match &ast.entrypoint.statements[0].kind {
+            StatementKind::VarDeclaration(_, vartype, value) => {
+                assert_eq!(None, vartype.to_owned());
+                assert!(value.is_some());
+            },
+            _ => panic!("Unexpected statement kind, expected a variable declaration")
+        };
+
+        let tokens = vec![
+            Token::KwVar(testfilepos()),
+            Token::Name(testfilepos(), "foo".to_string(), "foo".to_string()),
+            Token::EndStatement(testfilepos()),
+            Token::Eob
+        ];
+
+        assert!(AstParser::new(&tokens).start_parse().is_err());
+    }
+
+    #[test]
+    fn test_type_aliases_are_expanded() {
+        let tokens = vec![
+            Token::KwVar(testfilepos()),
+            Token::Name(testfilepos(), "t".to_string(), "t".to_string()),
+            Token::SingleQuote(testfilepos()),
+            Token::Name(testfilepos(), "pkg.Pair".to_string(), "Pair".to_string()),
+            Token::OpLt(testfilepos()),
+            Token::TypeInteger(testfilepos()),
+            Token::OpGt(testfilepos()),
+            Token::EndStatement(testfilepos()),
+            Token::Eob
+        ];
+
+        let mut aliases = TypeAliases::new();
+
+        aliases.insert("pkg.Pair".to_string(), TypeAliasDefinition {
+            source_name: "Pair".to_string(),
+            type_params: vec!['A'],
+            aliased_type: Box::new(SahaType::Tuple(vec![
+                Box::new(SahaType::TypeParam('A')),
+                Box::new(SahaType::Str)
+            ]))
+        });
+
+        let mut parser = AstParser::new(&tokens);
+
+        parser.set_type_aliases(&aliases);
+
+        let ast = parser.start_parse().ok().unwrap();
+
+        match &ast.entrypoint.statements[0].kind {
+            StatementKind::VarDeclaration(_, vartype, _) => {
+                assert_eq!(Some(Box::new(SahaType::Tuple(vec![
+                    Box::new(SahaType::Int),
+                    Box::new(SahaType::Str)
+                ]))), vartype.to_owned());
+            },
+            _ => panic!("Unexpected statement kind, expected a variable declaration")
+        };
+    }
+
+    #[test]
+    fn test_positional_args_must_precede_named_args() {
+        let call_tokens = |first: Vec<Token>, second: Vec<Token>| -> Vec<Token> {
+            let mut tokens = vec![
+                Token::Name(testfilepos(), "add".to_string(), "add".to_string()),
+                Token::ParensOpen(testfilepos()),
+            ];
+
+            tokens.extend(first);
+            tokens.push(Token::Comma(testfilepos()));
+            tokens.extend(second);
+            tokens.extend(vec![
+                Token::ParensClose(testfilepos()),
+                Token::EndStatement(testfilepos()),
+                Token::Eob
+            ]);
+
+            return tokens;
+        };
+
+        let positional = || vec![Token::IntegerValue(testfilepos(), 1)];
+        let named = |name: &str| vec![
+            Token::Name(testfilepos(), name.to_string(), name.to_string()),
+            Token::Assign(testfilepos()),
+            Token::IntegerValue(testfilepos(), 2),
+        ];
+
+        // add(1, b = 2);
+        let tokens = call_tokens(positional(), named("b"));
+        assert!(AstParser::new(&tokens).start_parse().is_ok());
+
+        // add(a = 2, 1);
+        let tokens = call_tokens(named("a"), positional());
+        let err = AstParser::new(&tokens).start_parse().err().unwrap();
+        assert!(err.get_message().contains("cannot follow named arguments"));
+
+        // add(a = 2, a = 2);
+        let tokens = call_tokens(named("a"), named("a"));
+        let err = AstParser::new(&tokens).start_parse().err().unwrap();
+        assert!(err.get_message().contains("Duplicate argument `a`"));
+    }
+
+    #[test]
+    fn test_optional_types_and_operators_are_parsed() {
+        let tokens = vec![
+            Token::KwVar(testfilepos()),
+            Token::Name(testfilepos(), "n".to_string(), "n".to_string()),
+            Token::SingleQuote(testfilepos()),
+            Token::TypeString(testfilepos()),
+            Token::QuestionMark(testfilepos()),
+            Token::Assign(testfilepos()),
+            Token::Name(testfilepos(), "user".to_string(), "user".to_string()),
+            Token::SafeObjectAccess(testfilepos()),
+            Token::Name(testfilepos(), "name".to_string(), "name".to_string()),
+            Token::OpCoalesce(testfilepos()),
+            Token::StringValue(testfilepos(), "nobody".to_string()),
+            Token::EndStatement(testfilepos()),
+            Token::Eob
+        ];
+
+        // above is
+        // var n'str? = user?->name ?? "nobody";
+
+        let mut parser = AstParser::new(&tokens);
+
+        let ast = parser.start_parse();
+
+        if ast.is_err() {
+            eprintln!("{:?}", ast.err().unwrap().get_message());
+            panic!();
+        }
+
+        let ast = ast.ok().unwrap();
+
+        let (vartype, value_expr) = match &ast.entrypoint.statements[0].kind {
+            StatementKind::VarDeclaration(_, vartype, Some(value_expr)) => (vartype.to_owned(), value_expr.to_owned()),
+            _ => panic!("Unexpected statement kind, expected a variable declaration")
+        };
+
+        assert_eq!(Some(Box::new(SahaType::Name("Option".to_string(), vec![Box::new(SahaType::Str)]))), vartype);
+
+        let (option_expr, op, fallback_expr) = match value_expr.kind {
+            ExpressionKind::BinaryOperation(lhs, op, rhs) => (lhs, op, rhs),
+            _ => panic!("Unexpected expression kind, expected a binary operation")
+        };
+
+        assert_eq!(BinOpKind::Coalesce, op.kind);
+        assert_eq!(ExpressionKind::LiteralValue(Value::str("nobody".to_string())), fallback_expr.kind);
+
+        match option_expr.kind {
+            ExpressionKind::SafeAccess(option, binding, access) => {
+                match (option.kind, access.kind) {
+                    (ExpressionKind::IdentPath(root, _), ExpressionKind::IdentPath(access_root, members)) => {
+                        assert_eq!("user", root.identifier);
+                        assert_eq!(binding.identifier, access_root.identifier);
+                        assert_eq!("name", members[0].1.identifier);
+                    },
+                    _ => panic!("Unexpected safe access structure")
+                };
+            },
+            _ => panic!("Unexpected expression kind, expected a safe access")
+        };
+    }
+
+    #[test]
+    fn test_not_equals_binop_is_parsed() {
+        let tokens = vec![
+            Token::KwVar(testfilepos()),
+            Token::Name(testfilepos(), "differs".to_string(), "differs".to_string()),
+            Token::SingleQuote(testfilepos()),
+            Token::TypeBoolean(testfilepos()),
+            Token::Assign(testfilepos()),
+            Token::Name(testfilepos(), "a".to_string(), "a".to_string()),
+            Token::OpNeq(testfilepos()),
+            Token::Name(testfilepos(), "b".to_string(), "b".to_string()),
+            Token::EndStatement(testfilepos()),
+            Token::Eob
+        ];
+
+        // above is
+        // var differs'bool = a != b;
+
+        let mut parser = AstParser::new(&tokens);
+
+        let ast = parser.start_parse();
+
+        if ast.is_err() {
+            eprintln!("{:?}", ast.err().unwrap().get_message());
+            panic!();
+        }
+
+        let ast = ast.ok().unwrap();
+
+        let value_expr = match &ast.entrypoint.statements[0].kind {
+            StatementKind::VarDeclaration(_, _, Some(value_expr)) => value_expr.to_owned(),
+            _ => panic!("Unexpected statement kind, expected a variable declaration")
+        };
+
+        match value_expr.kind {
+            ExpressionKind::BinaryOperation(_, op, _) => assert_eq!(BinOpKind::Neq, op.kind),
+            _ => panic!("Unexpected expression kind, expected a binary operation")
+        };
+    }
 }
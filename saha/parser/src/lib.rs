@@ -21,31 +21,37 @@ mod parser;
 mod root_parser;
 mod ast_parser;
 
-use std::collections::HashMap;
+use std::collections::{HashMap, hash_map::Entry};
 
 use saha_lib::{
     SAHA_SYMBOL_TABLE,
+    symbol_table::SymbolTable,
+    source::files::FilePosition,
     types::{
+        SahaType,
         functions::{SahaCallable, UserFunction},
-        objects::{MemberVisibility, BehaviorDefinition, ClassDefinition, Property, ObjProperties}
+        behaviors::get_derived_method,
+        objects::{MemberVisibility, BehaviorDefinition, ClassDefinition, Property, ObjProperties, SELF_TYPE_NAME}
     },
     errors::{Error, ParseError},
-    source::token::Token,
+    source::{token::Token, import::Import},
 };
 
 use crate::{
-    parse_table::{ParseTable, BehaviorDefinition as PTBehaviorDefinition, ClassDefinition as PTClassDefinition},
+    parse_table::{ParseTable, FunctionDefinition, BehaviorDefinition as PTBehaviorDefinition, ClassDefinition as PTClassDefinition, TypeAliases},
     ast_parser::AstParser,
     root_parser::RootParser
 };
 
-/// Populate parsed constants to the global symbol table.
+/// Populate parsed constants to the global symbol table, next to the core
+/// defined constants.
 fn populate_constants(parse_table: &ParseTable) -> Result<(), ParseError> {
     let constants = parse_table.constants.to_owned();
 
     let mut st = SAHA_SYMBOL_TABLE.lock().unwrap();
 
-    st.set_constants(constants);
+    st.add_constants(constants);
+    st.constant_visibilities.extend(parse_table.constant_visibilities.to_owned());
 
     return Ok(());
 }
@@ -59,6 +65,8 @@ fn populate_functions(parse_table: &ParseTable) -> Result<(), ParseError> {
     for (_, func) in funcs {
         let mut parser = AstParser::new(&func.body_tokens);
 
+        parser.set_type_aliases(&parse_table.type_aliases);
+
         let ast = parser.start_parse()?;
 
         let func = UserFunction {
@@ -67,8 +75,10 @@ fn populate_functions(parse_table: &ParseTable) -> Result<(), ParseError> {
             params: func.parameters,
             return_type: func.return_type,
             ast: ast,
-            visibility: MemberVisibility::Public,
-            is_static: false
+            visibility: func.visibility,
+            is_static: false,
+            class_name: None,
+            attributes: func.attributes
         };
 
         st.add_function(Box::new(func));
@@ -82,6 +92,17 @@ fn populate_behaviors(parse_table: &ParseTable) -> Result<(), ParseError> {
     let mut st = SAHA_SYMBOL_TABLE.lock().unwrap();
 
     for (behname, parsed_behavior) in &parse_table.behaviors {
+        for extended in &parsed_behavior.extends {
+            if !parse_table.behaviors.contains_key(extended) {
+                let err = ParseError::new(
+                    &format!("Behavior `{}` extends undefined behavior `{}`", behname, extended),
+                    Some(parsed_behavior.source_position.clone())
+                );
+
+                return Err(err);
+            }
+        }
+
         let mut behavior_methods: HashMap<String, (_, _)> = HashMap::new();
         let behavior_name = parsed_behavior.name.clone();
         let behavior_plain_name = parsed_behavior.source_name.clone();
@@ -95,7 +116,9 @@ fn populate_behaviors(parse_table: &ParseTable) -> Result<(), ParseError> {
         let behavior_def: BehaviorDefinition = BehaviorDefinition {
             name: behavior_plain_name,
             fqname: behavior_name,
-            methods: behavior_methods
+            visibility: parsed_behavior.visibility.clone(),
+            methods: behavior_methods,
+            extends: parsed_behavior.extends.clone()
         };
 
         st.behaviors.insert(behname.to_string(), behavior_def);
@@ -115,56 +138,127 @@ fn generate_class_properties(c: &PTClassDefinition) -> ObjProperties {
             default: pdef.default.clone(),
             is_static: pdef.is_static,
             visibility: pdef.visibility.clone(),
-            value: None
+            value: None,
+            attributes: pdef.attributes.clone()
         });
     }
 
     return props;
 }
 
-/// Generate methods for a class definition.
-fn generate_class_methods(c: &PTClassDefinition) -> Result<HashMap<String, Box<dyn SahaCallable>>, ParseError> {
+/// Generate a method callable for a class from a method definition.
+fn generate_class_method(c: &PTClassDefinition, fndef: &FunctionDefinition, type_aliases: &TypeAliases) -> Result<Box<dyn SahaCallable>, ParseError> {
+    let mut parser = AstParser::new(&fndef.body_tokens);
+
+    parser.set_type_aliases(type_aliases);
+
+    let ast = parser.start_parse()?;
+
+    let func = UserFunction {
+        source_name: fndef.source_name.clone(),
+        name: fndef.name.clone(),
+        params: fndef.parameters.clone(),
+        return_type: fndef.return_type.clone(),
+        ast: ast,
+        visibility: fndef.visibility.clone(),
+        is_static: fndef.is_static,
+        class_name: Some(c.name.clone()),
+        attributes: fndef.attributes.clone()
+    };
+
+    return Ok(Box::new(func));
+}
+
+/// Generate methods for a class definition. Default methods from implemented
+/// behaviors and derived methods are included for methods the class does not
+/// define itself.
+fn generate_class_methods(c: &PTClassDefinition, implements: &[String], beh_defs: &HashMap<String, PTBehaviorDefinition>, type_aliases: &TypeAliases) -> Result<HashMap<String, Box<dyn SahaCallable>>, ParseError> {
     let mut methods: HashMap<String, Box<dyn SahaCallable>> = HashMap::new();
 
     for fndef in c.methods.values() {
-        let mut parser = AstParser::new(&fndef.body_tokens);
-
-        let ast = parser.start_parse()?;
+        methods.insert(fndef.source_name.clone(), generate_class_method(c, fndef, type_aliases)?);
+    }
 
-        let func = UserFunction {
-            source_name: fndef.source_name.clone(),
-            name: fndef.name.clone(),
-            params: fndef.parameters.clone(),
-            return_type: fndef.return_type.clone(),
-            ast: ast,
-            visibility: fndef.visibility.clone(),
-            is_static: fndef.is_static
-        };
+    for (mname, fndef) in get_default_methods(implements, beh_defs) {
+        if let Entry::Vacant(entry) = methods.entry(mname) {
+            entry.insert(generate_class_method(c, fndef, type_aliases)?);
+        }
+    }
 
-        methods.insert(fndef.source_name.clone(), Box::new(func));
+    for derived in &c.derives() {
+        if let Some(method) = get_derived_method(derived, &get_class_type(c)) {
+            if let Entry::Vacant(entry) = methods.entry(method.name.clone()) {
+                entry.insert(Box::new(method));
+            }
+        }
     }
 
     return Ok(methods);
 }
 
-/// Check that classes implement their deifned behaviors correctly.
-fn validate_class_implements(c: &PTClassDefinition, beh_defs: &HashMap<String, PTBehaviorDefinition>) -> Result<(), ParseError> {
-    let c_impl = &c.implements;
+/// Get the default method implementations of behaviors. Behaviors listed
+/// earlier take precedence.
+fn get_default_methods<'a>(behaviors: &[String], beh_defs: &'a HashMap<String, PTBehaviorDefinition>) -> HashMap<String, &'a FunctionDefinition> {
+    let mut defaults: HashMap<String, &FunctionDefinition> = HashMap::new();
+
+    for b in behaviors {
+        for (mname, method) in &beh_defs[b].methods {
+            if !method.body_tokens.is_empty() && !defaults.contains_key(mname) {
+                defaults.insert(mname.clone(), method);
+            }
+        }
+    }
 
-    for i in c_impl {
-        if !beh_defs.contains_key(i) {
+    return defaults;
+}
+
+/// Get all behaviors a class implements, including derived behaviors and the
+/// behaviors extended by the implemented behaviors.
+fn get_class_behaviors(c: &PTClassDefinition, beh_defs: &HashMap<String, PTBehaviorDefinition>) -> Result<Vec<String>, ParseError> {
+    let mut behaviors: Vec<String> = Vec::new();
+    let mut pending: Vec<String> = c.implements.iter().cloned().chain(c.derives()).collect();
+
+    while !pending.is_empty() {
+        let b = pending.remove(0);
+
+        if behaviors.contains(&b) {
+            continue;
+        }
+
+        if !beh_defs.contains_key(&b) {
             let err = ParseError::new(
-                &format!("Invalid behavior implementation on `{}`, no behavior `{}` defined", c.name, i),
+                &format!("Invalid behavior implementation on `{}`, no behavior `{}` defined", c.name, b),
                 Some(c.source_position.clone())
             );
 
             return Err(err);
         }
 
+        pending.extend(beh_defs[&b].extends.iter().cloned());
+        behaviors.push(b);
+    }
+
+    return Ok(behaviors);
+}
+
+/// Check that classes implement their deifned behaviors correctly.
+fn validate_class_implements(c: &PTClassDefinition, implements: &[String], beh_defs: &HashMap<String, PTBehaviorDefinition>) -> Result<(), ParseError> {
+    let defaults = get_default_methods(implements, beh_defs);
+    let derives = c.derives();
+
+    let self_type = Box::new(get_class_type(c));
+
+    for i in implements {
         let cbeh = beh_defs.get(i).unwrap();
 
         for (mname, method) in &cbeh.methods {
+            let method = &with_self_type(method, &self_type);
+
             if !c.methods.contains_key(mname) {
+                if defaults.contains_key(mname) || derives.contains(i) {
+                    continue;
+                }
+
                 let err = ParseError::new(
                     &format!("Invalid behavior implementation on `{}`, method `{}` defined in behavior `{}` not found in class", c.name, mname, cbeh.name),
                     Some(c.source_position.clone())
@@ -189,25 +283,99 @@ fn validate_class_implements(c: &PTClassDefinition, beh_defs: &HashMap<String, P
     return Ok(());
 }
 
+/// Get the type of a class, with its type parameters unresolved.
+fn get_class_type(c: &PTClassDefinition) -> SahaType {
+    return SahaType::Name(
+        c.name.clone(),
+        c.type_params.iter().map(|(tp, _)| Box::new(SahaType::TypeParam(*tp))).collect()
+    );
+}
+
+/// Replace the `Self` type in a behavior method signature with the type of
+/// the implementing class.
+fn with_self_type(method: &FunctionDefinition, self_type: &SahaType) -> FunctionDefinition {
+    let is_self = |t: &SahaType| *t == SahaType::Name(SELF_TYPE_NAME.to_string(), Vec::new());
+
+    let mut new_method = method.clone();
+
+    for p in new_method.parameters.values_mut() {
+        if is_self(&p.param_type) {
+            *p.param_type = self_type.clone();
+        }
+    }
+
+    if is_self(&new_method.return_type) {
+        *new_method.return_type = self_type.clone();
+    }
+
+    return new_method;
+}
+
+/// Get behaviors registered by the core, e.g. operator behaviors, as parse
+/// table definitions so classes can implement them.
+fn get_core_behaviors(st: &SymbolTable, parse_table: &ParseTable) -> HashMap<String, PTBehaviorDefinition> {
+    let mut core_behaviors: HashMap<String, PTBehaviorDefinition> = HashMap::new();
+
+    for (behname, b) in &st.behaviors {
+        if parse_table.behaviors.contains_key(behname) {
+            continue;
+        }
+
+        let methods = b.methods.iter().map(|(mname, (params, return_type))| {
+            (mname.clone(), FunctionDefinition {
+                name: mname.clone(),
+                source_name: mname.clone(),
+                source_position: FilePosition::unknown(),
+                return_type: return_type.clone(),
+                body_tokens: Vec::new(),
+                visibility: MemberVisibility::Public,
+                is_static: false,
+                parameters: params.clone(),
+                attributes: Vec::new()
+            })
+        }).collect();
+
+        core_behaviors.insert(behname.clone(), PTBehaviorDefinition {
+            name: b.fqname.clone(),
+            source_name: b.name.clone(),
+            source_position: FilePosition::unknown(),
+            visibility: b.visibility.clone(),
+            methods: methods,
+            extends: b.extends.clone()
+        });
+    }
+
+    return core_behaviors;
+}
+
 /// Populate parsed class definitions to the global symbol table.
 fn populate_classes(parse_table: &ParseTable) -> Result<(), ParseError> {
     let classes = parse_table.classes.clone();
-    let behaviors = &parse_table.behaviors;
 
     let mut st = SAHA_SYMBOL_TABLE.lock().unwrap();
 
+    let mut behaviors = get_core_behaviors(&st, parse_table);
+
+    behaviors.extend(parse_table.behaviors.clone());
+
+    let behaviors = &behaviors;
+
     for (cname, c) in classes {
-        validate_class_implements(&c, behaviors)?;
+        let implements: Vec<String> = get_class_behaviors(&c, behaviors)?;
 
-        let methods: HashMap<String, Box<dyn SahaCallable>> = generate_class_methods(&c)?;
+        validate_class_implements(&c, &implements, behaviors)?;
+
+        let methods: HashMap<String, Box<dyn SahaCallable>> = generate_class_methods(&c, &implements, behaviors, &parse_table.type_aliases)?;
         let props: ObjProperties = generate_class_properties(&c);
 
         let cdef = ClassDefinition {
             name: c.source_name.clone(),
             fqname: c.name.clone(),
+            visibility: c.visibility.clone(),
             properties: props,
-            implements: c.implements.clone(),
-            type_params: c.type_params
+            implements: implements,
+            type_params: c.type_params,
+            attributes: c.attributes
         };
 
         st.classes.insert(cname.clone(), cdef);
@@ -215,6 +383,12 @@ fn populate_classes(parse_table: &ParseTable) -> Result<(), ParseError> {
         for m in methods.values() {
             st.add_method(&cname, &m);
         }
+
+        for p in st.classes[&cname].properties.clone().values() {
+            if p.is_static {
+                st.add_static_property(&cname, p);
+            }
+        }
     }
 
     return Ok(());
@@ -231,12 +405,53 @@ fn populate_global_symbol_table(parse_table: &ParseTable) -> Result<(), ParseErr
     return Ok(());
 }
 
+/// Resolve names imported from the standard library with `use std.x.Y;` to
+/// the names under which the stdlib members are registered in the symbol
+/// table.
+fn resolve_std_names(tokens: &[Token]) -> Result<Vec<Token>, ParseError> {
+    let std_members = {
+        let st = SAHA_SYMBOL_TABLE.lock().unwrap();
+
+        st.std_members.clone()
+    };
+
+    let unknown_member = |name: &str, pos: &FilePosition| {
+        return ParseError::new(&format!("Unknown standard library member `{}`", name), Some(pos.to_owned()));
+    };
+
+    let mut resolved: Vec<Token> = Vec::new();
+
+    for token in tokens {
+        match token {
+            Token::Import(pos, Import::Std(member, _)) => {
+                if !std_members.contains_key(member) {
+                    return Err(unknown_member(member, pos));
+                }
+
+                resolved.push(token.to_owned());
+            },
+            Token::Name(pos, alias, source) if alias.starts_with("std.") => {
+                let registered_name = match std_members.get(alias) {
+                    Some(name) => name,
+                    None => return Err(unknown_member(alias, pos))
+                };
+
+                resolved.push(Token::Name(pos.to_owned(), registered_name.to_owned(), source.to_owned()));
+            },
+            _ => resolved.push(token.to_owned())
+        }
+    }
+
+    return Ok(resolved);
+}
+
 /// Parse a collection of tokens into a declaration table and ASTs.
 pub fn parse_tokens(tokens: &[Token]) -> Result<(), ParseError> {
     let mut parse_table = ParseTable::new();
+    let tokens = resolve_std_names(tokens)?;
 
     {
-        let mut root_parser = RootParser::new(tokens, &mut parse_table);
+        let mut root_parser = RootParser::new(&tokens, &mut parse_table);
 
         root_parser.start_parse()?;
     }
@@ -7,6 +7,7 @@ use std::collections::HashMap;
 
 use saha_lib::prelude::*;
 use saha_lib::source::token::Token;
+use saha_lib::types::attributes::{Attribute, DERIVE_ATTRIBUTE, get_attribute};
 
 /// Function defintion, from which a callable can be built from.
 #[derive(Clone, Debug)]
@@ -18,7 +19,8 @@ pub struct FunctionDefinition {
     pub body_tokens: Vec<Token>,
     pub visibility: MemberVisibility,
     pub is_static: bool,
-    pub parameters: SahaFunctionParamDefs
+    pub parameters: SahaFunctionParamDefs,
+    pub attributes: Vec<Attribute>
 }
 
 /// A property definition.
@@ -29,7 +31,8 @@ pub struct PropertyDefinition {
     pub visibility: MemberVisibility,
     pub is_static: bool,
     pub property_type: Box<SahaType>,
-    pub default: Value
+    pub default: Value,
+    pub attributes: Vec<Attribute>
 }
 
 /// Class definition blueprint. Used to generate class definitions to a symbol
@@ -39,10 +42,22 @@ pub struct ClassDefinition {
     pub name: String,
     pub source_name: String,
     pub source_position: FilePosition,
+    pub visibility: MemberVisibility,
     pub properties: HashMap<String, PropertyDefinition>,
     pub methods: HashMap<String, FunctionDefinition>,
     pub implements: Vec<String>,
-    pub type_params: Vec<(char, Box<SahaType>)>
+    pub type_params: Vec<(char, Box<SahaType>)>,
+    pub attributes: Vec<Attribute>
+}
+
+impl ClassDefinition {
+    /// Get the behaviors derived with `@derive(...)`, e.g. `Equals`.
+    pub fn derives(&self) -> Vec<String> {
+        return match get_attribute(&self.attributes, DERIVE_ATTRIBUTE) {
+            Some(attr) => attr.args.iter().filter_map(|a| a.name.clone()).collect(),
+            None => Vec::new()
+        };
+    }
 }
 
 /// Behavior definitions.
@@ -51,15 +66,30 @@ pub struct BehaviorDefinition {
     pub name: String,
     pub source_name: String,
     pub source_position: FilePosition,
-    pub methods: HashMap<String, FunctionDefinition>
+    pub visibility: MemberVisibility,
+    pub methods: HashMap<String, FunctionDefinition>,
+    pub extends: Vec<String>
+}
+
+/// Type alias definition, e.g. `type Pair<A, B> = (A, B);`.
+#[derive(Clone, Debug)]
+pub struct TypeAliasDefinition {
+    pub source_name: String,
+    pub type_params: Vec<char>,
+    pub aliased_type: Box<SahaType>
 }
 
+/// Type aliases by their aliased names.
+pub type TypeAliases = HashMap<String, TypeAliasDefinition>;
+
 /// Intermediate parse table, contains "blueprints" for root level declarations.
 pub struct ParseTable {
     pub functions: HashMap<String, FunctionDefinition>,
     pub constants: HashMap<String, Value>,
+    pub constant_visibilities: HashMap<String, MemberVisibility>,
     pub classes: HashMap<String, ClassDefinition>,
     pub behaviors: HashMap<String, BehaviorDefinition>,
+    pub type_aliases: TypeAliases,
 }
 
 impl ParseTable {
@@ -67,12 +97,104 @@ impl ParseTable {
         return ParseTable {
             functions: HashMap::new(),
             constants: HashMap::new(),
+            constant_visibilities: HashMap::new(),
             classes: HashMap::new(),
             behaviors: HashMap::new(),
+            type_aliases: HashMap::new(),
         };
     }
 }
 
+/// Expand type aliases in a type into the types they refer to. Aliases may
+/// refer to other aliases, which are expanded as well.
+pub fn expand_type_aliases(aliases: &TypeAliases, typ: SahaType, pos: &FilePosition) -> Result<SahaType, ParseError> {
+    return expand_type_aliases_recursive(aliases, typ, pos, &mut Vec::new());
+}
+
+fn expand_type_aliases_recursive(aliases: &TypeAliases, typ: SahaType, pos: &FilePosition, expanding: &mut Vec<String>) -> Result<SahaType, ParseError> {
+    match typ {
+        SahaType::Name(name, type_params) => {
+            let mut expanded_params: Vec<Box<SahaType>> = Vec::new();
+
+            for param in type_params {
+                expanded_params.push(Box::new(expand_type_aliases_recursive(aliases, *param, pos, expanding)?));
+            }
+
+            let alias = match aliases.get(&name) {
+                Some(a) => a,
+                None => return Ok(SahaType::Name(name, expanded_params))
+            };
+
+            if expanding.contains(&name) {
+                return Err(ParseError::new(
+                    &format!("Type alias `{}` refers to itself", alias.source_name),
+                    Some(pos.to_owned())
+                ));
+            }
+
+            if alias.type_params.len() != expanded_params.len() {
+                return Err(ParseError::new(
+                    &format!(
+                        "Type alias `{}` expects {} type parameters, {} given",
+                        alias.source_name,
+                        alias.type_params.len(),
+                        expanded_params.len()
+                    ),
+                    Some(pos.to_owned())
+                ));
+            }
+
+            let substituted = substitute_type_params(&alias.aliased_type, &alias.type_params, &expanded_params);
+
+            expanding.push(name);
+
+            let expanded = expand_type_aliases_recursive(aliases, substituted, pos, expanding)?;
+
+            expanding.pop();
+
+            return Ok(expanded);
+        },
+        SahaType::Tuple(items) => {
+            let mut expanded_items: Vec<Box<SahaType>> = Vec::new();
+
+            for item in items {
+                expanded_items.push(Box::new(expand_type_aliases_recursive(aliases, *item, pos, expanding)?));
+            }
+
+            return Ok(SahaType::Tuple(expanded_items));
+        },
+        _ => return Ok(typ)
+    };
+}
+
+/// Get the type parameters used in a type, e.g. `T` in `List<T>`.
+pub fn get_type_params(typ: &SahaType) -> Vec<char> {
+    return match typ {
+        SahaType::TypeParam(c) => vec![*c],
+        SahaType::Name(_, params) => params.iter().flat_map(|p| get_type_params(p)).collect(),
+        SahaType::Tuple(items) => items.iter().flat_map(|i| get_type_params(i)).collect(),
+        _ => Vec::new()
+    };
+}
+
+/// Replace alias type parameters with the types given for them.
+fn substitute_type_params(typ: &SahaType, type_params: &[char], given: &[Box<SahaType>]) -> SahaType {
+    return match typ {
+        SahaType::TypeParam(c) => match type_params.iter().position(|p| p == c) {
+            Some(idx) => *given[idx].clone(),
+            None => typ.clone()
+        },
+        SahaType::Name(n, params) => SahaType::Name(
+            n.clone(),
+            params.iter().map(|p| Box::new(substitute_type_params(p, type_params, given))).collect()
+        ),
+        SahaType::Tuple(items) => SahaType::Tuple(
+            items.iter().map(|i| Box::new(substitute_type_params(i, type_params, given))).collect()
+        ),
+        _ => typ.clone()
+    };
+}
+
 impl PartialEq for FunctionDefinition {
     fn eq(&self, other: &FunctionDefinition) -> bool {
         let name_match = self.name == other.name;
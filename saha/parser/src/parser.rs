@@ -54,6 +54,7 @@ pub trait ParsesTokens {
             "name" => Token::Name(_f(), _s(), _s()),
             "curlyopen" | "{" => Token::CurlyOpen(_f()),
             "curlyclose" | "}" => Token::CurlyClose(_f()),
+            "ellipsis" | "..." => Token::Ellipsis(_f()),
             "parensopen" | "(" => Token::ParensOpen(_f()),
             "parensclose" | ")" => Token::ParensClose(_f()),
             "braceopen" | "[" => Token::BraceOpen(_f()),
@@ -63,6 +64,9 @@ pub trait ParsesTokens {
             "endstatement" | ";" => Token::EndStatement(_f()),
             "assign" | "=" => Token::Assign(_f()),
             "objectaccess" | "->" => Token::ObjectAccess(_f()),
+            "safeobjectaccess" | "?->" => Token::SafeObjectAccess(_f()),
+            "questionmark" | "?" => Token::QuestionMark(_f()),
+            "at" | "@" => Token::At(_f()),
             "staticaccess" | "::" => Token::StaticAccess(_f()),
             "squote" | "'" => Token::SingleQuote(_f()),
 
@@ -71,6 +75,7 @@ pub trait ParsesTokens {
             "integerval" => Token::IntegerValue(_f(), 1),
             "floatval" => Token::FloatValue(_f(), r64(0.0)),
             "booleanval" => Token::BooleanValue(_f(), true),
+            "tupleindex" => Token::TupleIndex(_f(), 0),
 
             // types
             "typestring" | "str" => Token::TypeString(_f()),
@@ -90,6 +95,7 @@ pub trait ParsesTokens {
             "<=" => Token::OpLte(_f()),
             "&&" => Token::OpAnd(_f()),
             "||" => Token::OpOr(_f()),
+            "??" => Token::OpCoalesce(_f()),
             "==" => Token::OpEq(_f()),
             "!=" => Token::OpNeq(_f()),
 
@@ -98,6 +104,7 @@ pub trait ParsesTokens {
             "as" => Token::KwAs(_f()),
             "class" => Token::KwClass(_f()),
             "behavior" => Token::KwBehavior(_f()),
+            "type" => Token::KwType(_f()),
             "use" => Token::KwUse(_f()),
             "prop" => Token::KwProperty(_f()),
             "const" => Token::KwConstant(_f()),
@@ -111,6 +118,7 @@ pub trait ParsesTokens {
             "elseif" => Token::KwElseif(_f()),
             "else" => Token::KwElse(_f()),
             "implements" => Token::KwImplements(_f()),
+            "extends" => Token::KwExtends(_f()),
             "function" => Token::KwFunction(_f()),
             "method" => Token::KwMethod(_f()),
             "pub" => Token::KwPublic(_f()),
@@ -7,11 +7,22 @@ use std::{
     collections::HashMap,
     iter::{Iterator, Peekable},
     slice::Iter,
-    mem::discriminant,
+    mem::{discriminant, take},
 };
 
 use saha_lib::prelude::*;
 use saha_lib::source::token::Token;
+use saha_lib::types::{
+    objects::INIT_METHOD_NAME,
+    behaviors::DERIVABLE_BEHAVIORS,
+    attributes::{
+        Attribute,
+        TEST_ATTRIBUTE,
+        DEPRECATED_ATTRIBUTE,
+        DERIVE_ATTRIBUTE,
+        has_attribute
+    }
+};
 
 use crate::{
     parse_table::{
@@ -19,7 +30,10 @@ use crate::{
         FunctionDefinition,
         PropertyDefinition,
         ClassDefinition,
-        BehaviorDefinition
+        BehaviorDefinition,
+        TypeAliasDefinition,
+        expand_type_aliases,
+        get_type_params
     },
     parser::{
         TokenType,
@@ -30,6 +44,10 @@ use crate::{
 
 type ClassBodyDefinition = (HashMap<String, PropertyDefinition>, HashMap<String, FunctionDefinition>, Vec<String>);
 
+/// Parameter default which refers to a constant: position, parameter name,
+/// parameter type, and constant name.
+type ConstantParamDefault = (FilePosition, String, Box<SahaType>, String);
+
 /// RootParser, which parses root level declarations from tokens. Means we use
 /// this to parse constants, classes, behaviors, and functions, while not
 /// touching the tokens inside function/method bodies.
@@ -39,7 +57,11 @@ pub struct RootParser<'a> {
     ntok: Option<&'a Token>,
     tokidx: usize,
     parse_table: &'a mut ParseTable,
-    tokens: Peekable<Iter<'a, Token>>
+    tokens: Peekable<Iter<'a, Token>>,
+    constant_param_defaults: Vec<ConstantParamDefault>,
+
+    /// Attributes parsed for the next class member declaration.
+    member_attributes: Vec<Attribute>
 }
 
 impl<'a> ParsesTokens for RootParser<'a> {
@@ -123,25 +145,212 @@ impl<'a> RootParser<'a> {
             ntok: None,
             tokidx: 0,
             parse_table: parse_table,
-            tokens: tokens.iter().peekable()
+            tokens: tokens.iter().peekable(),
+            constant_param_defaults: Vec::new(),
+            member_attributes: Vec::new()
         };
     }
 
     /// Parse tokens.
     pub fn start_parse(&mut self) -> PR<()> {
+        self.parse_type_alias_declarations()?;
+        self.parse_root()?;
+
+        return self.resolve_constant_param_defaults();
+    }
+
+    /// Resolve parameter defaults which refer to constants. Constants can be
+    /// declared after the functions that use them, which is why these are
+    /// resolved only after all root declarations have been parsed.
+    fn resolve_constant_param_defaults(&mut self) -> PR<()> {
+        for (pos, param_name, param_type, const_name) in &self.constant_param_defaults {
+            let const_val = match self.parse_table.constants.get(const_name) {
+                Some(v) => v,
+                None => {
+                    return Err(ParseError::new(
+                        &format!("Unknown constant `{}` used as default value for `{}`", const_name, param_name),
+                        Some(pos.to_owned())
+                    ));
+                }
+            };
+
+            if *param_type != const_val.kind {
+                return Err(ParseError::new(
+                    &format!(
+                        "Parameter type mismatch for `{}`, expected `{}` but given default value is a `{}`",
+                        param_name,
+                        param_type.to_readable_string(),
+                        const_val.kind.to_readable_string()
+                    ),
+                    Some(pos.to_owned())
+                ));
+            }
+        }
+
+        let constants = &self.parse_table.constants;
+
+        let resolve = |params: &mut SahaFunctionParamDefs| {
+            for param in params.values_mut() {
+                let const_val = match *param.default.kind {
+                    SahaType::Name(ref const_name, _) => constants.get(const_name).cloned(),
+                    _ => None
+                };
+
+                if let Some(val) = const_val {
+                    param.default = val;
+                }
+            }
+        };
+
+        for func in self.parse_table.functions.values_mut() {
+            resolve(&mut func.parameters);
+        }
+
+        for class in self.parse_table.classes.values_mut() {
+            for method in class.methods.values_mut() {
+                resolve(&mut method.parameters);
+            }
+        }
+
+        for behavior in self.parse_table.behaviors.values_mut() {
+            for method in behavior.methods.values_mut() {
+                resolve(&mut method.parameters);
+            }
+        }
+
+        return Ok(());
+    }
+
+    /// Parse all root level type alias declarations before other declarations,
+    /// so that types can refer to aliases declared later on in the source.
+    fn parse_type_alias_declarations(&mut self) -> PR<()> {
+        let tokens = self.tokens.clone();
+        let mut block_depth = 0;
+
+        while let Some(next) = self.tokens.peek() {
+            match next {
+                Token::KwType(..) if block_depth == 0 => {
+                    self.consume_next(vec!["type"])?;
+                    self.parse_type_alias_declaration()?;
+
+                    continue;
+                },
+                Token::CurlyOpen(..) => block_depth += 1,
+                Token::CurlyClose(..) => block_depth -= 1,
+                _ => ()
+            };
+
+            self.consume_any()?;
+        }
+
+        // rewind for parsing the rest of the declarations
+        self.tokens = tokens;
+        self.ctok = None;
+        self.ptok = None;
+        self.ntok = None;
+        self.tokidx = 0;
+
+        return Ok(());
+    }
+
+    /// Parse a type alias declaration, e.g. `type Pair<A, B> = (A, B);`.
+    fn parse_type_alias_declaration(&mut self) -> PR<()> {
+        self.consume_next(vec!["name"])?;
+
+        let (alias_pos, alias_name, source_name) = match self.ctok.unwrap() {
+            Token::Name(pos, alias, source) => (pos, alias, source),
+            _ => unreachable!()
+        };
+
+        if self.parse_table.type_aliases.contains_key(alias_name) {
+            return Err(ParseError::new(
+                &format!("Cannot redeclare type alias `{}`", alias_name),
+                Some(alias_pos.to_owned())
+            ));
+        }
+
+        let type_params: Vec<char> = match self.ntok.unwrap() {
+            Token::OpLt(..) => self.parse_paramtype_defs()?.into_iter().map(|(c, _)| c).collect(),
+            _ => Vec::new()
+        };
+
+        self.consume_next(vec!["="])?;
+
+        let aliased_type = self.parse_type_declaration(!type_params.is_empty())?;
+
+        for used in get_type_params(&aliased_type) {
+            if !type_params.contains(&used) {
+                return Err(ParseError::new(
+                    &format!("Unknown type parameter `{}` in type alias `{}`", used, source_name),
+                    Some(alias_pos.to_owned())
+                ));
+            }
+        }
+
+        self.consume_next(vec![";"])?;
+
+        self.parse_table.type_aliases.insert(alias_name.to_owned(), TypeAliasDefinition {
+            source_name: source_name.to_owned(),
+            type_params: type_params,
+            aliased_type: aliased_type
+        });
+
+        return Ok(());
+    }
+
+    /// Skip a type alias declaration, which has been parsed before other root
+    /// level declarations.
+    fn skip_type_alias_declaration(&mut self) -> PR<()> {
+        loop {
+            self.consume_any()?;
+
+            if let Token::EndStatement(..) = self.ctok.unwrap() {
+                break;
+            }
+        }
+
         return self.parse_root();
     }
 
     /// Parse a root level declaration.
     fn parse_root(&mut self) -> PR<()> {
-        self.consume_next(vec!["class", "behavior", "function", "const", "import", "eof"])?;
+        self.consume_next(vec!["@", "pub", "class", "behavior", "function", "const", "type", "import", "eof"])?;
 
         match self.ctok.unwrap() {
-            Token::KwFunction(..) => self.parse_function_declaration(),
-            Token::KwClass(..) => self.parse_class_declaration(),
-            Token::KwBehavior(..) => self.parse_behavior_declaration(),
-            Token::KwConstant(..) => self.parse_constant_declaration(),
-            Token::Import(..) => Ok(()), // TODO validate how this should really be handled
+            Token::KwFunction(..)
+            | Token::KwClass(..)
+            | Token::KwBehavior(..)
+            | Token::KwConstant(..)
+            | Token::KwType(..) => self.parse_declaration(Vec::new(), MemberVisibility::Private),
+            Token::KwPublic(..) => {
+                self.consume_next(vec!["class", "behavior", "function", "const", "type"])?;
+
+                self.parse_declaration(Vec::new(), MemberVisibility::Public)
+            },
+            Token::At(..) => {
+                let attributes = self.parse_attributes()?;
+
+                self.consume_next(vec!["pub", "class", "function"])?;
+
+                let visibility = match self.ctok.unwrap() {
+                    Token::KwPublic(..) => {
+                        self.consume_next(vec!["class", "function"])?;
+
+                        MemberVisibility::Public
+                    },
+                    _ => MemberVisibility::Private
+                };
+
+                let target = match self.ctok.unwrap() {
+                    Token::KwClass(..) => "class",
+                    _ => "function"
+                };
+
+                self.validate_attributes(&attributes, target)?;
+
+                self.parse_declaration(attributes, visibility)
+            },
+            Token::Import(..) => self.parse_root(),
             Token::Eof(..) => {
                 if self.ntok.is_none() {
                     // final EOF, we can stop parsing now
@@ -155,11 +364,50 @@ impl<'a> RootParser<'a> {
         }
     }
 
+    /// Parse a root level declaration after its keyword. Visibility is
+    /// enforced when importing declarations from other modules during
+    /// tokenization, and stored with the declaration for the runtime.
+    fn parse_declaration(&mut self, attributes: Vec<Attribute>, visibility: MemberVisibility) -> PR<()> {
+        match self.ctok.unwrap() {
+            Token::KwFunction(..) => self.parse_function_declaration(attributes, visibility),
+            Token::KwClass(..) => self.parse_class_declaration(attributes, visibility),
+            Token::KwBehavior(..) => self.parse_behavior_declaration(visibility),
+            Token::KwConstant(..) => self.parse_constant_declaration(visibility),
+            Token::KwType(..) => self.skip_type_alias_declaration(),
+            _ => unreachable!()
+        }
+    }
+
     /// Parse a type declaration.
     fn parse_type_declaration(&mut self, parse_param_types: bool) -> PR<Box<SahaType>> {
-        self.consume_next(vec!["name", "typestring", "typeboolean", "typeinteger", "typefloat"])?;
+        self.consume_next(vec!["name", "typestring", "typeboolean", "typeinteger", "typefloat", "("])?;
+
+        let type_pos = self.ctok.unwrap().get_file_position();
 
         let typ = match self.ctok.unwrap() {
+            Token::ParensOpen(pos) => {
+                let mut item_types = Vec::new();
+
+                loop {
+                    item_types.push(self.parse_type_declaration(parse_param_types)?);
+
+                    match self.ntok.unwrap() {
+                        Token::Comma(..) => self.consume_next(vec![","])?,
+                        _ => break
+                    };
+                }
+
+                self.consume_next(vec![")"])?;
+
+                if item_types.len() < 2 {
+                    return Err(ParseError::new(
+                        "Tuple types require at least two element types",
+                        Some(pos.to_owned())
+                    ));
+                }
+
+                SahaType::Tuple(item_types)
+            },
             Token::TypeBoolean(..) => SahaType::Bool,
             Token::TypeString(..) => SahaType::Str,
             Token::TypeInteger(..) => SahaType::Int,
@@ -202,11 +450,20 @@ impl<'a> RootParser<'a> {
             _ => unreachable!()
         };
 
+        let typ = expand_type_aliases(&self.parse_table.type_aliases, typ, &type_pos)?;
+
+        // `T?` is shorthand for `Option<T>`
+        if let Token::QuestionMark(..) = self.ntok.unwrap() {
+            self.consume_next(vec!["?"])?;
+
+            return Ok(Box::new(SahaType::Name("Option".to_string(), vec![Box::new(typ)])));
+        }
+
         return Ok(Box::new(typ));
     }
 
     /// Parse root level function declaration.
-    fn parse_function_declaration(&mut self) -> PR<()> {
+    fn parse_function_declaration(&mut self, attributes: Vec<Attribute>, visibility: MemberVisibility) -> PR<()> {
         self.consume_next(vec!["name"])?;
 
         let (fn_pos, fn_alias, fn_source_name) = match self.ctok.unwrap() {
@@ -224,7 +481,7 @@ impl<'a> RootParser<'a> {
         self.consume_next(vec!["("])?;
 
         let fn_parameter_definitions: SahaFunctionParamDefs = match self.ntok.unwrap() {
-            Token::ParensClose(..) => HashMap::new(),
+            Token::ParensClose(..) => SahaFunctionParamDefs::new(),
             _ => self.parse_function_parameter_definitions(false)?
         };
 
@@ -243,6 +500,13 @@ impl<'a> RootParser<'a> {
 
         // last curly open token was parsed in the parse fn call above
 
+        if has_attribute(&attributes, TEST_ATTRIBUTE) && !fn_parameter_definitions.is_empty() {
+            return Err(ParseError::new(
+                &format!("Test function `{}` cannot take parameters", fn_source_name),
+                Some(fn_pos.to_owned())
+            ));
+        }
+
         let fn_definition = FunctionDefinition {
             name: fn_alias.to_owned(),
             source_name: fn_source_name,
@@ -250,8 +514,9 @@ impl<'a> RootParser<'a> {
             return_type: return_type,
             body_tokens: fn_body_tokens,
             parameters: fn_parameter_definitions,
-            visibility: MemberVisibility::Public,
-            is_static: false
+            visibility: visibility,
+            is_static: false,
+            attributes: attributes
         };
 
         self.parse_table.functions.insert(fn_alias, fn_definition);
@@ -261,13 +526,23 @@ impl<'a> RootParser<'a> {
 
     /// Parse function declaration parameter definitions.
     fn parse_function_parameter_definitions(&mut self, parse_param_types: bool) -> PR<SahaFunctionParamDefs> {
-        let mut param_defs: SahaFunctionParamDefs = HashMap::new();
+        let mut param_defs: SahaFunctionParamDefs = SahaFunctionParamDefs::new();
+        let mut position = 0;
 
         loop {
+            let is_variadic = match self.ntok.unwrap() {
+                Token::Ellipsis(..) => {
+                    self.consume_next(vec!["..."])?;
+
+                    true
+                },
+                _ => false
+            };
+
             self.consume_next(vec!["name"])?;
 
-            let param_name = match self.ctok.unwrap() {
-                Token::Name(_, _, name) => name.to_owned(),
+            let (param_pos, param_name) = match self.ctok.unwrap() {
+                Token::Name(pos, _, name) => (pos.to_owned(), name.to_owned()),
                 _ => unreachable!()
             };
 
@@ -280,28 +555,71 @@ impl<'a> RootParser<'a> {
                 return Err(err);
             }
 
+            if param_defs.contains_key(&param_name) {
+                return Err(ParseError::new(
+                    &format!("Duplicate parameter `{}`", param_name),
+                    Some(param_pos)
+                ));
+            }
+
             self.consume_next(vec!["'"])?;
 
             let param_type = self.parse_type_declaration(parse_param_types)?;
 
+            if is_variadic {
+                let is_list = match *param_type {
+                    SahaType::Name(ref n, ref tps) => n == "List" && tps.len() == 1,
+                    _ => false
+                };
+
+                if !is_list {
+                    return Err(ParseError::new(
+                        &format!("Variadic parameter `{}` must be a `List<T>`", param_name),
+                        Some(param_pos)
+                    ));
+                }
+
+                match self.ntok.unwrap() {
+                    Token::ParensClose(..) => (),
+                    _ => return Err(ParseError::new(
+                        &format!("Variadic parameter `{}` must be the last parameter, without a default value", param_name),
+                        Some(param_pos)
+                    ))
+                };
+            }
+
             let paramdef: FunctionParameter;
 
             match self.ntok.unwrap() {
                 Token::Assign(..) => {
                     self.consume_next(vec!["="])?;
 
-                    // only primitive types can be set as param defaults
-                    self.consume_next(vec!["stringval", "booleanval", "integerval", "floatval"])?;
+                    // only primitive types and constants can be set as param defaults
+                    self.consume_next(vec!["stringval", "booleanval", "integerval", "floatval", "name"])?;
 
                     let (def_pos, default) = match self.ctok.unwrap() {
                         Token::StringValue(fp, val) => (fp, Value::str(val.to_owned())),
                         Token::BooleanValue(fp, val) => (fp, Value::bool(val.to_owned())),
                         Token::IntegerValue(fp, val) => (fp, Value::int(val.to_owned())),
                         Token::FloatValue(fp, val) => (fp, Value::float(val.to_owned())),
+                        Token::Name(fp, _, const_name) => {
+                            // constants may be declared later on, resolve and
+                            // type check after root parsing
+                            self.constant_param_defaults.push((
+                                fp.to_owned(),
+                                param_name.clone(),
+                                param_type.clone(),
+                                const_name.to_owned()
+                            ));
+
+                            (fp, Value::name(const_name.to_owned(), Vec::new()))
+                        },
                         _ => unreachable!()
                     };
 
-                    if param_type != default.kind {
+                    let is_constant = matches!(*default.kind, SahaType::Name(..));
+
+                    if !is_constant && param_type != default.kind {
                         return Err(ParseError::new(
                             &format!(
                                 "Parameter type mismatch for `{}`, expected `{}` but given default value is a `{}`",
@@ -316,20 +634,26 @@ impl<'a> RootParser<'a> {
                     paramdef = FunctionParameter {
                         name: param_name.clone(),
                         param_type: param_type,
-                        default: default
+                        default: default,
+                        position: position,
+                        is_variadic: false
                     };
                 },
                 _ => {
                     paramdef = FunctionParameter {
                         name: param_name.clone(),
                         param_type: param_type,
-                        default: Value::void()
+                        default: Value::void(),
+                        position: position,
+                        is_variadic: is_variadic
                     };
                 }
             };
 
             param_defs.insert(param_name, paramdef);
 
+            position += 1;
+
             match self.ntok.unwrap() {
                 Token::ParensClose(..) => break,
                 _ => {
@@ -352,6 +676,11 @@ impl<'a> RootParser<'a> {
 
                     Box::new(SahaType::Void)
                 },
+                Token::CurlyOpen(..) => {
+                    self.consume_next(vec!["{"])?;
+
+                    Box::new(SahaType::Void)
+                },
                 _ => self.parse_type_declaration(parse_param_types)?
             }
         } else {
@@ -407,7 +736,7 @@ impl<'a> RootParser<'a> {
     }
 
     /// Parse constant declaration.
-    fn parse_constant_declaration(&mut self) -> PR<()> {
+    fn parse_constant_declaration(&mut self, visibility: MemberVisibility) -> PR<()> {
         let (const_pos, const_name, const_type) = self.parse_constant_name_declaration()?;
 
         self.consume_next(vec!["="])?;
@@ -433,6 +762,7 @@ impl<'a> RootParser<'a> {
             ));
         }
 
+        self.parse_table.constant_visibilities.insert(const_name.to_owned(), visibility);
         self.parse_table.constants.insert(const_name, const_val);
 
         self.consume_next(vec![";"])?;
@@ -507,8 +837,161 @@ impl<'a> RootParser<'a> {
         return Ok(value);
     }
 
+    /// Parse attributes preceding a declaration, e.g. `@test` or
+    /// `@deprecated("Use bar() instead")`. Arguments can be literal values or
+    /// names.
+    fn parse_attributes(&mut self) -> PR<Vec<Attribute>> {
+        let mut attributes: Vec<Attribute> = Vec::new();
+
+        loop {
+            // the `@` token was parsed before calling this or on the previous loop
+            self.consume_next(vec!["name"])?;
+
+            let (attr_pos, attr_name) = match self.ctok.unwrap() {
+                Token::Name(pos, _, sourcename) => (pos.to_owned(), sourcename.to_owned()),
+                _ => unreachable!()
+            };
+
+            if attributes.iter().any(|a| a.name == attr_name) {
+                return Err(ParseError::new(
+                    &format!("Attribute `@{}` given multiple times", attr_name),
+                    Some(attr_pos)
+                ));
+            }
+
+            let mut args: Vec<Value> = Vec::new();
+
+            if let Some(Token::ParensOpen(..)) = self.ntok {
+                self.consume_next(vec!["("])?;
+
+                loop {
+                    self.consume_next(vec!["name", "stringval", "integerval", "floatval", "booleanval"])?;
+
+                    let arg = match self.ctok.unwrap() {
+                        Token::Name(_, alias, _) => Value::name(alias.to_owned(), Vec::new()),
+                        Token::StringValue(_, val) => Value::str(val.to_owned()),
+                        Token::IntegerValue(_, val) => Value::int(*val),
+                        Token::FloatValue(_, val) => Value::float(*val),
+                        Token::BooleanValue(_, val) => Value::bool(*val),
+                        _ => unreachable!()
+                    };
+
+                    args.push(arg);
+
+                    self.consume_next(vec![",", ")"])?;
+
+                    match self.ctok.unwrap() {
+                        Token::ParensClose(..) => break,
+                        Token::Comma(..) => continue,
+                        _ => unreachable!()
+                    };
+                }
+            }
+
+            attributes.push(Attribute {
+                name: attr_name,
+                args: args,
+                source_position: attr_pos
+            });
+
+            if let Some(Token::At(..)) = self.ntok {
+                self.consume_next(vec!["@"])?;
+
+                continue;
+            }
+
+            break;
+        }
+
+        return Ok(attributes);
+    }
+
+    /// Validate the attributes the language itself uses. Other attributes are
+    /// rejected on functions and classes, but allowed and stored as is on
+    /// methods and properties, for tooling to use.
+    fn validate_attributes(&self, attributes: &[Attribute], target: &str) -> PR<()> {
+        for attr in attributes {
+            let allowed_targets: &[&str] = match &attr.name as &str {
+                TEST_ATTRIBUTE => &["function"],
+                DEPRECATED_ATTRIBUTE => &["function", "method", "class"],
+                DERIVE_ATTRIBUTE => &["class"],
+                _ if ["function", "class"].contains(&target) => {
+                    return Err(ParseError::new(
+                        &format!("Unknown attribute `@{}` on a {}", attr.name, target),
+                        Some(attr.source_position.clone())
+                    ));
+                },
+                _ => continue
+            };
+
+            if !allowed_targets.contains(&target) {
+                return Err(ParseError::new(
+                    &format!("Attribute `@{}` cannot be used on a {}", attr.name, target),
+                    Some(attr.source_position.clone())
+                ));
+            }
+
+            let args_error = match &attr.name as &str {
+                TEST_ATTRIBUTE if !attr.args.is_empty() => Some("takes no arguments"),
+                DEPRECATED_ATTRIBUTE if attr.args.len() > 1 || attr.args.iter().any(|a| *a.kind != SahaType::Str) => {
+                    Some("takes an optional message string")
+                },
+                DERIVE_ATTRIBUTE if attr.args.is_empty() => Some("requires at least one behavior name"),
+                _ => None
+            };
+
+            if let Some(msg) = args_error {
+                return Err(ParseError::new(
+                    &format!("Attribute `@{}` {}", attr.name, msg),
+                    Some(attr.source_position.clone())
+                ));
+            }
+
+            if attr.name == DERIVE_ATTRIBUTE {
+                self.validate_derive_attribute(attr)?;
+            }
+        }
+
+        return Ok(());
+    }
+
+    /// Validate the behavior names given to a `@derive(...)` attribute.
+    fn validate_derive_attribute(&self, attr: &Attribute) -> PR<()> {
+        let mut derives: Vec<String> = Vec::new();
+
+        for arg in &attr.args {
+            let beh_name = match arg.name {
+                Some(ref n) => n.to_owned(),
+                None => {
+                    return Err(ParseError::new(
+                        &format!("Attribute `@{}` accepts only behavior names", attr.name),
+                        Some(attr.source_position.clone())
+                    ));
+                }
+            };
+
+            if !DERIVABLE_BEHAVIORS.contains(&beh_name.as_str()) {
+                return Err(ParseError::new(
+                    &format!("Behavior `{}` cannot be derived, derivable behaviors are `{}`", beh_name, DERIVABLE_BEHAVIORS.join("`, `")),
+                    Some(attr.source_position.clone())
+                ));
+            }
+
+            if derives.contains(&beh_name) {
+                return Err(ParseError::new(
+                    &format!("Behavior derived multiple times `{}`", beh_name),
+                    Some(attr.source_position.clone())
+                ));
+            }
+
+            derives.push(beh_name);
+        }
+
+        return Ok(());
+    }
+
     /// Parse class declaration.
-    fn parse_class_declaration(&mut self) -> PR<()> {
+    fn parse_class_declaration(&mut self, attributes: Vec<Attribute>, visibility: MemberVisibility) -> PR<()> {
         self.consume_next(vec!["name"])?;
 
         let (cname_pos, cname, cname_source) = match self.ctok.unwrap() {
@@ -516,7 +999,7 @@ impl<'a> RootParser<'a> {
             _ => unreachable!()
         };
 
-        if self.parse_table.classes.contains_key(cname) {
+        if self.parse_table.classes.contains_key(cname) || self.parse_table.type_aliases.contains_key(cname) {
             return Err(ParseError::new(
                 &format!("Cannot redeclare class `{}`", cname),
                 Some(cname_pos.to_owned())
@@ -537,14 +1020,20 @@ impl<'a> RootParser<'a> {
 
         // closing `}` was parsed in body parsing function
 
+        if let Some(init) = method_definitions.get(INIT_METHOD_NAME) {
+            self.validate_init_method(cname, init)?;
+        }
+
         let class_def = ClassDefinition {
             name: cname.to_owned(),
             source_name: cname_source.to_owned(),
             source_position: cname_pos.to_owned(),
+            visibility: visibility,
             methods: method_definitions,
             properties: property_definitions,
             implements: implements,
-            type_params: paramtype_defs
+            type_params: paramtype_defs,
+            attributes: attributes
         };
 
         self.parse_table.classes.insert(cname.to_owned(), class_def);
@@ -552,6 +1041,32 @@ impl<'a> RootParser<'a> {
         return self.parse_root();
     }
 
+    /// Validate the signature of a class `init` method. It is called after a
+    /// new instance has been created, so it cannot be static or take
+    /// parameters, and may only return a `Result`.
+    fn validate_init_method(&self, class_name: &str, init: &FunctionDefinition) -> PR<()> {
+        let error_msg = if init.is_static {
+            Some("cannot be static")
+        } else if !init.parameters.is_empty() {
+            Some("cannot take parameters")
+        } else {
+            match *init.return_type {
+                SahaType::Void => None,
+                SahaType::Name(ref n, ref tps) if n == "Result" && tps.len() == 2 => None,
+                _ => Some("must return nothing or a `Result<T, U>`")
+            }
+        };
+
+        if let Some(msg) = error_msg {
+            return Err(ParseError::new(
+                &format!("The `{}` method of class `{}` {}", INIT_METHOD_NAME, class_name, msg),
+                Some(init.source_position.clone())
+            ));
+        }
+
+        return Ok(());
+    }
+
     /// Validate a parameter type name (should be a single uppercase char).
     fn validate_paramtype_name(&self, name: &str) -> bool {
         if name.len() != 1 {
@@ -624,12 +1139,22 @@ impl<'a> RootParser<'a> {
         let mut implements: Vec<String> = Vec::new();
 
         loop {
-            self.consume_next(vec!["method", "prop", "pub", "static", "implements", "}"])?;
+            self.consume_next(vec!["@", "method", "prop", "pub", "static", "implements", "}"])?;
 
             let mut member_visibility = MemberVisibility::Private;
             let mut member_is_static = false;
 
             match self.ctok.unwrap() {
+                Token::CurlyClose(..) | Token::KwImplements(..) if !self.member_attributes.is_empty() => {
+                    return Err(ParseError::new(
+                        "Attributes must be followed by a method or a property declaration",
+                        Some(self.member_attributes[0].source_position.clone())
+                    ));
+                },
+                Token::At(..) => {
+                    // attributes are taken by the member declaration parsed next
+                    self.member_attributes = self.parse_attributes()?;
+                },
                 Token::CurlyClose(..) => break, // reached end of class body
                 Token::KwPublic(..) => {
                     member_visibility = MemberVisibility::Public;
@@ -801,6 +1326,10 @@ impl<'a> RootParser<'a> {
 
     /// Parse a class property.
     fn parse_class_property(&mut self, visibility: MemberVisibility, is_static: bool) -> PR<PropertyDefinition> {
+        let attributes = take(&mut self.member_attributes);
+
+        self.validate_attributes(&attributes, "property")?;
+
         self.consume_next(vec!["name"])?;
 
         let (prop_pos, prop_name) = match self.ctok.unwrap() {
@@ -841,12 +1370,17 @@ impl<'a> RootParser<'a> {
             visibility: visibility,
             is_static: is_static,
             property_type: prop_type,
-            default: default_value
+            default: default_value,
+            attributes: attributes
         });
     }
 
     /// Parse a class method
     fn parse_class_method(&mut self, visibility: MemberVisibility, is_static: bool) -> PR<FunctionDefinition> {
+        let attributes = take(&mut self.member_attributes);
+
+        self.validate_attributes(&attributes, "method")?;
+
         self.consume_next(vec!["name"])?;
 
         let (method_pos, method_name) = match self.ctok.unwrap() {
@@ -857,7 +1391,7 @@ impl<'a> RootParser<'a> {
         self.consume_next(vec!["("])?;
 
         let fn_parameter_definitions: SahaFunctionParamDefs = match self.ntok.unwrap() {
-            Token::ParensClose(..) => HashMap::new(),
+            Token::ParensClose(..) => SahaFunctionParamDefs::new(),
             _ => self.parse_function_parameter_definitions(true)?
         };
 
@@ -881,7 +1415,8 @@ impl<'a> RootParser<'a> {
             body_tokens: fn_body_tokens,
             parameters: fn_parameter_definitions,
             visibility: visibility,
-            is_static: is_static
+            is_static: is_static,
+            attributes: attributes
         };
 
         return Ok(method_definition);
@@ -921,7 +1456,7 @@ impl<'a> RootParser<'a> {
     }
 
     /// Parse behavior declaration.
-    fn parse_behavior_declaration(&mut self) -> PR<()> {
+    fn parse_behavior_declaration(&mut self, visibility: MemberVisibility) -> PR<()> {
         self.consume_next(vec!["name"])?;
 
         let (behavior_pos, behavior_name, source_name) = match self.ctok.unwrap() {
@@ -938,32 +1473,48 @@ impl<'a> RootParser<'a> {
 
         self.consume_next(vec!["{"])?;
 
-        let method_definitions: HashMap<String, FunctionDefinition> = self.parse_behavior_body()?;
+        let (method_definitions, extends) = self.parse_behavior_body()?;
+
+        if extends.contains(behavior_name) {
+            return Err(ParseError::new(
+                &format!("Behavior `{}` cannot extend itself", behavior_name),
+                Some(behavior_pos.to_owned())
+            ));
+        }
 
         self.parse_table.behaviors.insert(behavior_name.to_owned(), BehaviorDefinition {
             name: behavior_name.to_owned(),
             source_name: source_name.to_owned(),
             source_position: behavior_pos.to_owned(),
-            methods: method_definitions
+            visibility: visibility,
+            methods: method_definitions,
+            extends: extends
         });
 
         return self.parse_root();
     }
 
-    /// Parse behavior body contents (methods).
-    fn parse_behavior_body(&mut self) -> PR<HashMap<String, FunctionDefinition>> {
+    /// Parse behavior body contents (methods and extended behaviors). Methods
+    /// with a body are default implementations for implementing classes.
+    fn parse_behavior_body(&mut self) -> PR<(HashMap<String, FunctionDefinition>, Vec<String>)> {
         let mut defs: HashMap<String, FunctionDefinition> = HashMap::new();
+        let mut extends: Vec<String> = Vec::new();
 
         loop {
-            self.consume_next(vec!["name", "}"])?;
+            self.consume_next(vec!["name", "extends", "}"])?;
 
             match self.ctok.unwrap() {
                 Token::CurlyClose(..) => break,
+                Token::KwExtends(..) => {
+                    let mut extends_list = self.parse_implements_list()?;
+
+                    extends.append(&mut extends_list);
+                },
                 Token::Name(name_pos, _, method_name) => {
                     self.consume_next(vec!["("])?;
 
                     let param_defs: SahaFunctionParamDefs = match self.ntok.unwrap() {
-                        Token::ParensClose(..) => HashMap::new(),
+                        Token::ParensClose(..) => SahaFunctionParamDefs::new(),
                         _ => self.parse_function_parameter_definitions(false)?
                     };
 
@@ -972,31 +1523,38 @@ impl<'a> RootParser<'a> {
                     let return_type = self.parse_function_return_type(false, true)?;
 
                     if *return_type != SahaType::Void {
-                        self.consume_next(vec![";"])?;
+                        self.consume_next(vec![";", "{"])?;
                     }
 
+                    let body_tokens = match self.ctok.unwrap() {
+                        Token::CurlyOpen(..) => self.parse_curly_block()?,
+                        _ => Vec::new()
+                    };
+
                     defs.insert(method_name.to_owned(), FunctionDefinition {
                         name: method_name.to_owned(),
                         source_name: method_name.to_owned(),
                         source_position: name_pos.to_owned(),
                         parameters: param_defs,
                         return_type: return_type,
-                        body_tokens: Vec::new(),
+                        body_tokens: body_tokens,
                         visibility: MemberVisibility::Public,
-                        is_static: false
+                        is_static: false,
+                        attributes: Vec::new()
                     });
                 },
                 _ => unreachable!()
             };
         }
 
-        return Ok(defs);
+        return Ok((defs, extends));
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use saha_lib::source::import::Import;
 
     fn testfilepos() -> FilePosition {
         return FilePosition::unknown();
@@ -1398,4 +1956,648 @@ mod tests {
 
         assert!(res.err().unwrap().get_message().contains("Cannot redeclare"));
     }
+
+    #[test]
+    fn test_constants_can_be_used_as_parameter_defaults() {
+        let tokens = vec![
+            Token::KwFunction(testfilepos()),
+            Token::Name(testfilepos(), "pkg.main".to_string(), "main".to_string()),
+            Token::ParensOpen(testfilepos()),
+            Token::Name(testfilepos(), "foo".to_string(), "foo".to_string()),
+            Token::SingleQuote(testfilepos()),
+            Token::TypeString(testfilepos()),
+            Token::Assign(testfilepos()),
+            Token::Name(testfilepos(), "FOOBAR".to_string(), "FOOBAR".to_string()),
+            Token::ParensClose(testfilepos()),
+            Token::TypeInteger(testfilepos()),
+            Token::CurlyOpen(testfilepos()),
+            Token::CurlyClose(testfilepos()),
+            Token::KwConstant(testfilepos()),
+            Token::Name(testfilepos(), "FOOBAR".to_string(), "FOOBAR".to_string()),
+            Token::SingleQuote(testfilepos()),
+            Token::TypeString(testfilepos()),
+            Token::Assign(testfilepos()),
+            Token::StringValue(testfilepos(), "FooestBar".to_string()),
+            Token::EndStatement(testfilepos()),
+            Token::Eof(testfilepos())
+        ];
+
+        let mut parse_table = ParseTable::new();
+
+        {
+            let mut parser = RootParser::new(&tokens, &mut parse_table);
+
+            let res = parser.start_parse();
+
+            if res.is_err() {
+                eprintln!("{:?}", res.err().unwrap());
+                panic!();
+            }
+        }
+
+        let fndefinition = parse_table.functions.get("pkg.main").unwrap();
+        let foo_param = fndefinition.parameters.get("foo").unwrap();
+
+        assert_eq!(Value::str("FooestBar".to_string()), foo_param.default);
+    }
+
+    #[test]
+    fn test_unknown_constant_parameter_defaults_are_rejected() {
+        let tokens = vec![
+            Token::KwFunction(testfilepos()),
+            Token::Name(testfilepos(), "pkg.main".to_string(), "main".to_string()),
+            Token::ParensOpen(testfilepos()),
+            Token::Name(testfilepos(), "foo".to_string(), "foo".to_string()),
+            Token::SingleQuote(testfilepos()),
+            Token::TypeString(testfilepos()),
+            Token::Assign(testfilepos()),
+            Token::Name(testfilepos(), "FOOBAR".to_string(), "FOOBAR".to_string()),
+            Token::ParensClose(testfilepos()),
+            Token::TypeInteger(testfilepos()),
+            Token::CurlyOpen(testfilepos()),
+            Token::CurlyClose(testfilepos()),
+            Token::Eof(testfilepos())
+        ];
+
+        let mut parse_table = ParseTable::new();
+        let mut parser = RootParser::new(&tokens, &mut parse_table);
+        let res = parser.start_parse();
+
+        assert!(res.err().unwrap().get_message().contains("Unknown constant `FOOBAR`"));
+    }
+
+    #[test]
+    fn test_variadic_parameters_are_parsed() {
+        let tokens = vec![
+            Token::KwFunction(testfilepos()),
+            Token::Name(testfilepos(), "pkg.main".to_string(), "main".to_string()),
+            Token::ParensOpen(testfilepos()),
+            Token::Name(testfilepos(), "label".to_string(), "label".to_string()),
+            Token::SingleQuote(testfilepos()),
+            Token::TypeString(testfilepos()),
+            Token::Comma(testfilepos()),
+            Token::Ellipsis(testfilepos()),
+            Token::Name(testfilepos(), "rest".to_string(), "rest".to_string()),
+            Token::SingleQuote(testfilepos()),
+            Token::Name(testfilepos(), "List".to_string(), "List".to_string()),
+            Token::OpLt(testfilepos()),
+            Token::TypeInteger(testfilepos()),
+            Token::OpGt(testfilepos()),
+            Token::ParensClose(testfilepos()),
+            Token::TypeInteger(testfilepos()),
+            Token::CurlyOpen(testfilepos()),
+            Token::CurlyClose(testfilepos()),
+            Token::Eof(testfilepos())
+        ];
+
+        let mut parse_table = ParseTable::new();
+
+        {
+            let mut parser = RootParser::new(&tokens, &mut parse_table);
+
+            let res = parser.start_parse();
+
+            if res.is_err() {
+                eprintln!("{:?}", res.err().unwrap());
+                panic!();
+            }
+        }
+
+        let params = parse_table.functions.get("pkg.main").unwrap().parameters.clone();
+
+        let label_param = params.get("label").unwrap();
+        let rest_param = params.get("rest").unwrap();
+
+        assert_eq!(0, label_param.position);
+        assert!(!label_param.is_variadic);
+
+        assert_eq!(1, rest_param.position);
+        assert!(rest_param.is_variadic);
+        assert_eq!(
+            Box::new(SahaType::Name("List".to_string(), vec![Box::new(SahaType::Int)])),
+            rest_param.param_type
+        );
+    }
+
+    #[test]
+    fn test_init_methods_cannot_take_parameters() {
+        let tokens = vec![
+            Token::KwClass(testfilepos()),
+            Token::Name(testfilepos(), "pkg.MyClass".to_string(), "MyClass".to_string()),
+            Token::CurlyOpen(testfilepos()),
+
+            Token::KwMethod(testfilepos()),
+            Token::Name(testfilepos(), "init".to_string(), "init".to_string()),
+            Token::ParensOpen(testfilepos()),
+            Token::Name(testfilepos(), "foo".to_string(), "foo".to_string()),
+            Token::SingleQuote(testfilepos()),
+            Token::TypeInteger(testfilepos()),
+            Token::ParensClose(testfilepos()),
+            Token::CurlyOpen(testfilepos()),
+            Token::CurlyClose(testfilepos()),
+
+            Token::CurlyClose(testfilepos()),
+            Token::Eof(testfilepos())
+        ];
+
+        let mut parse_table = ParseTable::new();
+        let mut parser = RootParser::new(&tokens, &mut parse_table);
+        let res = parser.start_parse();
+
+        assert_eq!(
+            "The `init` method of class `pkg.MyClass` cannot take parameters",
+            res.err().unwrap().get_message()
+        );
+    }
+
+    #[test]
+    fn test_behavior_default_methods_and_extends_are_parsed() {
+        let tokens = vec![
+            Token::KwBehavior(testfilepos()),
+            Token::Name(testfilepos(), "pkg.MyBehavior".to_string(), "MyBehavior".to_string()),
+            Token::CurlyOpen(testfilepos()),
+
+            Token::KwExtends(testfilepos()),
+            Token::Name(testfilepos(), "pkg.OtherBehavior".to_string(), "OtherBehavior".to_string()),
+            Token::EndStatement(testfilepos()),
+
+            Token::Name(testfilepos(), "getSomeValue".to_string(), "getSomeValue".to_string()),
+            Token::ParensOpen(testfilepos()),
+            Token::ParensClose(testfilepos()),
+            Token::TypeInteger(testfilepos()),
+            Token::CurlyOpen(testfilepos()),
+            Token::KwReturn(testfilepos()),
+            Token::IntegerValue(testfilepos(), 1),
+            Token::EndStatement(testfilepos()),
+            Token::CurlyClose(testfilepos()),
+
+            Token::Name(testfilepos(), "doSomething".to_string(), "doSomething".to_string()),
+            Token::ParensOpen(testfilepos()),
+            Token::ParensClose(testfilepos()),
+            Token::CurlyOpen(testfilepos()),
+            Token::CurlyClose(testfilepos()),
+
+            Token::Name(testfilepos(), "otherMethod".to_string(), "otherMethod".to_string()),
+            Token::ParensOpen(testfilepos()),
+            Token::ParensClose(testfilepos()),
+            Token::EndStatement(testfilepos()),
+
+            Token::CurlyClose(testfilepos()),
+            Token::Eof(testfilepos())
+        ];
+
+        let mut parse_table = ParseTable::new();
+
+        {
+            let mut parser = RootParser::new(&tokens, &mut parse_table);
+
+            let res = parser.start_parse();
+
+            if res.is_err() {
+                eprintln!("{:?}", res.err().unwrap());
+                panic!();
+            }
+        }
+
+        let behavior_definition = parse_table.behaviors.get("pkg.MyBehavior").unwrap();
+        let methods = &behavior_definition.methods;
+
+        assert_eq!(vec!["pkg.OtherBehavior".to_string()], behavior_definition.extends);
+
+        assert_eq!(vec![
+            Token::KwReturn(testfilepos()),
+            Token::IntegerValue(testfilepos(), 1),
+            Token::EndStatement(testfilepos()),
+            Token::Eob
+        ], methods.get("getSomeValue").unwrap().body_tokens);
+
+        assert_eq!(Box::new(SahaType::Void), methods.get("doSomething").unwrap().return_type);
+        assert_eq!(vec![Token::Eob], methods.get("doSomething").unwrap().body_tokens);
+        assert!(methods.get("otherMethod").unwrap().body_tokens.is_empty());
+    }
+
+    #[test]
+    fn test_class_derive_attributes_are_parsed() {
+        let tokens = vec![
+            Token::At(testfilepos()),
+            Token::Name(testfilepos(), "derive".to_string(), "derive".to_string()),
+            Token::ParensOpen(testfilepos()),
+            Token::Name(testfilepos(), "Equals".to_string(), "Equals".to_string()),
+            Token::Comma(testfilepos()),
+            Token::Name(testfilepos(), "Displayable".to_string(), "Displayable".to_string()),
+            Token::ParensClose(testfilepos()),
+
+            Token::KwClass(testfilepos()),
+            Token::Name(testfilepos(), "pkg.MyClass".to_string(), "MyClass".to_string()),
+            Token::CurlyOpen(testfilepos()),
+            Token::CurlyClose(testfilepos()),
+
+            Token::At(testfilepos()),
+            Token::Name(testfilepos(), "derive".to_string(), "derive".to_string()),
+            Token::ParensOpen(testfilepos()),
+            Token::Name(testfilepos(), "Comparable".to_string(), "Comparable".to_string()),
+            Token::ParensClose(testfilepos()),
+
+            Token::KwClass(testfilepos()),
+            Token::Name(testfilepos(), "pkg.OtherClass".to_string(), "OtherClass".to_string()),
+            Token::CurlyOpen(testfilepos()),
+            Token::CurlyClose(testfilepos()),
+            Token::Eof(testfilepos())
+        ];
+
+        let mut parse_table = ParseTable::new();
+        let res;
+
+        {
+            let mut parser = RootParser::new(&tokens, &mut parse_table);
+
+            res = parser.start_parse();
+        }
+
+        assert_eq!(
+            "Behavior `Comparable` cannot be derived, derivable behaviors are `Equals`, `Hashable`, `Displayable`, `Cloneable`",
+            res.err().unwrap().get_message()
+        );
+
+        assert_eq!(
+            vec!["Equals".to_string(), "Displayable".to_string()],
+            parse_table.classes.get("pkg.MyClass").unwrap().derives()
+        );
+    }
+
+    #[test]
+    fn test_attributes_are_parsed_on_declarations() {
+        let tokens = vec![
+            Token::At(testfilepos()),
+            Token::Name(testfilepos(), "test".to_string(), "test".to_string()),
+            Token::At(testfilepos()),
+            Token::Name(testfilepos(), "deprecated".to_string(), "deprecated".to_string()),
+            Token::ParensOpen(testfilepos()),
+            Token::StringValue(testfilepos(), "use something else".to_string()),
+            Token::ParensClose(testfilepos()),
+            Token::KwFunction(testfilepos()),
+            Token::Name(testfilepos(), "pkg.checksThings".to_string(), "checksThings".to_string()),
+            Token::ParensOpen(testfilepos()),
+            Token::ParensClose(testfilepos()),
+            Token::CurlyOpen(testfilepos()),
+            Token::CurlyClose(testfilepos()),
+
+            Token::KwClass(testfilepos()),
+            Token::Name(testfilepos(), "pkg.MyClass".to_string(), "MyClass".to_string()),
+            Token::CurlyOpen(testfilepos()),
+
+            Token::At(testfilepos()),
+            Token::Name(testfilepos(), "serialized".to_string(), "serialized".to_string()),
+            Token::KwProperty(testfilepos()),
+            Token::Name(testfilepos(), "propname".to_string(), "propname".to_string()),
+            Token::SingleQuote(testfilepos()),
+            Token::TypeInteger(testfilepos()),
+            Token::EndStatement(testfilepos()),
+
+            Token::At(testfilepos()),
+            Token::Name(testfilepos(), "custom".to_string(), "custom".to_string()),
+            Token::ParensOpen(testfilepos()),
+            Token::IntegerValue(testfilepos(), 1),
+            Token::Comma(testfilepos()),
+            Token::Name(testfilepos(), "thing".to_string(), "thing".to_string()),
+            Token::ParensClose(testfilepos()),
+            Token::KwMethod(testfilepos()),
+            Token::Name(testfilepos(), "helloThere".to_string(), "helloThere".to_string()),
+            Token::ParensOpen(testfilepos()),
+            Token::ParensClose(testfilepos()),
+            Token::CurlyOpen(testfilepos()),
+            Token::CurlyClose(testfilepos()),
+
+            Token::CurlyClose(testfilepos()),
+            Token::Eof(testfilepos())
+        ];
+
+        let mut parse_table = ParseTable::new();
+
+        {
+            let mut parser = RootParser::new(&tokens, &mut parse_table);
+
+            let res = parser.start_parse();
+
+            if res.is_err() {
+                eprintln!("{:?}", res.err().unwrap());
+                panic!();
+            }
+        }
+
+        let fnattrs = parse_table.functions.get("pkg.checksThings").unwrap().attributes.clone();
+
+        assert_eq!(2, fnattrs.len());
+        assert_eq!("test".to_string(), fnattrs[0].name);
+        assert_eq!("deprecated".to_string(), fnattrs[1].name);
+        assert_eq!(vec![Value::str("use something else".to_string())], fnattrs[1].args);
+
+        let class_definition = parse_table.classes.get("pkg.MyClass").unwrap();
+
+        assert_eq!(0, class_definition.attributes.len());
+
+        let propattrs = class_definition.properties.get("propname").unwrap().attributes.clone();
+
+        assert_eq!(1, propattrs.len());
+        assert_eq!("serialized".to_string(), propattrs[0].name);
+        assert_eq!(0, propattrs[0].args.len());
+
+        let methattrs = class_definition.methods.get("helloThere").unwrap().attributes.clone();
+
+        assert_eq!(1, methattrs.len());
+        assert_eq!("custom".to_string(), methattrs[0].name);
+        assert_eq!(vec![Value::int(1), Value::name("thing".to_string(), Vec::new())], methattrs[0].args);
+    }
+
+    #[test]
+    fn test_attributes_are_validated() {
+        let tokens = vec![
+            Token::At(testfilepos()),
+            Token::Name(testfilepos(), "test".to_string(), "test".to_string()),
+            Token::KwClass(testfilepos()),
+            Token::Name(testfilepos(), "pkg.MyClass".to_string(), "MyClass".to_string()),
+            Token::CurlyOpen(testfilepos()),
+            Token::CurlyClose(testfilepos()),
+            Token::Eof(testfilepos())
+        ];
+
+        let mut parse_table = ParseTable::new();
+        let res;
+
+        {
+            let mut parser = RootParser::new(&tokens, &mut parse_table);
+
+            res = parser.start_parse();
+        }
+
+        assert_eq!(
+            "Attribute `@test` cannot be used on a class",
+            res.err().unwrap().get_message()
+        );
+
+        let tokens = vec![
+            Token::At(testfilepos()),
+            Token::Name(testfilepos(), "test".to_string(), "test".to_string()),
+            Token::At(testfilepos()),
+            Token::Name(testfilepos(), "test".to_string(), "test".to_string()),
+            Token::KwFunction(testfilepos()),
+            Token::Name(testfilepos(), "pkg.checksThings".to_string(), "checksThings".to_string()),
+            Token::ParensOpen(testfilepos()),
+            Token::ParensClose(testfilepos()),
+            Token::CurlyOpen(testfilepos()),
+            Token::CurlyClose(testfilepos()),
+            Token::Eof(testfilepos())
+        ];
+
+        let mut parse_table = ParseTable::new();
+        let res;
+
+        {
+            let mut parser = RootParser::new(&tokens, &mut parse_table);
+
+            res = parser.start_parse();
+        }
+
+        assert_eq!(
+            "Attribute `@test` given multiple times",
+            res.err().unwrap().get_message()
+        );
+
+        let tokens = vec![
+            Token::At(testfilepos()),
+            Token::Name(testfilepos(), "serialized".to_string(), "serialized".to_string()),
+            Token::KwFunction(testfilepos()),
+            Token::Name(testfilepos(), "pkg.checksThings".to_string(), "checksThings".to_string()),
+            Token::ParensOpen(testfilepos()),
+            Token::ParensClose(testfilepos()),
+            Token::CurlyOpen(testfilepos()),
+            Token::CurlyClose(testfilepos()),
+            Token::Eof(testfilepos())
+        ];
+
+        let mut parse_table = ParseTable::new();
+        let res;
+
+        {
+            let mut parser = RootParser::new(&tokens, &mut parse_table);
+
+            res = parser.start_parse();
+        }
+
+        assert_eq!(
+            "Unknown attribute `@serialized` on a function",
+            res.err().unwrap().get_message()
+        );
+    }
+
+    #[test]
+    fn test_root_declaration_visibility_is_parsed() {
+        let tokens = vec![
+            Token::KwPublic(testfilepos()),
+            Token::KwFunction(testfilepos()),
+            Token::Name(testfilepos(), "pkg.exposed".to_string(), "exposed".to_string()),
+            Token::ParensOpen(testfilepos()),
+            Token::ParensClose(testfilepos()),
+            Token::CurlyOpen(testfilepos()),
+            Token::CurlyClose(testfilepos()),
+
+            Token::KwFunction(testfilepos()),
+            Token::Name(testfilepos(), "pkg.helper".to_string(), "helper".to_string()),
+            Token::ParensOpen(testfilepos()),
+            Token::ParensClose(testfilepos()),
+            Token::CurlyOpen(testfilepos()),
+            Token::CurlyClose(testfilepos()),
+
+            Token::At(testfilepos()),
+            Token::Name(testfilepos(), "deprecated".to_string(), "deprecated".to_string()),
+            Token::KwPublic(testfilepos()),
+            Token::KwClass(testfilepos()),
+            Token::Name(testfilepos(), "pkg.MyClass".to_string(), "MyClass".to_string()),
+            Token::CurlyOpen(testfilepos()),
+            Token::CurlyClose(testfilepos()),
+
+            Token::KwPublic(testfilepos()),
+            Token::KwBehavior(testfilepos()),
+            Token::Name(testfilepos(), "pkg.MyBehavior".to_string(), "MyBehavior".to_string()),
+            Token::CurlyOpen(testfilepos()),
+            Token::CurlyClose(testfilepos()),
+
+            Token::KwPublic(testfilepos()),
+            Token::KwConstant(testfilepos()),
+            Token::Name(testfilepos(), "FOOBAR".to_string(), "FOOBAR".to_string()),
+            Token::SingleQuote(testfilepos()),
+            Token::TypeInteger(testfilepos()),
+            Token::Assign(testfilepos()),
+            Token::IntegerValue(testfilepos(), 1),
+            Token::EndStatement(testfilepos()),
+            Token::Eof(testfilepos())
+        ];
+
+        let mut parse_table = ParseTable::new();
+
+        {
+            let mut parser = RootParser::new(&tokens, &mut parse_table);
+
+            let res = parser.start_parse();
+
+            if res.is_err() {
+                eprintln!("{:?}", res.err().unwrap());
+                panic!();
+            }
+        }
+
+        assert_eq!(MemberVisibility::Public, parse_table.functions.get("pkg.exposed").unwrap().visibility);
+        assert_eq!(MemberVisibility::Private, parse_table.functions.get("pkg.helper").unwrap().visibility);
+        assert_eq!(1, parse_table.classes.get("pkg.MyClass").unwrap().attributes.len());
+        assert_eq!(MemberVisibility::Public, parse_table.classes.get("pkg.MyClass").unwrap().visibility);
+        assert_eq!(MemberVisibility::Public, parse_table.behaviors.get("pkg.MyBehavior").unwrap().visibility);
+        assert_eq!(MemberVisibility::Public, parse_table.constant_visibilities["FOOBAR"]);
+        assert!(parse_table.constants.contains_key("FOOBAR"));
+    }
+
+    #[test]
+    fn test_type_aliases_are_parsed_and_expanded() {
+        let tokens = vec![
+            // function declared before the aliases it uses
+            Token::KwFunction(testfilepos()),
+            Token::Name(testfilepos(), "pkg.main".to_string(), "main".to_string()),
+            Token::ParensOpen(testfilepos()),
+            Token::Name(testfilepos(), "scores".to_string(), "scores".to_string()),
+            Token::SingleQuote(testfilepos()),
+            Token::Name(testfilepos(), "pkg.Named".to_string(), "Named".to_string()),
+            Token::OpLt(testfilepos()),
+            Token::TypeInteger(testfilepos()),
+            Token::OpGt(testfilepos()),
+            Token::ParensClose(testfilepos()),
+            Token::TypeInteger(testfilepos()),
+            Token::CurlyOpen(testfilepos()),
+            Token::CurlyClose(testfilepos()),
+
+            Token::KwType(testfilepos()),
+            Token::Name(testfilepos(), "pkg.Named".to_string(), "Named".to_string()),
+            Token::OpLt(testfilepos()),
+            Token::Name(testfilepos(), "T".to_string(), "T".to_string()),
+            Token::OpGt(testfilepos()),
+            Token::Assign(testfilepos()),
+            Token::Name(testfilepos(), "pkg.Pair".to_string(), "Pair".to_string()),
+            Token::OpLt(testfilepos()),
+            Token::TypeString(testfilepos()),
+            Token::Comma(testfilepos()),
+            Token::Name(testfilepos(), "T".to_string(), "T".to_string()),
+            Token::OpGt(testfilepos()),
+            Token::EndStatement(testfilepos()),
+
+            Token::KwPublic(testfilepos()),
+            Token::KwType(testfilepos()),
+            Token::Name(testfilepos(), "pkg.Pair".to_string(), "Pair".to_string()),
+            Token::OpLt(testfilepos()),
+            Token::Name(testfilepos(), "A".to_string(), "A".to_string()),
+            Token::Comma(testfilepos()),
+            Token::Name(testfilepos(), "B".to_string(), "B".to_string()),
+            Token::OpGt(testfilepos()),
+            Token::Assign(testfilepos()),
+            Token::ParensOpen(testfilepos()),
+            Token::Name(testfilepos(), "A".to_string(), "A".to_string()),
+            Token::Comma(testfilepos()),
+            Token::Name(testfilepos(), "List".to_string(), "List".to_string()),
+            Token::OpLt(testfilepos()),
+            Token::Name(testfilepos(), "B".to_string(), "B".to_string()),
+            Token::OpGt(testfilepos()),
+            Token::ParensClose(testfilepos()),
+            Token::EndStatement(testfilepos()),
+            Token::Eof(testfilepos())
+        ];
+
+        let mut parse_table = ParseTable::new();
+
+        {
+            let mut parser = RootParser::new(&tokens, &mut parse_table);
+
+            let res = parser.start_parse();
+
+            if res.is_err() {
+                eprintln!("{:?}", res.err().unwrap());
+                panic!();
+            }
+        }
+
+        assert_eq!(2, parse_table.type_aliases.len());
+        assert_eq!(vec!['A', 'B'], parse_table.type_aliases.get("pkg.Pair").unwrap().type_params);
+
+        let params = parse_table.functions.get("pkg.main").unwrap().parameters.clone();
+
+        assert_eq!(
+            Box::new(SahaType::Tuple(vec![
+                Box::new(SahaType::Str),
+                Box::new(SahaType::Name("List".to_string(), vec![Box::new(SahaType::Int)]))
+            ])),
+            params.get("scores").unwrap().param_type
+        );
+    }
+
+    #[test]
+    fn test_recursive_type_aliases_are_rejected() {
+        let tokens = vec![
+            Token::KwType(testfilepos()),
+            Token::Name(testfilepos(), "pkg.Nested".to_string(), "Nested".to_string()),
+            Token::Assign(testfilepos()),
+            Token::Name(testfilepos(), "List".to_string(), "List".to_string()),
+            Token::OpLt(testfilepos()),
+            Token::Name(testfilepos(), "pkg.Nested".to_string(), "Nested".to_string()),
+            Token::OpGt(testfilepos()),
+            Token::EndStatement(testfilepos()),
+
+            Token::KwFunction(testfilepos()),
+            Token::Name(testfilepos(), "pkg.main".to_string(), "main".to_string()),
+            Token::ParensOpen(testfilepos()),
+            Token::ParensClose(testfilepos()),
+            Token::Name(testfilepos(), "pkg.Nested".to_string(), "Nested".to_string()),
+            Token::CurlyOpen(testfilepos()),
+            Token::CurlyClose(testfilepos()),
+            Token::Eof(testfilepos())
+        ];
+
+        let mut parse_table = ParseTable::new();
+        let res;
+
+        {
+            let mut parser = RootParser::new(&tokens, &mut parse_table);
+
+            res = parser.start_parse();
+        }
+
+        assert_eq!(
+            "Type alias `Nested` refers to itself",
+            res.err().unwrap().get_message()
+        );
+    }
+
+    #[test]
+    fn test_declarations_after_std_imports_are_parsed() {
+        let tokens = vec![
+            Token::Import(testfilepos(), Import::Std("std.io.print_line".to_string(), "say".to_string())),
+            Token::KwFunction(testfilepos()),
+            Token::Name(testfilepos(), "pkg.main".to_string(), "main".to_string()),
+            Token::ParensOpen(testfilepos()),
+            Token::ParensClose(testfilepos()),
+            Token::CurlyOpen(testfilepos()),
+            Token::CurlyClose(testfilepos()),
+            Token::Eof(testfilepos())
+        ];
+
+        let mut parse_table = ParseTable::new();
+
+        {
+            let mut parser = RootParser::new(&tokens, &mut parse_table);
+
+            let res = parser.start_parse();
+
+            if res.is_err() {
+                eprintln!("{:?}", res.err().unwrap());
+                panic!();
+            }
+        }
+
+        assert!(parse_table.functions.contains_key("pkg.main"));
+    }
 }
\ No newline at end of file
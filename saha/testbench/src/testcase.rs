@@ -12,6 +12,8 @@ use regex;
 pub struct TestCase {
     pub file: PathBuf,
     pub saha_source: Option<String>,
+    pub stdin_input: Option<String>,
+    pub args: Vec<Vec<String>>,
     pub desired_output: Option<String>,
     pub desired_exit_code: Option<i32>,
     pub actual_output: Option<String>,
@@ -42,13 +44,39 @@ impl TestCaseCollection {
     }
 }
 
-/// Parse the Saha source code from the test case source.
+/// Parse the optional interpreter arguments from the test case source, given
+/// before the Saha source code. Each line holds the arguments of one run.
+fn get_test_case_args(source: &str) -> Vec<Vec<String>> {
+    let match_ptrn = regex::Regex::new("(?s)^-----BEGIN ARGS-----\n(.*?)\n-----BEGIN (?:SOURCE|STDIN|OUTPUT)-----.*").unwrap();
+
+    return match match_ptrn.captures(source) {
+        Some(matched) => matched[1].lines()
+            .filter(|l| !l.trim().is_empty())
+            .map(|l| l.split_whitespace().map(|a| a.to_string()).collect())
+            .collect(),
+        None => Vec::new()
+    };
+}
+
+/// Parse the Saha source code from the test case source. Test cases which run
+/// a source file given in the arguments can leave the source code out, in
+/// which case nothing is written to the STDIN.
 fn get_test_case_source(source: &str) -> String {
-    let match_ptrn = regex::Regex::new("(?s)^-----BEGIN SOURCE-----\n(.*?)\n-----BEGIN OUTPUT-----.*").unwrap();
+    let match_ptrn = regex::Regex::new("(?s)^(?:-----BEGIN ARGS-----\n.*?\n)?-----BEGIN SOURCE-----\n(.*?)\n-----BEGIN OUTPUT-----.*").unwrap();
 
-    let matched = match_ptrn.captures(source).unwrap();
+    return match match_ptrn.captures(source) {
+        Some(matched) => matched[1].to_string(),
+        None => String::new()
+    };
+}
 
-    return matched[1].to_string();
+/// Parse the optional input for the program from the test case source. Test
+/// cases which run a source file given in the arguments can pipe input to the
+/// program in place of the source code.
+fn get_test_case_stdin_input(source: &str) -> Option<String> {
+    let match_ptrn = regex::Regex::new("(?s)^(?:-----BEGIN ARGS-----\n.*?\n)?-----BEGIN STDIN-----\n(.*?)\n-----BEGIN OUTPUT-----.*").unwrap();
+
+    return match_ptrn.captures(source).map(|matched| format!("{}\n", &matched[1]));
 }
 
 /// Parse the output part from the test case source.
@@ -81,6 +109,8 @@ pub fn parse_files_to_test_cases(files: HashMap<PathBuf, Result<String, ()>>) ->
             return TestCase {
                 file: path,
                 saha_source: None,
+                stdin_input: None,
+                args: Vec::new(),
                 desired_output: None,
                 desired_exit_code: None,
                 actual_output: None,
@@ -91,17 +121,21 @@ pub fn parse_files_to_test_cases(files: HashMap<PathBuf, Result<String, ()>>) ->
 
         let source = source.ok().unwrap();
 
+        let test_args = get_test_case_args(&source);
         let mut test_source = get_test_case_source(&source);
+        let test_stdin = get_test_case_stdin_input(&source);
         let test_output = get_test_case_desired_output(&source);
         let test_exit = get_test_case_desired_exit_code(&source);
 
-        if !test_source.contains("function main() int") {
+        if !test_source.is_empty() && !test_source.contains("function main() int") {
             test_source = wrap_source_into_main_fn(test_source);
         }
 
         TestCase {
             file: path,
             saha_source: Some(test_source),
+            stdin_input: test_stdin,
+            args: test_args,
             desired_output: Some(test_output),
             desired_exit_code: Some(test_exit),
             actual_output: None,
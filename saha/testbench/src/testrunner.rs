@@ -1,6 +1,7 @@
 //! Test runner
 
 use std::{
+    env::current_dir,
     thread,
     sync::{Arc, Mutex},
     io::Write,
@@ -206,7 +207,46 @@ struct BinRunner {
 }
 
 impl BinRunner {
+    /// Run the command for a test case once per line of its arguments. The
+    /// outputs of the runs are concatenated, and the exit code of the last run
+    /// is the exit code of the test case.
     pub fn run(&self, test_case: &TestCase) -> TestResult {
+        let no_args: Vec<Vec<String>> = vec![Vec::new()];
+        let runs = if test_case.args.is_empty() { &no_args } else { &test_case.args };
+
+        let mut actual_output = String::new();
+        let mut actual_exit: i32 = -1024;
+
+        for args in runs {
+            let (output, exit) = match self.run_once(test_case, args) {
+                Ok(result) => result,
+                Err(_) => {
+                    return TestResult {
+                        test_case: test_case.clone(),
+                        success: false,
+                        actual_output: "".to_string(),
+                        actual_exit: -1024
+                    };
+                }
+            };
+
+            actual_output.push_str(&output);
+            actual_exit = exit;
+        }
+
+        let result = TestResult {
+            test_case: test_case.clone(),
+            success: test_case.desired_output.clone().unwrap() == actual_output && test_case.desired_exit_code.unwrap() == actual_exit,
+            actual_output: actual_output,
+            actual_exit: actual_exit
+        };
+
+        return result;
+    }
+
+    /// Run the command once with the given arguments, and get its output and
+    /// exit code.
+    fn run_once(&self, test_case: &TestCase, args: &[String]) -> Result<(String, i32), ()> {
         let test_cmd = self.command.clone();
 
         let mut cmd_parts: Vec<&str> = test_cmd.split(' ').collect();
@@ -219,6 +259,8 @@ impl BinRunner {
             cmd_src.arg(p);
         }
 
+        cmd_src.args(args);
+
         let mut cmd = cmd_src
             .stdin(Stdio::piped())
             .stdout(Stdio::piped())
@@ -226,15 +268,15 @@ impl BinRunner {
             .spawn()
             .expect("Failed to execute test command");
 
-        let stdin_result = cmd.stdin.as_mut().unwrap().write_all(test_case.saha_source.clone().unwrap().as_bytes());
+        let stdin_data = match &test_case.stdin_input {
+            Some(input) => input.clone(),
+            None => test_case.saha_source.clone().unwrap()
+        };
+
+        let stdin_result = cmd.stdin.as_mut().unwrap().write_all(stdin_data.as_bytes());
 
         if stdin_result.is_err() {
-            return TestResult {
-                test_case: test_case.clone(),
-                success: false,
-                actual_output: "".to_string(),
-                actual_exit: -1024
-            };
+            return Err(());
         }
 
         let output = cmd.wait_with_output().unwrap();
@@ -246,13 +288,11 @@ impl BinRunner {
             actual_output = String::from_utf8_lossy(&output.stderr).to_string();
         }
 
-        let result = TestResult {
-            test_case: test_case.clone(),
-            success: test_case.desired_output.clone().unwrap() == actual_output && test_case.desired_exit_code.clone().unwrap() == actual_exit,
-            actual_output: actual_output,
-            actual_exit: actual_exit
-        };
+        // source file paths in the output are relative to where the tests are run from
+        if let Ok(cwd) = current_dir() {
+            actual_output = actual_output.replace(&format!("{}/", cwd.display()), "");
+        }
 
-        return result;
+        return Ok((actual_output, actual_exit));
     }
 }
\ No newline at end of file
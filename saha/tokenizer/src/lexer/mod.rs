@@ -32,7 +32,9 @@ pub enum Lexeme {
     /// Numbers, e.g. `1`, `123`, `3.14`, `-3`, `-6.66`...
     Number(FilePosition, String),
 
-    /// Anything wrapped in `"` characters.
+    /// Anything wrapped in `"` characters, or `"""` for multi-line strings.
+    /// Escape sequences are decoded unless the string is raw (`r"..."`), and
+    /// the value is kept wrapped in a single pair of `"` characters.
     String(FilePosition, String),
 
     /// Anything preceded by two slashes (`//`).
@@ -96,6 +98,91 @@ impl<'a> Lexer<'a> {
         };
     }
 
+    /// Read the contents of a string literal, starting right after the opening
+    /// delimiter and ending after the closing delimiter.
+    ///
+    /// Each character is paired with its source position so escape sequence
+    /// errors can point to the exact column. Escaped characters are kept as
+    /// `\\` + character pairs for `decode_escape_sequences`, except for a
+    /// backslash followed by a newline, which continues the string on the next
+    /// line.
+    #[allow(clippy::too_many_arguments)]
+    fn read_string_contents(
+        &self,
+        source_chars: &[char],
+        char_index: &mut usize,
+        current_line: &mut usize,
+        current_column: &mut i32,
+        string_begin_pos: &FilePosition,
+        is_raw: bool,
+        is_multiline: bool
+    ) -> Result<Vec<(char, FilePosition)>, ParseError> {
+        let mut contents: Vec<(char, FilePosition)> = Vec::new();
+
+        loop {
+            let current_character = match source_chars.get(*char_index) {
+                Some(c) => *c,
+                None => return Err(ParseError::new(
+                    "Unterminated string encountered",
+                    Some(string_begin_pos.clone())
+                ))
+            };
+
+            *char_index += 1;
+            *current_column += 1;
+
+            let current_pos = self.new_filepos(*current_line, *current_column);
+
+            if current_character == '"' {
+                if !is_multiline {
+                    return Ok(contents);
+                }
+
+                if source_chars.get(*char_index) == Some(&'"') && source_chars.get(*char_index + 1) == Some(&'"') {
+                    *char_index += 2;
+                    *current_column += 2;
+
+                    return Ok(contents);
+                }
+            }
+
+            if current_character == '\\' && !is_raw {
+                match source_chars.get(*char_index) {
+                    Some('\n') => {
+                        // line continuation
+                        *char_index += 1;
+                        *current_line += 1;
+                        *current_column = 0;
+                    },
+                    Some(escaped) => {
+                        contents.push((current_character, current_pos.clone()));
+                        contents.push((*escaped, current_pos));
+
+                        *char_index += 1;
+                        *current_column += 1;
+                    },
+                    None => contents.push((current_character, current_pos)),
+                };
+
+                continue;
+            }
+
+            if current_character == '\n' {
+                if !is_multiline {
+                    return Err(ParseError::new(
+                        "Unterminated string encountered",
+                        Some(self.new_filepos(*current_line, 0)))
+                    );
+                }
+
+                *current_line += 1;
+                *current_column = 0;
+            }
+
+            contents.push((current_character, current_pos));
+        }
+    }
+
     /// Iterate over a string and collect Lexemes.
     ///
     /// We prioritize parsing string values (encased in `"`) and comments (lines
@@ -107,7 +194,6 @@ impl<'a> Lexer<'a> {
         let mut previous_character: char = 'a';
         let mut char_buffer: Vec<char> = Vec::new();
         let mut comment_buffer: Vec<char> = Vec::new();
-        let mut string_buffer: Vec<char> = Vec::new();
         let mut number_buffer: Vec<char> = Vec::new();
 
         let word_joiners = ['_', '.'];
@@ -118,11 +204,14 @@ impl<'a> Lexer<'a> {
         let mut comment_maybe_starts = false;
         let mut comment_can_start = true;
         let mut comment_is_open = false;
-        let mut string_is_open = false;
 
-        let mut string_begin_pos: Option<FilePosition> = None;
+        let source_chars: Vec<char> = self.source_string.chars().collect();
+        let mut char_index = 0;
 
-        for current_character in self.source_string.chars() {
+        while char_index < source_chars.len() {
+            let current_character = source_chars[char_index];
+
+            char_index += 1;
             current_column += 1;
 
             if comment_maybe_starts {
@@ -177,55 +266,72 @@ impl<'a> Lexer<'a> {
                 comment_maybe_starts = true;
             }
 
-            if string_is_open {
-                comment_can_start = false;
-                comment_maybe_starts = false;
-                let last_string_char: char;
-                let buf = string_buffer.clone();
+            if current_character == '"' {
+                // string delimiter, a lone `r` word right before it marks a raw
+                // string
+                let is_raw = char_buffer == ['r'];
+                let mut string_begin_column = current_column;
+
+                if is_raw {
+                    char_buffer.clear();
+                    string_begin_column -= 1;
+                }
 
-                last_string_char = *buf.last().unwrap_or(&'-');
+                if !char_buffer.is_empty() {
+                    lexemes.push(Lexeme::Word(
+                        self.new_filepos(current_line, current_column - char_buffer.len() as i32),
+                        char_buffer.iter().collect()
+                    ));
 
-                string_buffer.push(current_character);
+                    char_buffer.clear();
+                }
 
-                if current_character == '\n' {
-                    if last_string_char != '\\' {
-                        return Err(ParseError::new(
-                            "Unterminated string encountered",
-                            Some(self.new_filepos(current_line, 0)))
-                        );
-                    }
+                if !number_buffer.is_empty() {
+                    lexemes.push(Lexeme::Number(
+                        self.new_filepos(current_line, current_column - number_buffer.len() as i32),
+                        number_buffer.iter().collect()
+                    ));
 
-                    current_line += 1;
-                    current_column = 0;
+                    number_buffer.clear();
                 }
 
-                if current_character == '"' && last_string_char != '\\' {
-                    string_is_open = false;
-                    comment_can_start = true;
-                    let cur_buffer_pos = string_begin_pos.clone().unwrap();
+                let string_begin_pos = self.new_filepos(current_line, string_begin_column);
 
-                    lexemes.push(Lexeme::String(
-                        cur_buffer_pos,
-                        string_buffer.iter().collect())
-                    );
+                let is_multiline = source_chars.get(char_index) == Some(&'"')
+                    && source_chars.get(char_index + 1) == Some(&'"');
 
-                    string_buffer.clear();
+                if is_multiline {
+                    char_index += 2;
+                    current_column += 2;
                 }
 
-                continue;
-            }
-
+                let mut contents = self.read_string_contents(
+                    &source_chars,
+                    &mut char_index,
+                    &mut current_line,
+                    &mut current_column,
+                    &string_begin_pos,
+                    is_raw,
+                    is_multiline
+                )?;
+
+                if is_multiline {
+                    contents = strip_common_indentation(contents);
+                }
 
-            if current_character == '"' {
-                // string delimiter
-                string_is_open = true;
-                comment_can_start = false;
+                let string_value: String = if is_raw {
+                    contents.iter().map(|(c, _)| c).collect()
+                } else {
+                    decode_escape_sequences(&contents)?
+                };
 
-                string_begin_pos = Some(self.new_filepos(current_line, current_column));
+                // Delimiters are kept so the tokenizer can tell the value apart
+                // from the surrounding source.
+                lexemes.push(Lexeme::String(string_begin_pos, format!("\"{}\"", string_value)));
 
-                string_buffer.push(current_character);
+                comment_can_start = true;
+                previous_character = '"';
 
-                // This continue opens the string loop
                 continue;
             } else if current_character == '\n' {
                 current_character_type = "newline";
@@ -431,6 +537,128 @@ impl<'a> Lexer<'a> {
     }
 }
 
+/// Decode escape sequences in string literal contents.
+///
+/// Supported sequences are `\\n`, `\\t`, `\\r`, `\\0`, `\\\\`, `\\"` and
+/// `\\u{...}` with 1 to 6 hexadecimal digits.
+fn decode_escape_sequences(contents: &[(char, FilePosition)]) -> Result<String, ParseError> {
+    let mut decoded = String::new();
+    let mut chars = contents.iter();
+
+    while let Some((current_character, pos)) = chars.next() {
+        if *current_character != '\\' {
+            decoded.push(*current_character);
+            continue;
+        }
+
+        let escaped = match chars.next() {
+            Some((c, _)) => *c,
+            None => return Err(ParseError::new("Incomplete escape sequence `\\`", Some(pos.clone())))
+        };
+
+        let decoded_character = match escaped {
+            'n' => '\n',
+            't' => '\t',
+            'r' => '\r',
+            '0' => '\0',
+            '\\' => '\\',
+            '"' => '"',
+            'u' => {
+                let mut sequence: Vec<char> = Vec::new();
+
+                for (c, _) in chars.by_ref() {
+                    sequence.push(*c);
+
+                    if *c == '}' {
+                        break;
+                    }
+                }
+
+                let sequence_string: String = sequence.iter().collect();
+
+                match parse_unicode_escape(&sequence) {
+                    Some(c) => c,
+                    None => return Err(ParseError::new(
+                        &format!("Invalid unicode escape sequence `\\u{}`", sequence_string),
+                        Some(pos.clone())
+                    ))
+                }
+            },
+            _ => return Err(ParseError::new(
+                &format!("Invalid escape sequence `\\{}`", escaped),
+                Some(pos.clone())
+            ))
+        };
+
+        decoded.push(decoded_character);
+    }
+
+    return Ok(decoded);
+}
+
+/// Parse the `{...}` part of a `\\u{...}` escape into a character.
+fn parse_unicode_escape(sequence: &[char]) -> Option<char> {
+    if sequence.len() < 3 || sequence.len() > 8 || sequence[0] != '{' || sequence[sequence.len() - 1] != '}' {
+        return None;
+    }
+
+    let hex: String = sequence[1..sequence.len() - 1].iter().collect();
+
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    return match u32::from_str_radix(&hex, 16) {
+        Ok(codepoint) => std::char::from_u32(codepoint),
+        Err(_) => None
+    };
+}
+
+/// Strip common indentation from multi-line string contents.
+///
+/// A whitespace-only first line (the rest of the line with the opening `"""`)
+/// and a whitespace-only last line (the indentation before the closing `"""`)
+/// are dropped, after which the smallest indentation of non-blank lines is
+/// removed from every line.
+fn strip_common_indentation(contents: Vec<(char, FilePosition)>) -> Vec<(char, FilePosition)> {
+    let is_indentation = |c: &char| *c == ' ' || *c == '\t';
+    let mut contents = contents;
+
+    if let Some(first_newline) = contents.iter().position(|(c, _)| *c == '\n') {
+        if contents[..first_newline].iter().all(|(c, _)| is_indentation(c)) {
+            contents.drain(..=first_newline);
+        }
+    }
+
+    if let Some(last_newline) = contents.iter().rposition(|(c, _)| *c == '\n') {
+        if contents[last_newline + 1..].iter().all(|(c, _)| is_indentation(c)) {
+            contents.truncate(last_newline);
+        }
+    }
+
+    let lines: Vec<&[(char, FilePosition)]> = contents.split_inclusive(|(c, _)| *c == '\n').collect();
+
+    let is_blank = |line: &[(char, FilePosition)]| line.iter().all(|(c, _)| c.is_whitespace());
+
+    let common_indentation = lines.iter()
+        .filter(|line| !is_blank(line))
+        .map(|line| line.iter().take_while(|(c, _)| is_indentation(c)).count())
+        .min()
+        .unwrap_or(0);
+
+    let mut stripped: Vec<(char, FilePosition)> = Vec::new();
+
+    for line in lines {
+        if is_blank(line) {
+            stripped.extend(line.iter().filter(|(c, _)| *c == '\n').cloned());
+        } else {
+            stripped.extend(line[common_indentation..].iter().cloned());
+        }
+    }
+
+    return stripped;
+}
+
 /// Lexemize a source file.
 ///
 /// Takes in a PathBuf which is read and then passed into a lexer. A path is
@@ -759,4 +987,75 @@ function main() int
             _ => unreachable!()
         };
     }
-}
\ No newline at end of file
+
+    fn get_string_lexemes(source: &str) -> Result<Vec<String>, ParseError> {
+        let test_file = PathBuf::from("/saha/test/file.saha");
+        let mut lexer = Lexer::new(&test_file, source.to_string());
+
+        return Ok(lexer.get_lexemes()?.into_iter().filter_map(|l| match l {
+            Lexeme::String(_, s) => Some(s),
+            _ => None
+        }).collect());
+    }
+
+    #[test]
+    fn test_escape_sequences_are_decoded() {
+        let strings = get_string_lexemes(r#"foo("a\nb\tc\\d\"e\u{48}\u{1F600}");"#).unwrap();
+
+        assert_eq!(vec!["\"a\nb\tc\\d\"eH\u{1F600}\"".to_string()], strings);
+    }
+
+    #[test]
+    fn test_invalid_escape_sequences_fail_at_exact_column() {
+        let err = get_string_lexemes(r#"var foo'str = "ab\qc";"#).err().unwrap();
+
+        assert_eq!("Invalid escape sequence `\\q`", err.get_message());
+        assert_eq!(1, err.get_file_position().unwrap().line);
+        assert_eq!(18, err.get_file_position().unwrap().column);
+
+        let err = get_string_lexemes(r#"  "\u{110000}""#).err().unwrap();
+
+        assert_eq!("Invalid unicode escape sequence `\\u{110000}`", err.get_message());
+        assert_eq!(4, err.get_file_position().unwrap().column);
+    }
+
+    #[test]
+    fn test_raw_strings_are_not_decoded() {
+        let test_file = PathBuf::from("/saha/test/file.saha");
+        let mut lexer = Lexer::new(&test_file, r#"foo r"a\nb\q";"#.to_string());
+        let lexemes = lexer.get_lexemes().unwrap();
+
+        match &lexemes[2] {
+            Lexeme::String(f, s) => {
+                assert_eq!(5, f.column);
+                assert_eq!("\"a\\nb\\q\"", s);
+            },
+            _ => unreachable!()
+        };
+    }
+
+    #[test]
+    fn test_multiline_strings_strip_common_indentation() {
+        let source = "    var foo'str = \"\"\"
+        first
+          second
+
+        third \"quoted\"\\t
+        \"\"\";
+    var bar'str = \"\"\"single\"\"\";";
+
+        let strings = get_string_lexemes(source).unwrap();
+
+        assert_eq!(vec![
+            "\"first\n  second\n\nthird \"quoted\"\t\"".to_string(),
+            "\"single\"".to_string(),
+        ], strings);
+    }
+
+    #[test]
+    fn test_unterminated_strings_fail() {
+        assert!(get_string_lexemes("\"foo\nbar\"").is_err());
+        assert!(get_string_lexemes("\"\"\"foo\nbar\"").is_err());
+        assert_eq!(vec!["\"foobar\"".to_string()], get_string_lexemes("\"foo\\\nbar\"").unwrap());
+    }
+}
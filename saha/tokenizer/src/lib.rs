@@ -83,6 +83,8 @@ pub fn tokenize(parsed_lexemes: Vec<Lexeme>, file: &PathBuf) -> Result<Vec<Token
         tokens = subtokens;
     }
 
+    validate_import_visibility(&already_tokenized_paths, &tokenized_files)?;
+
     let mut flattened_paths: Vec<PathBuf> = Vec::new();
     let mut flattened_tokens: Vec<Token> = Vec::new();
 
@@ -113,6 +115,65 @@ pub fn tokenize(parsed_lexemes: Vec<Lexeme>, file: &PathBuf) -> Result<Vec<Token
     return Ok(flattened_tokens);
 }
 
+/// Validate that names imported from other modules with `use` are declared
+/// with `pub` in the module they are imported from. Names which are not
+/// declared in the module at all are left for later parsing phases to catch.
+fn validate_import_visibility(paths: &[PathBuf], tokenized_files: &HashMap<PathBuf, Vec<Token>>) -> Result<(), ParseError> {
+    for path in paths {
+        for token in &tokenized_files[path] {
+            let (use_pos, source, module_path) = match token {
+                Token::Import(pos, Import::Pkg(source, _, module_path)) => (pos, source, module_path),
+                _ => continue
+            };
+
+            let module_tokens = match tokenized_files.get(module_path) {
+                Some(t) => t,
+                None => continue
+            };
+
+            let member = source.split('.').next_back().unwrap_or_default();
+
+            if is_declared_public(module_tokens, member) == Some(false) {
+                return Err(ParseError::new(
+                    &format!("Cannot use `{}`, it is not declared `pub` in its module", source),
+                    Some(use_pos.to_owned())
+                ));
+            }
+        }
+    }
+
+    return Ok(());
+}
+
+/// Find a root level declaration by name from module tokens, and see whether
+/// it was declared with `pub`. Returns `None` if no such declaration exists.
+fn is_declared_public(tokens: &[Token], name: &str) -> Option<bool> {
+    let mut depth = 0;
+
+    for (idx, token) in tokens.iter().enumerate() {
+        match token {
+            Token::CurlyOpen(..) => depth += 1,
+            Token::CurlyClose(..) => depth -= 1,
+            // names inside bodies, e.g. class methods, are not root level declarations
+            Token::Name(_, _, source) if depth == 0 && idx > 0 && source == name => {
+                match tokens[idx - 1] {
+                    Token::KwFunction(..)
+                    | Token::KwClass(..)
+                    | Token::KwBehavior(..)
+                    | Token::KwType(..)
+                    | Token::KwConstant(..) => {
+                        return Some(idx >= 2 && matches!(tokens[idx - 2], Token::KwPublic(..)));
+                    },
+                    _ => ()
+                };
+            },
+            _ => ()
+        };
+    }
+
+    return None;
+}
+
 /// Tokenize a source file.
 pub fn tokenize_file(file: &PathBuf) -> Result<Vec<Token>, ParseError> {
     let lexemes = lexemize_source_file(file)?;
@@ -161,21 +222,23 @@ mod tests {
 
         let expected: Vec<Token> = vec![
             // imported file qwerty
-            Token::KwFunction(fpos(&usefile2, 1, 1)),
-            Token::Name(fpos(&usefile2, 1, 10), "pkg.flatten_once_more.qwerty".to_string(), "qwerty".to_string()),
-            Token::ParensOpen(fpos(&usefile2, 1, 16)),
-            Token::ParensClose(fpos(&usefile2, 1, 17)),
-            Token::CurlyOpen(fpos(&usefile2, 1, 19)),
-            Token::CurlyClose(fpos(&usefile2, 1, 20)),
+            Token::KwPublic(fpos(&usefile2, 1, 1)),
+            Token::KwFunction(fpos(&usefile2, 1, 5)),
+            Token::Name(fpos(&usefile2, 1, 14), "pkg.flatten_once_more.qwerty".to_string(), "qwerty".to_string()),
+            Token::ParensOpen(fpos(&usefile2, 1, 20)),
+            Token::ParensClose(fpos(&usefile2, 1, 21)),
+            Token::CurlyOpen(fpos(&usefile2, 1, 23)),
+            Token::CurlyClose(fpos(&usefile2, 1, 24)),
             Token::Eof(fpos(&usefile2, 3, 0)),
 
             // imported file foobar
-            Token::KwFunction(fpos(&usefile, 3, 1)),
-            Token::Name(fpos(&usefile, 3, 10), "pkg.flatten_from.foobar".to_string(), "foobar".to_string()),
-            Token::ParensOpen(fpos(&usefile, 3, 16)),
-            Token::ParensClose(fpos(&usefile, 3, 17)),
-            Token::CurlyOpen(fpos(&usefile, 3, 19)),
-            Token::CurlyClose(fpos(&usefile, 3, 20)),
+            Token::KwPublic(fpos(&usefile, 3, 1)),
+            Token::KwFunction(fpos(&usefile, 3, 5)),
+            Token::Name(fpos(&usefile, 3, 14), "pkg.flatten_from.foobar".to_string(), "foobar".to_string()),
+            Token::ParensOpen(fpos(&usefile, 3, 20)),
+            Token::ParensClose(fpos(&usefile, 3, 21)),
+            Token::CurlyOpen(fpos(&usefile, 3, 23)),
+            Token::CurlyClose(fpos(&usefile, 3, 24)),
             Token::Eof(fpos(&usefile, 5, 0)),
 
             // main file
@@ -192,8 +255,42 @@ mod tests {
             Token::Eof(fpos(&mainfile, 8, 0)),
         ];
 
-        let tokenized = tokenize(&mainfile);
+        let tokenized = tokenize_file(&mainfile);
 
         assert_eq!(expected, tokenized.unwrap());
     }
+
+    #[test]
+    fn test_private_declarations_cannot_be_imported() {
+        let mainfile = get_test_sample_file("src/use_private.saha");
+
+        let tokenized = tokenize_file(&mainfile);
+
+        assert_eq!(
+            "Cannot use `pkg.private_helpers.helper`, it is not declared `pub` in its module",
+            tokenized.err().unwrap().get_message()
+        );
+    }
+
+    #[test]
+    fn test_only_root_declarations_are_checked_for_visibility() {
+        let pos = FilePosition::unknown();
+
+        let tokens = vec![
+            Token::KwClass(pos.clone()),
+            Token::Name(pos.clone(), "pkg.mymod.Helpers".to_string(), "Helpers".to_string()),
+            Token::CurlyOpen(pos.clone()),
+            Token::KwFunction(pos.clone()),
+            Token::Name(pos.clone(), "helper".to_string(), "helper".to_string()),
+            Token::CurlyClose(pos.clone()),
+            Token::KwPublic(pos.clone()),
+            Token::KwFunction(pos.clone()),
+            Token::Name(pos.clone(), "pkg.mymod.helper".to_string(), "helper".to_string()),
+            Token::Eof(pos)
+        ];
+
+        assert_eq!(Some(true), is_declared_public(&tokens, "helper"));
+        assert_eq!(Some(false), is_declared_public(&tokens, "Helpers"));
+        assert_eq!(None, is_declared_public(&tokens, "missing"));
+    }
 }
\ No newline at end of file
@@ -106,7 +106,7 @@ impl<'a> Tokenizer<'a> {
     }
 
     /// Parse use keywords to imports and alias names to imports.
-    fn parse_imports(&self, tokens: &[Token], main_file: &PathBuf) -> TokenizationResult {
+    fn parse_imports(&self, tokens: &[Token]) -> TokenizationResult {
         let mut iterable = tokens.iter().peekable();
         let mut parsed: Vec<Token> = Vec::new();
         let mut previous_token: Option<Token> = None;
@@ -222,15 +222,15 @@ impl<'a> Tokenizer<'a> {
                         }
                     }
                 },
-                Token::Name(ref name_pos, ref alias, ref source) => {
-                    // here we declare aliasing for the pkg root stuff, like the program `main()`
+                Token::Name(_, _, ref source) => {
+                    // here we declare aliasing for the module root stuff, like the program `main()`,
+                    // so declarations can be referred to by their name within their own module
                     match previous_token.unwrap_or(Token::Eob) {
                         Token::KwFunction(..)
                         | Token::KwClass(..)
-                        | Token::KwBehavior(..) => {
-                            if &name_pos.path == main_file {
-                                names_to_alias.insert(source.clone(), format!("pkg.{}", alias));
-                            }
+                        | Token::KwBehavior(..)
+                        | Token::KwType(..) => {
+                            names_to_alias.insert(source.clone(), format!("{}.{}", self.module, source));
 
                             parsed.push(current_token.clone());
                         },
@@ -251,7 +251,16 @@ impl<'a> Tokenizer<'a> {
                     let copypos = pos.to_owned();
                     let copysource = source.to_owned();
 
-                    if names_to_alias.contains_key(source) {
+                    // member names are never aliased, e.g. `get` in `dict->get()` stays as is even
+                    // when some imported function is named `get`
+                    let is_member_name = matches!(
+                        alias_previous,
+                        Some(Token::ObjectAccess(..)) | Some(Token::SafeObjectAccess(..)) | Some(Token::StaticAccess(..))
+                    );
+
+                    if is_member_name {
+                        tok.to_owned()
+                    } else if names_to_alias.contains_key(source) {
                         // this alters names to match imported module names
                         let alias_to: String = names_to_alias[source].to_string();
 
@@ -264,6 +273,7 @@ impl<'a> Tokenizer<'a> {
                                 match p {
                                     Token::KwFunction(..) |
                                     Token::KwBehavior(..) |
+                                    Token::KwType(..) |
                                     Token::KwClass(..)  => {
                                         let alias_to = format!("{}.{}", self.module, source);
 
@@ -287,6 +297,42 @@ impl<'a> Tokenizer<'a> {
         return Ok(parsed);
     }
 
+    /// Split tuple element indices from the end of a word, e.g. `my_tuple.0.1`
+    /// becomes `my_tuple` and index tokens for `0` and `1`. Words that only
+    /// contain indices (`.0`) result in an empty name.
+    fn split_tuple_indices(&self, pos: &FilePosition, word: &str) -> Result<(String, Vec<Token>), ParseError> {
+        let segments: Vec<&str> = word.split('.').collect();
+
+        let is_index = |segment: &&str| !segment.is_empty() && segment.chars().all(|c| c.is_ascii_digit());
+
+        let first_index = match segments.iter().skip(1).position(is_index) {
+            Some(idx) => idx + 1,
+            None => return Ok((word.to_string(), Vec::new()))
+        };
+
+        let name = segments[..first_index].join(".");
+        let mut offset = name.chars().count() as i32;
+        let mut indices: Vec<Token> = Vec::new();
+
+        for segment in &segments[first_index..] {
+            let index_pos = pos.shift_col(offset);
+
+            let index = match segment.parse::<usize>() {
+                Ok(idx) if is_index(segment) => idx,
+                _ => return Err(ParseError::new(
+                    &format!("Invalid tuple index `.{}`", segment),
+                    Some(index_pos)
+                ))
+            };
+
+            indices.push(Token::TupleIndex(index_pos, index));
+
+            offset += segment.chars().count() as i32 + 1;
+        }
+
+        return Ok((name, indices));
+    }
+
     /// When the self instance is ready, we can tokenize.
     pub fn tokenize(&mut self) -> TokenizationResult {
         let mut tokens: Vec<Token> = Vec::new();
@@ -362,9 +408,9 @@ impl<'a> Tokenizer<'a> {
                         "]" => Token::BraceClose(fp),
                         "{" => Token::CurlyOpen(fp),
                         "}" => Token::CurlyClose(fp),
-                        "?" => Token::QuestionMark(fp),
                         "," => Token::Comma(fp),
                         ";" => Token::EndStatement(fp),
+                        "@" => Token::At(fp),
 
                         // single ops
                         "-" => Token::OpSub(fp),
@@ -385,13 +431,34 @@ impl<'a> Tokenizer<'a> {
                             }
                         },
 
+                        "?" => {
+                            if &prev_symbol == "?" {
+                                allow_prev_use = false;
+                                tokens.pop();
+                                Token::OpCoalesce(fp.shift_col(-1))
+                            } else {
+                                Token::QuestionMark(fp)
+                            }
+                        },
+
                         ">" => {
                             if ["|", "-"].contains(&prev_symbol.as_str()) {
                                 allow_prev_use = false;
                                 tokens.pop();
                             }
 
+                            // `?->`, the question mark must be directly before the arrow
+                            let is_safe_access = &prev_symbol == "-" && match tokens.last() {
+                                Some(Token::QuestionMark(qpos)) => *qpos == fp.shift_col(-2),
+                                _ => false
+                            };
+
+                            if is_safe_access {
+                                tokens.pop();
+                            }
+
                             match &prev_symbol as &str {
+                                "-" if is_safe_access => Token::SafeObjectAccess(fp.shift_col(-2)),
                                 "-" => Token::ObjectAccess(fp.shift_col(-1)),
                                 "|" => Token::OpPipe(fp.shift_col(-1)),
                                 _ => Token::OpGt(fp)
@@ -450,9 +517,36 @@ impl<'a> Tokenizer<'a> {
                 Lexeme::Word(ref f, ref word) => {
                     prev_symbol = "a".to_string();
 
+                    // variadic parameters, e.g. `...rest`
+                    let (f, word) = match word.strip_prefix("...") {
+                        Some(name) => {
+                            tokens.push(Token::Ellipsis(f.to_owned()));
+
+                            (&f.shift_col(3), name)
+                        },
+                        None => (f, &word[..])
+                    };
+
+                    let (word, tuple_indices) = self.split_tuple_indices(f, word)?;
+
+                    if word.is_empty() {
+                        // a bare `.0` after a call or parentheses
+                        tokens.extend(tuple_indices);
+
+                        continue;
+                    }
+
                     let fp: FilePosition = f.to_owned();
 
+                    // member names may be keywords, e.g. `int` in `rng->int()`
+                    let is_member_name = matches!(
+                        tokens.last(),
+                        Some(Token::ObjectAccess(..)) | Some(Token::SafeObjectAccess(..)) | Some(Token::StaticAccess(..))
+                    );
+
                     let newtoken: Token = match &word as &str {
+                        _ if is_member_name => Token::Name(fp, word.to_owned(), word.to_owned()),
+
                         // boolean value
                         "true" => Token::BooleanValue(fp, true),
                         "false" => Token::BooleanValue(fp, false),
@@ -471,6 +565,7 @@ impl<'a> Tokenizer<'a> {
                         "pub" => Token::KwPublic(fp),
                         "class" => Token::KwClass(fp),
                         "behavior" => Token::KwBehavior(fp),
+                        "type" => Token::KwType(fp),
                         "const" => Token::KwConstant(fp),
                         "prop" => Token::KwProperty(fp),
                         "for" => Token::KwFor(fp),
@@ -484,6 +579,7 @@ impl<'a> Tokenizer<'a> {
                         "use" => Token::KwUse(fp),
                         "as" => Token::KwAs(fp),
                         "implements" => Token::KwImplements(fp),
+                        "extends" => Token::KwExtends(fp),
                         "continue" => Token::KwContinue(fp),
                         "break" => Token::KwBreak(fp),
 
@@ -494,6 +590,7 @@ impl<'a> Tokenizer<'a> {
                     };
 
                     tokens.push(newtoken);
+                    tokens.extend(tuple_indices);
                 },
                 _ => {
                     return Err(ParseError::new("Unknown lexeme encountered", prev_pos));
@@ -503,7 +600,7 @@ impl<'a> Tokenizer<'a> {
             prev_pos = Some(current_lexeme.get_file_position());
         }
 
-        let imports_parsed = self.parse_imports(&tokens, self.main_file)?;
+        let imports_parsed = self.parse_imports(&tokens)?;
 
         return Ok(imports_parsed);
     }
@@ -572,6 +669,34 @@ mod tests {
         assert_eq!(tokens.unwrap(), expected);
     }
 
+    #[test]
+    fn test_tuple_indices_are_split_from_names() {
+        let testpath: PathBuf = get_test_main_file();
+
+        let lexemes = vec![
+            Lexeme::Word(testfilepos(), "foo.0.12".to_string()),
+            Lexeme::Symbol(testfilepos(), ")".to_string()),
+            Lexeme::Word(testfilepos(), ".1".to_string()),
+        ];
+
+        let expected = vec![
+            Token::Name(testfilepos(), "foo".to_string(), "foo".to_string()),
+            Token::TupleIndex(testfilepos().shift_col(3), 0),
+            Token::TupleIndex(testfilepos().shift_col(5), 12),
+            Token::ParensClose(testfilepos()),
+            Token::TupleIndex(testfilepos(), 1),
+        ];
+
+        let mut tokenizer = Tokenizer::new(lexemes, &testpath, String::new());
+
+        assert_eq!(expected, tokenizer.tokenize().unwrap());
+
+        let lexemes = vec![Lexeme::Word(testfilepos(), "foo.0.bar".to_string())];
+        let mut tokenizer = Tokenizer::new(lexemes, &testpath, String::new());
+
+        assert!(tokenizer.tokenize().is_err());
+    }
+
     #[test]
     fn test_tokenizer_tokenizes_basic_sources() {
         let testpath: PathBuf = get_test_main_file();
@@ -847,6 +972,64 @@ mod tests {
         assert_eq!(expected, tokens.unwrap());
     }
 
+    #[test]
+    fn test_module_declarations_are_aliased_within_the_module() {
+        let testpath: PathBuf = get_test_main_file();
+
+        let lexemes = vec![
+            Lexeme::Word(testfilepos(), "pub".to_string()),
+            Lexeme::Whitespace(testfilepos(), " ".to_string()),
+            Lexeme::Word(testfilepos(), "class".to_string()),
+            Lexeme::Whitespace(testfilepos(), " ".to_string()),
+            Lexeme::Word(testfilepos(), "MyClass".to_string()),
+            Lexeme::Whitespace(testfilepos(), " ".to_string()),
+            Lexeme::Symbol(testfilepos(), "{".to_string()),
+            Lexeme::Symbol(testfilepos(), "}".to_string()),
+
+            Lexeme::Newline(testfilepos()),
+
+            Lexeme::Word(testfilepos(), "function".to_string()),
+            Lexeme::Whitespace(testfilepos(), " ".to_string()),
+            Lexeme::Word(testfilepos(), "helper".to_string()),
+            Lexeme::Symbol(testfilepos(), "(".to_string()),
+            Lexeme::Symbol(testfilepos(), ")".to_string()),
+            Lexeme::Whitespace(testfilepos(), " ".to_string()),
+            Lexeme::Symbol(testfilepos(), "{".to_string()),
+            Lexeme::Word(testfilepos(), "new".to_string()),
+            Lexeme::Whitespace(testfilepos(), " ".to_string()),
+            Lexeme::Word(testfilepos(), "MyClass".to_string()),
+            Lexeme::Symbol(testfilepos(), "(".to_string()),
+            Lexeme::Symbol(testfilepos(), ")".to_string()),
+            Lexeme::Symbol(testfilepos(), ";".to_string()),
+            Lexeme::Symbol(testfilepos(), "}".to_string())
+        ];
+
+        let mut tokenizer = Tokenizer::new(lexemes, &testpath, String::from("pkg.mymod"));
+
+        let tokens = tokenizer.tokenize();
+
+        let expected = vec![
+            Token::KwPublic(testfilepos()),
+            Token::KwClass(testfilepos()),
+            Token::Name(testfilepos(), "pkg.mymod.MyClass".to_string(), "MyClass".to_string()),
+            Token::CurlyOpen(testfilepos()),
+            Token::CurlyClose(testfilepos()),
+            Token::KwFunction(testfilepos()),
+            Token::Name(testfilepos(), "pkg.mymod.helper".to_string(), "helper".to_string()),
+            Token::ParensOpen(testfilepos()),
+            Token::ParensClose(testfilepos()),
+            Token::CurlyOpen(testfilepos()),
+            Token::KwNew(testfilepos()),
+            Token::Name(testfilepos(), "pkg.mymod.MyClass".to_string(), "MyClass".to_string()),
+            Token::ParensOpen(testfilepos()),
+            Token::ParensClose(testfilepos()),
+            Token::EndStatement(testfilepos()),
+            Token::CurlyClose(testfilepos()),
+        ];
+
+        assert_eq!(expected, tokens.unwrap());
+    }
+
     #[test]
     fn test_local_funcs_are_aliased() {
         let testpath: PathBuf = get_test_main_file();
@@ -903,4 +1086,123 @@ mod tests {
 
         assert_eq!(expected, tokens.unwrap());
     }
+
+    #[test]
+    fn test_ellipsis_is_split_from_names() {
+        let testpath: PathBuf = get_test_main_file();
+
+        let lexemes = vec![
+            Lexeme::Word(testfilepos(), "...rest".to_string()),
+        ];
+
+        let expected = vec![
+            Token::Ellipsis(testfilepos()),
+            Token::Name(testfilepos().shift_col(3), "rest".to_string(), "rest".to_string()),
+        ];
+
+        let mut tokenizer = Tokenizer::new(lexemes, &testpath, String::new());
+
+        assert_eq!(expected, tokenizer.tokenize().unwrap());
+    }
+
+    #[test]
+    fn test_safe_access_and_coalesce_are_tokenized() {
+        let testpath: PathBuf = get_test_main_file();
+
+        let lexemes = vec![
+            Lexeme::Word(testfilepos(), "a".to_string()),
+            Lexeme::Symbol(testfilepos().shift_col(1), "?".to_string()),
+            Lexeme::Symbol(testfilepos().shift_col(2), "-".to_string()),
+            Lexeme::Symbol(testfilepos().shift_col(3), ">".to_string()),
+            Lexeme::Word(testfilepos().shift_col(4), "b".to_string()),
+            Lexeme::Whitespace(testfilepos().shift_col(5), " ".to_string()),
+            Lexeme::Symbol(testfilepos().shift_col(6), "?".to_string()),
+            Lexeme::Symbol(testfilepos().shift_col(7), "?".to_string()),
+        ];
+
+        let expected = vec![
+            Token::Name(testfilepos(), "a".to_string(), "a".to_string()),
+            Token::SafeObjectAccess(testfilepos().shift_col(1)),
+            Token::Name(testfilepos().shift_col(4), "b".to_string(), "b".to_string()),
+            Token::OpCoalesce(testfilepos().shift_col(6)),
+        ];
+
+        let mut tokenizer = Tokenizer::new(lexemes, &testpath, String::new());
+
+        assert_eq!(expected, tokenizer.tokenize().unwrap());
+    }
+
+    #[test]
+    fn test_member_names_are_not_aliased() {
+        let testpath: PathBuf = get_test_main_file();
+
+        let lexemes = vec![
+            Lexeme::Word(testfilepos(), "function".to_string()),
+            Lexeme::Whitespace(testfilepos(), " ".to_string()),
+            Lexeme::Word(testfilepos(), "count".to_string()),
+            Lexeme::Symbol(testfilepos(), "(".to_string()),
+            Lexeme::Symbol(testfilepos(), ")".to_string()),
+            Lexeme::Whitespace(testfilepos(), " ".to_string()),
+            Lexeme::Symbol(testfilepos(), "{".to_string()),
+            Lexeme::Word(testfilepos(), "items".to_string()),
+            Lexeme::Symbol(testfilepos(), "-".to_string()),
+            Lexeme::Symbol(testfilepos(), ">".to_string()),
+            Lexeme::Word(testfilepos(), "count".to_string()),
+            Lexeme::Symbol(testfilepos(), "(".to_string()),
+            Lexeme::Symbol(testfilepos(), ")".to_string()),
+            Lexeme::Symbol(testfilepos(), ";".to_string()),
+            Lexeme::Symbol(testfilepos(), "}".to_string())
+        ];
+
+        let mut tokenizer = Tokenizer::new(lexemes, &testpath, String::from("pkg"));
+
+        let tokens = tokenizer.tokenize();
+
+        let expected = vec![
+            Token::KwFunction(testfilepos()),
+            Token::Name(testfilepos(), "pkg.count".to_string(), "count".to_string()),
+            Token::ParensOpen(testfilepos()),
+            Token::ParensClose(testfilepos()),
+            Token::CurlyOpen(testfilepos()),
+            Token::Name(testfilepos(), "items".to_string(), "items".to_string()),
+            Token::ObjectAccess(testfilepos().shift_col(-1)),
+            Token::Name(testfilepos(), "count".to_string(), "count".to_string()),
+            Token::ParensOpen(testfilepos()),
+            Token::ParensClose(testfilepos()),
+            Token::EndStatement(testfilepos()),
+            Token::CurlyClose(testfilepos()),
+        ];
+
+        assert_eq!(expected, tokens.unwrap());
+    }
+
+    #[test]
+    fn test_keyword_member_names_are_names() {
+        let testpath: PathBuf = get_test_main_file();
+
+        let lexemes = vec![
+            Lexeme::Word(testfilepos(), "rng".to_string()),
+            Lexeme::Symbol(testfilepos(), "-".to_string()),
+            Lexeme::Symbol(testfilepos(), ">".to_string()),
+            Lexeme::Word(testfilepos(), "int".to_string()),
+            Lexeme::Symbol(testfilepos(), "(".to_string()),
+            Lexeme::Symbol(testfilepos(), ")".to_string()),
+            Lexeme::Symbol(testfilepos(), ";".to_string()),
+            Lexeme::Word(testfilepos(), "float".to_string()),
+        ];
+
+        let mut tokenizer = Tokenizer::new(lexemes, &testpath, String::new());
+
+        let expected = vec![
+            Token::Name(testfilepos(), "rng".to_string(), "rng".to_string()),
+            Token::ObjectAccess(testfilepos().shift_col(-1)),
+            Token::Name(testfilepos(), "int".to_string(), "int".to_string()),
+            Token::ParensOpen(testfilepos()),
+            Token::ParensClose(testfilepos()),
+            Token::EndStatement(testfilepos()),
+            Token::TypeFloat(testfilepos()),
+        ];
+
+        assert_eq!(expected, tokenizer.tokenize().unwrap());
+    }
 }